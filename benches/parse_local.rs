@@ -0,0 +1,64 @@
+//! Parse versions with local segments, such as the `+cu118` builds of PyTorch wheels.
+//!
+//! ```text
+//! cargo bench --bench parse_local
+//! ```
+
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Instant;
+
+use pep440_rs::Version;
+
+/// A deterministic pseudo-random number generator (SplitMix64).
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) % bound
+    }
+}
+
+/// Accelerator builds, distribution builds with several segments, and some uppercase local
+/// versions that have to be lowercased.
+fn corpus(len: usize) -> Vec<String> {
+    let mut rng = Rng(0);
+    (0..len)
+        .map(|_| {
+            let release = format!("{}.{}.{}", rng.below(3), rng.below(20), rng.below(5));
+            match rng.below(10) {
+                0..=3 => format!("{release}+cu{}", 110 + rng.below(20)),
+                4..=5 => format!("{release}+rocm{}.{}", 5 + rng.below(2), rng.below(8)),
+                6 => format!("{release}+cpu"),
+                7..=8 => format!(
+                    "{release}+ubuntu{}.{}.{}",
+                    18 + rng.below(6),
+                    rng.below(2) * 10 + 4,
+                    rng.below(5)
+                ),
+                _ => format!("{release}+CU{}", 110 + rng.below(20)),
+            }
+        })
+        .collect()
+}
+
+fn main() {
+    let versions = corpus(500_000);
+    let mut best = f64::INFINITY;
+    for _ in 0..10 {
+        let start = Instant::now();
+        for version in &versions {
+            black_box(Version::from_str(black_box(version)).unwrap());
+        }
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    println!(
+        "parse {} local versions: {:.1} ms",
+        versions.len(),
+        best * 1000.0
+    );
+}
@@ -0,0 +1,74 @@
+//! Sort a synthetic corpus with the shape of the versions on PyPI.
+//!
+//! ```text
+//! cargo bench --bench sort
+//! ```
+
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Instant;
+
+use pep440_rs::Version;
+
+/// A deterministic pseudo-random number generator (SplitMix64).
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        (z ^ (z >> 31)) % bound
+    }
+}
+
+/// Mostly final releases, some calendar and long versions, and a few pre-, post-, dev and local
+/// versions.
+fn corpus(len: usize) -> Vec<Version> {
+    let mut rng = Rng(0);
+    (0..len)
+        .map(|_| {
+            let version = match rng.below(100) {
+                0..=59 => format!("{}.{}.{}", rng.below(5), rng.below(30), rng.below(20)),
+                60..=74 => format!(
+                    "{}.{}.{}",
+                    2015 + rng.below(10),
+                    rng.below(13),
+                    rng.below(32)
+                ),
+                75..=84 => format!(
+                    "{}.{}.{}.{}.{}",
+                    rng.below(3),
+                    rng.below(10),
+                    rng.below(10),
+                    rng.below(10),
+                    rng.below(10)
+                ),
+                85..=89 => format!("{}.{}rc{}", rng.below(5), rng.below(30), rng.below(3)),
+                90..=94 => format!("{}.{}.post{}", rng.below(5), rng.below(30), rng.below(3)),
+                95..=97 => format!("{}.{}.dev{}", rng.below(5), rng.below(30), rng.below(300)),
+                _ => format!(
+                    "{}.{}+cu{}",
+                    rng.below(5),
+                    rng.below(30),
+                    110 + rng.below(20)
+                ),
+            };
+            Version::from_str(&version).unwrap()
+        })
+        .collect()
+}
+
+fn main() {
+    let versions = corpus(500_000);
+    let mut best = f64::INFINITY;
+    for _ in 0..10 {
+        let mut versions = versions.clone();
+        let start = Instant::now();
+        versions.sort();
+        best = best.min(start.elapsed().as_secs_f64());
+        black_box(versions);
+    }
+    println!("sort {} versions: {:.1} ms", versions.len(), best * 1000.0);
+}
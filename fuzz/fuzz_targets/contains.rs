@@ -0,0 +1,28 @@
+//! Matching must not panic, and a set of specifiers must match exactly when all of its
+//! specifiers match.
+
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use pep440_rs::{Version, VersionSpecifiers};
+
+fuzz_target!(|data: (&str, &str)| {
+    let (Ok(specifiers), Ok(version)) = (
+        VersionSpecifiers::from_str(data.0),
+        Version::from_str(data.1),
+    ) else {
+        return;
+    };
+    let contains = specifiers.contains(&version);
+    assert_eq!(
+        contains,
+        specifiers
+            .iter()
+            .all(|specifier| specifier.contains(&version))
+    );
+    // Matching is independent of the spelling of the version
+    let reparsed = Version::from_str(&version.to_string()).unwrap();
+    assert_eq!(contains, specifiers.contains(&reparsed));
+});
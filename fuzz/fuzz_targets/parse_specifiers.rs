@@ -0,0 +1,22 @@
+//! Parsing specifiers must not panic, and parsed specifiers must survive a `Display` ->
+//! `FromStr` round trip unchanged.
+
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use pep440_rs::{VersionSpecifier, VersionSpecifiers};
+
+fuzz_target!(|data: &str| {
+    if let Ok(specifier) = VersionSpecifier::from_str(data) {
+        let reparsed = VersionSpecifier::from_str(&specifier.to_string())
+            .expect("normalized specifier must parse");
+        assert_eq!(specifier, reparsed);
+    }
+    if let Ok(specifiers) = VersionSpecifiers::from_str(data) {
+        let reparsed = VersionSpecifiers::from_str(&specifiers.to_string())
+            .expect("normalized specifiers must parse");
+        assert_eq!(specifiers, reparsed);
+    }
+});
@@ -0,0 +1,20 @@
+//! Parsing a version must not panic, and a parsed version must survive a `Display` -> `FromStr`
+//! round trip unchanged.
+
+#![no_main]
+
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use pep440_rs::{Version, VersionPattern};
+
+fuzz_target!(|data: &str| {
+    let _ = VersionPattern::from_str(data);
+    let Ok(version) = Version::from_str(data) else {
+        return;
+    };
+    let normalized = version.to_string();
+    let reparsed = Version::from_str(&normalized).expect("normalized version must parse");
+    assert_eq!(version, reparsed);
+    assert_eq!(normalized, reparsed.to_string());
+});
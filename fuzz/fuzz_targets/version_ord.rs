@@ -0,0 +1,37 @@
+//! The ordering of versions must be a total order that is consistent with `Eq` and `Hash`.
+
+#![no_main]
+
+use std::cmp::Ordering;
+use std::hash::{BuildHasher, RandomState};
+use std::str::FromStr;
+
+use libfuzzer_sys::fuzz_target;
+use pep440_rs::Version;
+
+fuzz_target!(|data: (&str, &str, &str)| {
+    let (Ok(a), Ok(b), Ok(c)) = (
+        Version::from_str(data.0),
+        Version::from_str(data.1),
+        Version::from_str(data.2),
+    ) else {
+        return;
+    };
+
+    // Reflexivity and antisymmetry
+    assert_eq!(a.cmp(&a), Ordering::Equal);
+    assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    // `Eq` and `Hash` agree with `Ord`
+    let hasher = RandomState::new();
+    if a == b {
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+    }
+    // Transitivity
+    if a <= b && b <= c {
+        assert!(a <= c, "{a} <= {b} <= {c}");
+    }
+    // The representation must not matter, e.g. through the normalization round trip
+    let a_reparsed = Version::from_str(&a.to_string()).unwrap();
+    assert_eq!(a.cmp(&b), a_reparsed.cmp(&b));
+});
@@ -0,0 +1,149 @@
+#ifndef PEP440_H
+#define PEP440_H
+
+/* Generated with cbindgen from src/capi.rs, do not edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * An opaque parsed version.
+ */
+typedef struct Pep440Version Pep440Version;
+
+/**
+ * An opaque parsed version specifier such as `>=1.16`.
+ */
+typedef struct Pep440VersionSpecifier Pep440VersionSpecifier;
+
+/**
+ * An opaque parsed list of version specifiers such as `>=1.16, <2.0`.
+ */
+typedef struct Pep440VersionSpecifiers Pep440VersionSpecifiers;
+
+/**
+ * Parse a version such as `1.19` from a NUL-terminated UTF-8 string.
+ *
+ * Returns `NULL` on failure. If `error` is not `NULL`, it is set to an error message (which
+ * must be released with [`pep440_string_free`]) on failure and to `NULL` on success.
+ *
+ * # Safety
+ *
+ * `version` must be a valid NUL-terminated string and `error` must be `NULL` or valid for writes.
+ */
+struct Pep440Version *pep440_version_parse(const char *version, char **error);
+
+/**
+ * Release a version returned by [`pep440_version_parse`]. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ *
+ * `version` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+ */
+void pep440_version_free(struct Pep440Version *version);
+
+/**
+ * Return the normalized version string, which must be released with [`pep440_string_free`].
+ *
+ * # Safety
+ *
+ * `version` must be a valid pointer returned by [`pep440_version_parse`].
+ */
+char *pep440_version_to_string(const struct Pep440Version *version);
+
+/**
+ * Compare two versions, returning a negative number, zero or a positive number if `a` is less
+ * than, equal to or greater than `b`, respectively.
+ *
+ * # Safety
+ *
+ * Both arguments must be valid pointers returned by [`pep440_version_parse`].
+ */
+int pep440_version_cmp(const struct Pep440Version *a, const struct Pep440Version *b);
+
+/**
+ * Parse a single version specifier such as `>=1.16` from a NUL-terminated UTF-8 string.
+ *
+ * Error handling is the same as for [`pep440_version_parse`].
+ *
+ * # Safety
+ *
+ * `specifier` must be a valid NUL-terminated string and `error` must be `NULL` or valid for
+ * writes.
+ */
+struct Pep440VersionSpecifier *pep440_specifier_parse(const char *specifier, char **error);
+
+/**
+ * Release a specifier returned by [`pep440_specifier_parse`]. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ *
+ * `specifier` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+ */
+void pep440_specifier_free(struct Pep440VersionSpecifier *specifier);
+
+/**
+ * Whether the version matches the specifier.
+ *
+ * # Safety
+ *
+ * Both arguments must be valid pointers returned by this API.
+ */
+bool pep440_specifier_contains(const struct Pep440VersionSpecifier *specifier,
+                               const struct Pep440Version *version);
+
+/**
+ * Parse a list of version specifiers such as `>=1.16, <2.0` from a NUL-terminated UTF-8
+ * string.
+ *
+ * Error handling is the same as for [`pep440_version_parse`].
+ *
+ * # Safety
+ *
+ * `specifiers` must be a valid NUL-terminated string and `error` must be `NULL` or valid for
+ * writes.
+ */
+struct Pep440VersionSpecifiers *pep440_specifiers_parse(const char *specifiers, char **error);
+
+/**
+ * Release specifiers returned by [`pep440_specifiers_parse`]. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ *
+ * `specifiers` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+ */
+void pep440_specifiers_free(struct Pep440VersionSpecifiers *specifiers);
+
+/**
+ * Whether the version matches all specifiers.
+ *
+ * # Safety
+ *
+ * Both arguments must be valid pointers returned by this API.
+ */
+bool pep440_specifiers_contains(const struct Pep440VersionSpecifiers *specifiers,
+                                const struct Pep440Version *version);
+
+/**
+ * Return the normalized specifiers string, which must be released with
+ * [`pep440_string_free`].
+ *
+ * # Safety
+ *
+ * `specifiers` must be a valid pointer returned by [`pep440_specifiers_parse`].
+ */
+char *pep440_specifiers_to_string(const struct Pep440VersionSpecifiers *specifiers);
+
+/**
+ * Release a string returned by this API. Passing `NULL` is a no-op.
+ *
+ * # Safety
+ *
+ * `string` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+ */
+void pep440_string_free(char *string);
+
+#endif  /* PEP440_H */
@@ -0,0 +1,34 @@
+//! The `lint` subcommand: Check requirement lines and `requires-python` values for common
+//! problems.
+
+use pep440_rs::Severity;
+use serde::Serialize;
+
+/// A problem found in one line of the input.
+#[derive(Debug, Serialize)]
+pub(crate) struct Diagnostic {
+    /// The 1-based line number.
+    pub(crate) line: usize,
+    /// A machine-readable code, e.g. `non-canonical`.
+    pub(crate) code: &'static str,
+    /// A human-readable description of the problem.
+    pub(crate) message: String,
+}
+
+/// Lint each line of the input, see [`pep440_rs::diagnostics`] for the checks.
+///
+/// Hints, such as a missing upper bound, are suggestions rather than problems and are skipped.
+pub(crate) fn lint(input: &str) -> Vec<Diagnostic> {
+    pep440_rs::diagnostics(input)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity != Severity::Hint)
+        .map(|diagnostic| Diagnostic {
+            line: input[..diagnostic.range.start].matches('\n').count() + 1,
+            code: diagnostic.code,
+            message: diagnostic.message,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
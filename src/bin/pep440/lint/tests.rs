@@ -0,0 +1,44 @@
+use indoc::indoc;
+
+use super::*;
+
+fn codes(input: &str) -> Vec<(usize, &'static str)> {
+    lint(input)
+        .into_iter()
+        .map(|diagnostic| (diagnostic.line, diagnostic.code))
+        .collect()
+}
+
+#[test]
+fn requirements() {
+    let input = indoc! {"
+        # A comment
+        -r other.txt
+        foo[bar] >=1.0, <2.0 ; python_version < '3.9'
+        bar (>=1.0-Alpha1,<2)
+        baz
+        qux ===1.0
+        quux >2,<1
+        foo @ https://example.com/foo.whl
+    "};
+    assert_eq!(
+        codes(input),
+        [
+            (4, "non-canonical"),
+            (5, "unbounded"),
+            (6, "arbitrary-equality"),
+            (7, "contradiction"),
+        ]
+    );
+}
+
+#[test]
+fn requires_python() {
+    assert_eq!(codes(">=3.8"), []);
+    assert_eq!(codes("!=3.9.*"), [(1, "unbounded")]);
+    assert_eq!(codes(">=3.8, <3.010"), [(1, "non-canonical")]);
+    assert_eq!(codes(">=3.8, <=>3.9"), [(1, "invalid")]);
+    assert_eq!(codes("==3.8, !=3.8"), [(1, "contradiction")]);
+    // `===` compares the operand as written, so it's not normalized
+    assert_eq!(codes("===3.08"), [(1, "arbitrary-equality")]);
+}
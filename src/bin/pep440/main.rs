@@ -0,0 +1,309 @@
+//! A small command line tool exposing PEP 440 version logic to shell scripts and CI jobs.
+//!
+//! ```text
+//! $ pep440 check 1.19 ">=1.16, <2.0" && echo ok
+//! ok
+//! $ pep440 compare 1.0rc1 1.0
+//! <
+//! $ printf '1.0\n1.0a1\n0.9\n' | pep440 sort
+//! 0.9
+//! 1.0a1
+//! 1.0
+//! $ pep440 requires-python ">=3.8" ">=3.9, <3.13"
+//! requires-python: >=3.9, <3.13
+//! supported: 3.9, 3.10, 3.11, 3.12
+//! $ pep440 range ">=1.0, !=1.5.*, <2.0"
+//! [1.0, 1.5)
+//! [1.6, 2.0)
+//! ```
+
+use std::io::{self, BufRead, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use pep440_rs::{Version, VersionSpecifiers};
+
+mod lint;
+mod range;
+mod requires_python;
+
+#[derive(Parser)]
+#[command(
+    name = "pep440",
+    version,
+    about = "PEP 440 version numbers and specifiers"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Exit with 0 if the version matches the specifiers and with 1 otherwise.
+    Check {
+        /// The version to check, e.g. `1.19`.
+        version: String,
+        /// The specifiers, e.g. `>=1.16, <2.0`.
+        specifiers: String,
+    },
+    /// Print `<`, `==` or `>` depending on how the first version orders relative to the second.
+    Compare {
+        /// The left-hand side version.
+        a: String,
+        /// The right-hand side version.
+        b: String,
+    },
+    /// Print the normalized form of a version.
+    Normalize {
+        /// The version to normalize, e.g. `1.0-Alpha1`.
+        version: String,
+    },
+    /// Read versions from stdin, one per line, and print them in ascending order.
+    ///
+    /// The lines are printed as given, not normalized.
+    Sort {
+        /// Print the versions in descending order instead.
+        #[arg(short, long)]
+        reverse: bool,
+    },
+    /// Read versions from stdin, one per line, and print those matching the specifiers.
+    ///
+    /// The lines are printed as given, not normalized.
+    Filter {
+        /// The specifiers, e.g. `>=1.16, <2.0`.
+        specifiers: String,
+    },
+    /// Check requirement lines or a `requires-python` value for problems.
+    ///
+    /// Reports non-canonical versions, constraints without any bounds, constraints that no version
+    /// can satisfy and uses of arbitrary equality (`===`). Exits with 1 if any problems were found.
+    Lint {
+        /// The file to read, e.g. `requirements.txt`. Reads from stdin if omitted.
+        file: Option<PathBuf>,
+        /// The output format.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Print the intervals of versions that the specifiers allow, one per line, e.g. `[1.0, 1.5)`.
+    ///
+    /// Uses release-only semantics like `requires-python`, so pre-releases aren't considered.
+    Range {
+        /// The specifiers, e.g. `>=1.0, !=1.5.*, <2.0`, or with `--reverse`, the intervals, e.g.
+        /// `[1.0, 1.5) [1.6, 2.0)`.
+        input: String,
+        /// Convert intervals into the specifiers that allow exactly those intervals instead.
+        #[arg(short, long)]
+        reverse: bool,
+    },
+    /// Print the intersection of several `requires-python` values and the Python minor releases
+    /// it supports.
+    ///
+    /// Exits with 1 if no Python version satisfies all values.
+    RequiresPython {
+        /// The `requires-python` values, e.g. `>=3.8` `>=3.9, <3.13`.
+        #[arg(required = true)]
+        specifiers: Vec<String>,
+        /// The latest Python minor release to report as supported.
+        #[arg(long, default_value = "3.14")]
+        latest: String,
+    },
+    /// Print a shell completion script, e.g. `pep440 completions bash > /etc/bash_completion.d/pep440`.
+    Completions {
+        /// The shell to generate completions for.
+        shell: Shell,
+    },
+    /// Print the man page in roff format, e.g. `pep440 man > pep440.1`.
+    Man,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One `<line>: <code>: <message>` line per problem.
+    Text,
+    /// A JSON array of `{"line", "code", "message"}` objects.
+    Json,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, String> {
+    match command {
+        Command::Check {
+            version,
+            specifiers,
+        } => {
+            let version = parse_version(&version)?;
+            let specifiers = parse_specifiers(&specifiers)?;
+            if specifiers.contains(&version) {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::Compare { a, b } => {
+            let ordering = match parse_version(&a)?.cmp(&parse_version(&b)?) {
+                std::cmp::Ordering::Less => "<",
+                std::cmp::Ordering::Equal => "==",
+                std::cmp::Ordering::Greater => ">",
+            };
+            write_stdout(|stdout| writeln!(stdout, "{ordering}"))
+        }
+        Command::Normalize { version } => {
+            let version = parse_version(&version)?;
+            write_stdout(|stdout| writeln!(stdout, "{version}"))
+        }
+        Command::Sort { reverse } => {
+            let mut versions = read_versions()?;
+            // Stable sort, so equal versions keep their input order in both directions
+            if reverse {
+                versions.sort_by(|(a, _), (b, _)| b.cmp(a));
+            } else {
+                versions.sort_by(|(a, _), (b, _)| a.cmp(b));
+            }
+            write_lines(versions.iter().map(|(_, line)| line))
+        }
+        Command::Filter { specifiers } => {
+            let specifiers = parse_specifiers(&specifiers)?;
+            let versions = read_versions()?;
+            write_lines(
+                versions
+                    .iter()
+                    .filter(|(version, _)| specifiers.contains(version))
+                    .map(|(_, line)| line),
+            )
+        }
+        Command::Lint { file, format } => {
+            let input = match file {
+                Some(file) => std::fs::read_to_string(&file)
+                    .map_err(|err| format!("failed to read `{}`: {err}", file.display()))?,
+                None => {
+                    let mut input = String::new();
+                    io::stdin()
+                        .read_to_string(&mut input)
+                        .map_err(|err| format!("failed to read stdin: {err}"))?;
+                    input
+                }
+            };
+            let diagnostics = lint::lint(&input);
+            match format {
+                Format::Text => write_stdout(|stdout| {
+                    for diagnostic in &diagnostics {
+                        writeln!(
+                            stdout,
+                            "{}: {}: {}",
+                            diagnostic.line, diagnostic.code, diagnostic.message
+                        )?;
+                    }
+                    Ok(())
+                })?,
+                Format::Json => {
+                    let json = serde_json::to_string_pretty(&diagnostics)
+                        .map_err(|err| format!("failed to serialize diagnostics: {err}"))?;
+                    write_stdout(|stdout| writeln!(stdout, "{json}"))?
+                }
+            };
+            if diagnostics.is_empty() {
+                Ok(ExitCode::SUCCESS)
+            } else {
+                Ok(ExitCode::FAILURE)
+            }
+        }
+        Command::Range { input, reverse } => {
+            if reverse {
+                let specifiers = range::to_specifiers(&range::parse_intervals(&input)?)?;
+                write_stdout(|stdout| writeln!(stdout, "{specifiers}"))
+            } else {
+                let intervals = range::intervals(&parse_specifiers(&input)?);
+                if intervals.is_empty() {
+                    eprintln!("no version matches `{input}`");
+                    return Ok(ExitCode::FAILURE);
+                }
+                write_lines(intervals.iter())
+            }
+        }
+        Command::RequiresPython { specifiers, latest } => {
+            let specifiers = specifiers
+                .iter()
+                .map(|specifiers| parse_specifiers(specifiers))
+                .collect::<Result<Vec<_>, _>>()?;
+            let latest = parse_version(&latest)?;
+            let Some(intersection) = requires_python::intersect(&specifiers, &latest) else {
+                eprintln!("no Python version satisfies all `requires-python` values");
+                return Ok(ExitCode::FAILURE);
+            };
+            let minors: Vec<String> = intersection
+                .minors
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            write_stdout(|stdout| {
+                writeln!(stdout, "requires-python: {}", intersection.specifiers)?;
+                writeln!(stdout, "supported: {}", minors.join(", "))
+            })
+        }
+        Command::Completions { shell } => write_stdout(|stdout| {
+            clap_complete::generate(shell, &mut Cli::command(), "pep440", stdout);
+            Ok(())
+        }),
+        Command::Man => write_stdout(|stdout| clap_mangen::Man::new(Cli::command()).render(stdout)),
+    }
+}
+
+/// Write to the locked stdout.
+///
+/// Unlike `println!`, this doesn't panic if the output was piped into e.g. `head`, but treats the
+/// closed pipe as success.
+fn write_stdout(write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> Result<ExitCode, String> {
+    let mut stdout = io::stdout().lock();
+    match write(&mut stdout).and_then(|()| stdout.flush()) {
+        Ok(()) => Ok(ExitCode::SUCCESS),
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(ExitCode::SUCCESS),
+        Err(err) => Err(format!("failed to write to stdout: {err}")),
+    }
+}
+
+fn parse_version(version: &str) -> Result<Version, String> {
+    Version::from_str(version).map_err(|err| format!("invalid version `{version}`: {err}"))
+}
+
+fn parse_specifiers(specifiers: &str) -> Result<VersionSpecifiers, String> {
+    VersionSpecifiers::from_str(specifiers).map_err(|err| err.to_string())
+}
+
+/// Read one version per line from stdin, skipping blank lines.
+///
+/// Returns the parsed versions alongside the trimmed input lines.
+fn read_versions() -> Result<Vec<(Version, String)>, String> {
+    let mut versions = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|err| format!("failed to read stdin: {err}"))?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        versions.push((parse_version(line)?, line.to_string()));
+    }
+    Ok(versions)
+}
+
+fn write_lines<'a>(lines: impl Iterator<Item = &'a String>) -> Result<ExitCode, String> {
+    write_stdout(|stdout| {
+        for line in lines {
+            writeln!(stdout, "{line}")?;
+        }
+        Ok(())
+    })
+}
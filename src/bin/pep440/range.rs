@@ -0,0 +1,96 @@
+//! The `range` subcommand: Convert between specifiers and the intervals of versions they allow.
+//!
+//! The conversion uses release-only semantics, like `requires-python`, so that the intervals
+//! have plain release bounds such as `[1.0, 2.0)` and can be converted back into specifiers.
+
+use std::ops::Bound;
+use std::str::FromStr;
+
+use pep440_rs::{release_specifiers_to_ranges, Version, VersionSpecifiers};
+use version_ranges::Ranges;
+
+/// The disjoint intervals that the specifiers allow, in ascending order, e.g. `[1.0, 1.5)` and
+/// `[1.6, 2.0)` for `>=1.0, !=1.5.*, <2.0`.
+pub(crate) fn intervals(specifiers: &VersionSpecifiers) -> Vec<String> {
+    release_specifiers_to_ranges(specifiers.clone())
+        .iter()
+        .map(|(lower, upper)| {
+            let lower = match lower {
+                Bound::Included(version) => format!("[{version}"),
+                Bound::Excluded(version) => format!("({version}"),
+                Bound::Unbounded => "(-inf".to_string(),
+            };
+            let upper = match upper {
+                Bound::Included(version) => format!("{version}]"),
+                Bound::Excluded(version) => format!("{version})"),
+                Bound::Unbounded => "inf)".to_string(),
+            };
+            format!("{lower}, {upper}")
+        })
+        .collect()
+}
+
+/// Parse intervals in the format of [`intervals`], separated by whitespace, newlines or `|`.
+pub(crate) fn parse_intervals(input: &str) -> Result<Ranges<Version>, String> {
+    let mut ranges = Ranges::empty();
+    let mut rest = input.trim_start_matches(|c: char| c.is_whitespace() || c == '|');
+    while !rest.is_empty() {
+        let Some(end) = rest.find([']', ')']) else {
+            return Err(format!("unterminated interval `{rest}`"));
+        };
+        let (interval, after) = rest.split_at(end + 1);
+        ranges = ranges.union(&parse_interval(interval)?);
+        rest = after.trim_start_matches(|c: char| c.is_whitespace() || c == '|');
+    }
+    Ok(ranges)
+}
+
+/// Parse a single interval such as `[1.0, 2.0)` or `(-inf, 2.0]`.
+fn parse_interval(interval: &str) -> Result<Ranges<Version>, String> {
+    let invalid = || format!("invalid interval `{interval}`, expected e.g. `[1.0, 2.0)`");
+    let (lower, upper) = interval.split_once(',').ok_or_else(invalid)?;
+    let (lower, upper) = (lower.trim(), upper.trim());
+    let parse = |version: &str| {
+        Version::from_str(version.trim())
+            .map_err(|err| format!("invalid version in `{interval}`: {err}"))
+    };
+    let lower = if let Some(version) = lower.strip_prefix('[') {
+        Bound::Included(parse(version)?)
+    } else if let Some(version) = lower.strip_prefix('(') {
+        match version.trim() {
+            "-inf" => Bound::Unbounded,
+            version => Bound::Excluded(parse(version)?),
+        }
+    } else {
+        return Err(invalid());
+    };
+    let upper = if let Some(version) = upper.strip_suffix(']') {
+        Bound::Included(parse(version)?)
+    } else if let Some(version) = upper.strip_suffix(')') {
+        match version.trim() {
+            "inf" => Bound::Unbounded,
+            version => Bound::Excluded(parse(version)?),
+        }
+    } else {
+        return Err(invalid());
+    };
+    Ok(Ranges::from_range_bounds((lower, upper)))
+}
+
+/// The specifiers that allow exactly the intervals, or an error if there are none, e.g. because
+/// a gap between two intervals is neither a single version nor a `major.minor` series.
+pub(crate) fn to_specifiers(ranges: &Ranges<Version>) -> Result<VersionSpecifiers, String> {
+    if ranges.is_empty() {
+        return Err("no version is in the intervals".to_string());
+    }
+    let specifiers = VersionSpecifiers::from_release_only_bounds(ranges.iter());
+    if release_specifiers_to_ranges(specifiers.clone()) != *ranges {
+        return Err(
+            "the gaps between the intervals can't be expressed with `!=` specifiers".to_string(),
+        );
+    }
+    Ok(specifiers)
+}
+
+#[cfg(test)]
+mod tests;
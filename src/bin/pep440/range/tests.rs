@@ -0,0 +1,51 @@
+use super::*;
+
+fn intervals_str(specifiers: &str) -> Vec<String> {
+    intervals(&VersionSpecifiers::from_str(specifiers).unwrap())
+}
+
+fn specifiers_str(intervals: &str) -> Result<String, String> {
+    to_specifiers(&parse_intervals(intervals)?).map(|specifiers| specifiers.to_string())
+}
+
+#[test]
+fn specifiers_to_intervals() {
+    assert_eq!(
+        intervals_str(">=1.0,!=1.5.*,<2.0"),
+        ["[1.0, 1.5)", "[1.6, 2.0)"]
+    );
+    assert_eq!(intervals_str("!=1.2"), ["(-inf, 1.2)", "(1.2, inf)"]);
+    assert_eq!(intervals_str("==1.2"), ["[1.2, 1.2]"]);
+    assert_eq!(intervals_str(""), ["(-inf, inf)"]);
+    assert!(intervals_str(">2, <1").is_empty());
+}
+
+#[test]
+fn intervals_to_specifiers() {
+    assert_eq!(
+        specifiers_str("[1.0, 1.5) [1.6, 2.0)").unwrap(),
+        ">=1.0, !=1.5.*, <2.0"
+    );
+    assert_eq!(
+        specifiers_str("(-inf, 1.2)\n(1.2, inf)\n").unwrap(),
+        "!=1.2"
+    );
+    assert_eq!(specifiers_str("[3.8, inf)").unwrap(), ">=3.8");
+    assert!(specifiers_str("[1.0, 1.2) | [1.4, 2.0)").is_err());
+    assert!(specifiers_str("").is_err());
+    assert!(specifiers_str("[1.0, 2.0").is_err());
+    assert!(specifiers_str("{1.0, 2.0)").is_err());
+}
+
+#[test]
+fn round_trip() {
+    for specifiers in [">=1.0, !=1.5.*, <2.0", "!=1.2", ">=3.8, <=3.12", "==1.2"] {
+        let intervals = intervals_str(specifiers).join("\n");
+        let round_tripped = specifiers_str(&intervals).unwrap();
+        assert_eq!(
+            VersionSpecifiers::from_str(&round_tripped).unwrap(),
+            VersionSpecifiers::from_str(specifiers).unwrap(),
+            "{intervals}"
+        );
+    }
+}
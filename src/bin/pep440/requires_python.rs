@@ -0,0 +1,52 @@
+//! The `requires-python` subcommand: Intersect the `requires-python` values of several projects,
+//! e.g. all members of a workspace.
+
+use pep440_rs::{release_specifiers_to_ranges, Version, VersionSpecifiers};
+use version_ranges::Ranges;
+
+/// The Python versions that all inputs allow.
+#[derive(Debug)]
+pub(crate) struct Intersection {
+    /// The intersection as a single `requires-python` value.
+    pub(crate) specifiers: VersionSpecifiers,
+    /// The `major.minor` releases up to and including the latest that have at least one allowed
+    /// version, in ascending order.
+    pub(crate) minors: Vec<Version>,
+}
+
+/// Intersect the specifiers, or return `None` if no version satisfies all of them.
+///
+/// Like installers checking `requires-python`, this uses release-only semantics, so `>=3.13.0b1`
+/// is the same as `>=3.13`.
+///
+/// The supported minor releases are those of the major release of `latest`, from `.0` up to
+/// the minor release of `latest`.
+pub(crate) fn intersect(inputs: &[VersionSpecifiers], latest: &Version) -> Option<Intersection> {
+    let range = inputs.iter().fold(Ranges::full(), |acc, specifiers| {
+        acc.intersection(&release_specifiers_to_ranges(specifiers.clone()))
+    });
+    if range.is_empty() {
+        return None;
+    }
+
+    let major = latest.release()[0];
+    let latest_minor = latest.release().get(1).copied().unwrap_or(0);
+    let minors = (0..=latest_minor)
+        .filter(|minor| {
+            let minor_range = Ranges::between(
+                Version::new([major, *minor]),
+                Version::new([major, minor + 1]),
+            );
+            !range.is_disjoint(&minor_range)
+        })
+        .map(|minor| Version::new([major, minor]))
+        .collect();
+
+    Some(Intersection {
+        specifiers: VersionSpecifiers::from_release_only_bounds(range.iter()),
+        minors,
+    })
+}
+
+#[cfg(test)]
+mod tests;
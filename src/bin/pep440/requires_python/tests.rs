@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn intersect_str(inputs: &[&str]) -> Option<(String, Vec<String>)> {
+    let inputs: Vec<VersionSpecifiers> = inputs
+        .iter()
+        .map(|input| VersionSpecifiers::from_str(input).unwrap())
+        .collect();
+    let latest = Version::from_str("3.13").unwrap();
+    intersect(&inputs, &latest).map(|intersection| {
+        (
+            intersection.specifiers.to_string(),
+            intersection
+                .minors
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        )
+    })
+}
+
+#[test]
+fn workspace() {
+    assert_eq!(
+        intersect_str(&[">=3.8", ">=3.9, <3.13", "!=3.10.*"]),
+        Some((
+            ">=3.9, !=3.10.*, <3.13".to_string(),
+            vec!["3.9".to_string(), "3.11".to_string(), "3.12".to_string()]
+        ))
+    );
+    assert_eq!(
+        intersect_str(&[">=3.12.1"]),
+        Some((
+            ">=3.12.1".to_string(),
+            vec!["3.12".to_string(), "3.13".to_string()]
+        ))
+    );
+}
+
+#[test]
+fn prerelease() {
+    let (_, minors) = intersect_str(&[">=3.13.0b1"]).unwrap();
+    assert_eq!(minors, ["3.13"]);
+    let (_, minors) = intersect_str(&["<3.13.0rc1"]).unwrap();
+    assert_eq!(minors.last().unwrap(), "3.12");
+}
+
+#[test]
+fn no_inputs() {
+    assert_eq!(intersect_str(&[]).unwrap().1.len(), 14);
+}
+
+#[test]
+fn disjoint() {
+    assert_eq!(intersect_str(&[">=3.10", "<3.9"]), None);
+}
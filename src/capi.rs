@@ -0,0 +1,232 @@
+//! A C API for non-Rust tools and language bindings.
+//!
+//! The header is in `include/pep440.h`; regenerate it with `cbindgen --output include/pep440.h`
+//! after changing this module.
+//!
+//! Versions and specifiers are opaque heap-allocated objects, created by the `*_parse` functions
+//! and released with the matching `*_free` function. Strings returned by this API must be
+//! released with [`pep440_string_free`].
+//!
+//! ```c
+//! char *error = NULL;
+//! Pep440Version *version = pep440_version_parse("1.19", &error);
+//! Pep440VersionSpecifiers *specifiers = pep440_specifiers_parse(">=1.16, <2.0", &error);
+//! assert(pep440_specifiers_contains(specifiers, version));
+//! pep440_specifiers_free(specifiers);
+//! pep440_version_free(version);
+//! ```
+
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use std::str::FromStr;
+
+use crate::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// An opaque parsed version.
+pub struct Pep440Version(Version);
+
+/// An opaque parsed version specifier such as `>=1.16`.
+pub struct Pep440VersionSpecifier(VersionSpecifier);
+
+/// An opaque parsed list of version specifiers such as `>=1.16, <2.0`.
+pub struct Pep440VersionSpecifiers(VersionSpecifiers);
+
+/// Parse a version such as `1.19` from a NUL-terminated UTF-8 string.
+///
+/// Returns `NULL` on failure. If `error` is not `NULL`, it is set to an error message (which
+/// must be released with [`pep440_string_free`]) on failure and to `NULL` on success.
+///
+/// # Safety
+///
+/// `version` must be a valid NUL-terminated string and `error` must be `NULL` or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_version_parse(
+    version: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut Pep440Version {
+    parse(version, error, |s| Version::from_str(s).map(Pep440Version))
+}
+
+/// Release a version returned by [`pep440_version_parse`]. Passing `NULL` is a no-op.
+///
+/// # Safety
+///
+/// `version` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_version_free(version: *mut Pep440Version) {
+    if !version.is_null() {
+        drop(Box::from_raw(version));
+    }
+}
+
+/// Return the normalized version string, which must be released with [`pep440_string_free`].
+///
+/// # Safety
+///
+/// `version` must be a valid pointer returned by [`pep440_version_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn pep440_version_to_string(version: *const Pep440Version) -> *mut c_char {
+    to_c_string((*version).0.to_string())
+}
+
+/// Compare two versions, returning a negative number, zero or a positive number if `a` is less
+/// than, equal to or greater than `b`, respectively.
+///
+/// # Safety
+///
+/// Both arguments must be valid pointers returned by [`pep440_version_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn pep440_version_cmp(
+    a: *const Pep440Version,
+    b: *const Pep440Version,
+) -> c_int {
+    (*a).0.cmp(&(*b).0) as c_int
+}
+
+/// Parse a single version specifier such as `>=1.16` from a NUL-terminated UTF-8 string.
+///
+/// Error handling is the same as for [`pep440_version_parse`].
+///
+/// # Safety
+///
+/// `specifier` must be a valid NUL-terminated string and `error` must be `NULL` or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifier_parse(
+    specifier: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut Pep440VersionSpecifier {
+    parse(specifier, error, |s| {
+        VersionSpecifier::from_str(s).map(Pep440VersionSpecifier)
+    })
+}
+
+/// Release a specifier returned by [`pep440_specifier_parse`]. Passing `NULL` is a no-op.
+///
+/// # Safety
+///
+/// `specifier` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifier_free(specifier: *mut Pep440VersionSpecifier) {
+    if !specifier.is_null() {
+        drop(Box::from_raw(specifier));
+    }
+}
+
+/// Whether the version matches the specifier.
+///
+/// # Safety
+///
+/// Both arguments must be valid pointers returned by this API.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifier_contains(
+    specifier: *const Pep440VersionSpecifier,
+    version: *const Pep440Version,
+) -> bool {
+    (*specifier).0.contains(&(*version).0)
+}
+
+/// Parse a list of version specifiers such as `>=1.16, <2.0` from a NUL-terminated UTF-8
+/// string.
+///
+/// Error handling is the same as for [`pep440_version_parse`].
+///
+/// # Safety
+///
+/// `specifiers` must be a valid NUL-terminated string and `error` must be `NULL` or valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifiers_parse(
+    specifiers: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut Pep440VersionSpecifiers {
+    parse(specifiers, error, |s| {
+        VersionSpecifiers::from_str(s).map(Pep440VersionSpecifiers)
+    })
+}
+
+/// Release specifiers returned by [`pep440_specifiers_parse`]. Passing `NULL` is a no-op.
+///
+/// # Safety
+///
+/// `specifiers` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifiers_free(specifiers: *mut Pep440VersionSpecifiers) {
+    if !specifiers.is_null() {
+        drop(Box::from_raw(specifiers));
+    }
+}
+
+/// Whether the version matches all specifiers.
+///
+/// # Safety
+///
+/// Both arguments must be valid pointers returned by this API.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifiers_contains(
+    specifiers: *const Pep440VersionSpecifiers,
+    version: *const Pep440Version,
+) -> bool {
+    (*specifiers).0.contains(&(*version).0)
+}
+
+/// Return the normalized specifiers string, which must be released with
+/// [`pep440_string_free`].
+///
+/// # Safety
+///
+/// `specifiers` must be a valid pointer returned by [`pep440_specifiers_parse`].
+#[no_mangle]
+pub unsafe extern "C" fn pep440_specifiers_to_string(
+    specifiers: *const Pep440VersionSpecifiers,
+) -> *mut c_char {
+    to_c_string((*specifiers).0.to_string())
+}
+
+/// Release a string returned by this API. Passing `NULL` is a no-op.
+///
+/// # Safety
+///
+/// `string` must be `NULL` or a pointer returned by this API that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pep440_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Shared implementation of the `*_parse` functions.
+unsafe fn parse<T, E: std::fmt::Display>(
+    input: *const c_char,
+    error: *mut *mut c_char,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> *mut T {
+    let result = match CStr::from_ptr(input).to_str() {
+        Ok(input) => parse(input).map_err(|err| err.to_string()),
+        Err(err) => Err(format!("Input is not valid UTF-8: {err}")),
+    };
+    match result {
+        Ok(value) => {
+            if !error.is_null() {
+                *error = ptr::null_mut();
+            }
+            Box::into_raw(Box::new(value))
+        }
+        Err(err) => {
+            if !error.is_null() {
+                *error = to_c_string(err);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Convert into a C string, replacing interior NUL bytes which can't be represented.
+fn to_c_string(string: String) -> *mut c_char {
+    let string = CString::new(string.replace('\0', "\u{FFFD}"))
+        .expect("interior NUL bytes have been replaced");
+    string.into_raw()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use super::*;
+
+#[test]
+fn version_roundtrip() {
+    let input = CString::new("1.0-Alpha1").unwrap();
+    let mut error = ptr::null_mut();
+    unsafe {
+        let version = pep440_version_parse(input.as_ptr(), &mut error);
+        assert!(!version.is_null());
+        assert!(error.is_null());
+        let normalized = pep440_version_to_string(version);
+        assert_eq!(CStr::from_ptr(normalized).to_str().unwrap(), "1.0a1");
+        pep440_string_free(normalized);
+        pep440_version_free(version);
+    }
+}
+
+#[test]
+fn version_cmp() {
+    let a = CString::new("1.0rc1").unwrap();
+    let b = CString::new("1.0").unwrap();
+    unsafe {
+        let a = pep440_version_parse(a.as_ptr(), ptr::null_mut());
+        let b = pep440_version_parse(b.as_ptr(), ptr::null_mut());
+        assert!(pep440_version_cmp(a, b) < 0);
+        assert!(pep440_version_cmp(b, a) > 0);
+        assert_eq!(pep440_version_cmp(a, a), 0);
+        pep440_version_free(a);
+        pep440_version_free(b);
+    }
+}
+
+#[test]
+fn specifiers_contains() {
+    let specifier = CString::new("==1.*").unwrap();
+    let specifiers = CString::new(">=1.16, <2.0").unwrap();
+    let version = CString::new("1.19").unwrap();
+    unsafe {
+        let specifier = pep440_specifier_parse(specifier.as_ptr(), ptr::null_mut());
+        let specifiers = pep440_specifiers_parse(specifiers.as_ptr(), ptr::null_mut());
+        let version = pep440_version_parse(version.as_ptr(), ptr::null_mut());
+        assert!(pep440_specifier_contains(specifier, version));
+        assert!(pep440_specifiers_contains(specifiers, version));
+        pep440_specifier_free(specifier);
+        pep440_specifiers_free(specifiers);
+        pep440_version_free(version);
+    }
+}
+
+#[test]
+fn parse_error() {
+    let input = CString::new(">=1.0, <=>2").unwrap();
+    let mut error = ptr::null_mut();
+    unsafe {
+        let specifiers = pep440_specifiers_parse(input.as_ptr(), &mut error);
+        assert!(specifiers.is_null());
+        assert!(CStr::from_ptr(error)
+            .to_str()
+            .unwrap()
+            .starts_with("Failed to parse version: no such comparison operator \"<=>\""));
+        pep440_string_free(error);
+    }
+}
@@ -0,0 +1,281 @@
+//! An order-preserving binary encoding of [`Version`], see [`Version::to_bytes`].
+
+use crate::version::sortable_tuple;
+use crate::{LocalSegment, Prerelease, PrereleaseKind, Version};
+
+impl Version {
+    /// Encode the version into bytes whose lexicographic order is the order of the versions, for
+    /// use as keys in embedded databases such as LMDB or RocksDB that can range-scan them.
+    ///
+    /// Equal versions, such as `1.0` and `1.0.0`, have the same encoding. The encoding is stable
+    /// and consists of:
+    ///
+    /// * Numbers as the count of their significant big-endian bytes (0 to 8) followed by those
+    ///   bytes, e.g. `[0]` for `0` and `[2, 1, 0]` for `256`.
+    /// * The epoch.
+    /// * The release without trailing zeros, each number prefixed by `1`, terminated by `0`.
+    /// * The stage: `0` for the internal minimum version, `1` for dev releases, `2`, `3` and `4`
+    ///   for alpha, beta and release candidates, `5` for final and `6` for post-releases.
+    /// * The pre-release number, `0` if there is none.
+    /// * `0` if there is no post-release, otherwise `1` followed by the post-release number.
+    /// * The dev release number, which is `u64::MAX` for pre- and post-releases without a dev
+    ///   release and `0` for final releases.
+    /// * The local version segments, strings as `1` followed by their bytes and `0`, numbers as
+    ///   `2` followed by the number, terminated by `0`.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let version = Version::from_str("1.2rc1").unwrap();
+    /// let bytes = version.to_bytes();
+    /// assert_eq!(Version::from_bytes(&bytes).unwrap(), version);
+    /// assert!(bytes < Version::from_str("1.2").unwrap().to_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn push_number(bytes: &mut Vec<u8>, number: u64) {
+            let len = 8 - (number.leading_zeros() / 8) as usize;
+            bytes.push(len as u8);
+            bytes.extend_from_slice(&number.to_be_bytes()[8 - len..]);
+        }
+
+        let mut bytes = Vec::new();
+        push_number(&mut bytes, self.epoch());
+        let release = self.release();
+        let len = release
+            .iter()
+            .rposition(|number| *number != 0)
+            .map_or(0, |idx| idx + 1);
+        for number in &release[..len] {
+            bytes.push(1);
+            push_number(&mut bytes, *number);
+        }
+        bytes.push(0);
+
+        let (stage, pre, post, dev, local) = sortable_tuple(self);
+        bytes.push(stage as u8);
+        push_number(&mut bytes, pre);
+        if let Some(post) = post {
+            bytes.push(1);
+            push_number(&mut bytes, post);
+        } else {
+            bytes.push(0);
+        }
+        push_number(&mut bytes, dev);
+        for segment in local {
+            match segment {
+                LocalSegment::String(string) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(string.as_bytes());
+                    bytes.push(0);
+                }
+                LocalSegment::Number(number) => {
+                    bytes.push(2);
+                    push_number(&mut bytes, *number);
+                }
+            }
+        }
+        bytes.push(0);
+        bytes
+    }
+
+    /// Decode a version encoded with [`Version::to_bytes`].
+    ///
+    /// The decoded version is equal to the encoded one, but trailing zeros in the release are
+    /// removed, e.g. `1.0.0` is decoded as `1`.
+    ///
+    /// Only bytes that [`Version::to_bytes`] can produce are accepted, so each version has
+    /// exactly one encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VersionBytesError> {
+        let mut decoder = Decoder { bytes, pos: 0 };
+        let epoch = decoder.number()?;
+        let mut release = Vec::new();
+        let mut last_pos = 0;
+        while decoder.flag()? {
+            last_pos = decoder.pos;
+            release.push(decoder.number()?);
+        }
+        // Trailing zeros are never encoded
+        if release.last() == Some(&0) {
+            return Err(BytesErrorKind::Invalid { offset: last_pos }.into());
+        }
+        if release.is_empty() {
+            release.push(0);
+        }
+
+        let stage_pos = decoder.pos;
+        let stage = decoder.byte()?;
+        let pre = decoder.number()?;
+        let post = if decoder.flag()? {
+            Some(decoder.number()?)
+        } else {
+            None
+        };
+        let dev = decoder.number()?;
+        let dev_if_set = (dev != u64::MAX).then_some(dev);
+        // The stages that don't have a pre-release, post-release or dev release number encode
+        // them as fixed values, see `sortable_tuple`
+        let payload_valid = match stage {
+            0 => pre == 0,
+            1 => pre == 0 && post.is_none(),
+            2..=4 => true,
+            5 => pre == 0 && post.is_none() && dev == 0,
+            6 => pre == 0 && post.is_some(),
+            _ => false,
+        };
+        if !payload_valid {
+            return Err(BytesErrorKind::Invalid { offset: stage_pos }.into());
+        }
+
+        let mut local = Vec::new();
+        loop {
+            let marker_pos = decoder.pos;
+            match decoder.byte()? {
+                0 => break,
+                1 => {
+                    let len = decoder.bytes[decoder.pos..]
+                        .iter()
+                        .position(|byte| *byte == 0)
+                        .ok_or(BytesErrorKind::Truncated)?;
+                    let segment = &decoder.bytes[decoder.pos..decoder.pos + len];
+                    // Like the parser, only lowercase strings, while digits are numbers
+                    if !segment
+                        .iter()
+                        .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit())
+                        || segment.iter().all(u8::is_ascii_digit)
+                    {
+                        return Err(BytesErrorKind::Invalid {
+                            offset: decoder.pos,
+                        }
+                        .into());
+                    }
+                    decoder.pos += len + 1;
+                    local.push(LocalSegment::String(
+                        String::from_utf8(segment.to_vec()).expect("ASCII is valid UTF-8"),
+                    ));
+                }
+                2 => local.push(LocalSegment::Number(decoder.number()?)),
+                _ => return Err(BytesErrorKind::Invalid { offset: marker_pos }.into()),
+            }
+        }
+        if decoder.pos != bytes.len() {
+            return Err(BytesErrorKind::Trailing {
+                offset: decoder.pos,
+            }
+            .into());
+        }
+
+        let version = Version::new(release)
+            .with_epoch(epoch)
+            .with_post(post)
+            .with_local(local);
+        let pre_kind = |kind| Some(Prerelease { kind, number: pre });
+        let version = match stage {
+            0 => version.with_min(Some(dev)),
+            1 => version.with_dev(Some(dev)),
+            2 => version
+                .with_pre(pre_kind(PrereleaseKind::Alpha))
+                .with_dev(dev_if_set),
+            3 => version
+                .with_pre(pre_kind(PrereleaseKind::Beta))
+                .with_dev(dev_if_set),
+            4 => version
+                .with_pre(pre_kind(PrereleaseKind::Rc))
+                .with_dev(dev_if_set),
+            5 => version,
+            6 => version.with_dev(dev_if_set),
+            _ => unreachable!("the stage was checked above"),
+        };
+        Ok(version)
+    }
+}
+
+/// Reads the parts of an encoded version.
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Decoder<'_> {
+    fn byte(&mut self) -> Result<u8, VersionBytesError> {
+        let byte = *self.bytes.get(self.pos).ok_or(BytesErrorKind::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    /// A `0` or `1` byte.
+    fn flag(&mut self) -> Result<bool, VersionBytesError> {
+        match self.byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(BytesErrorKind::Invalid {
+                offset: self.pos - 1,
+            }
+            .into()),
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, VersionBytesError> {
+        let len_pos = self.pos;
+        let len = usize::from(self.byte()?);
+        if len > 8 {
+            return Err(BytesErrorKind::Invalid { offset: len_pos }.into());
+        }
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or(BytesErrorKind::Truncated)?;
+        // The shortest encoding is required, so that equal numbers have equal encodings
+        if digits.first() == Some(&0) {
+            return Err(BytesErrorKind::Invalid { offset: len_pos }.into());
+        }
+        self.pos += len;
+        let mut buffer = [0; 8];
+        buffer[8 - len..].copy_from_slice(digits);
+        Ok(u64::from_be_bytes(buffer))
+    }
+}
+
+/// The bytes passed to [`Version::from_bytes`] are not an encoded version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionBytesError {
+    kind: Box<BytesErrorKind>,
+}
+
+impl std::error::Error for VersionBytesError {}
+
+impl std::fmt::Display for VersionBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.kind {
+            BytesErrorKind::Truncated => write!(f, "encoded version ends unexpectedly"),
+            BytesErrorKind::Invalid { offset } => {
+                write!(f, "invalid byte in encoded version at offset {offset}")
+            }
+            BytesErrorKind::Trailing { offset } => {
+                write!(
+                    f,
+                    "unexpected bytes after encoded version at offset {offset}"
+                )
+            }
+        }
+    }
+}
+
+/// The kind of error that occurs when decoding a version.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum BytesErrorKind {
+    Truncated,
+    Invalid { offset: usize },
+    Trailing { offset: usize },
+}
+
+impl From<BytesErrorKind> for VersionBytesError {
+    fn from(kind: BytesErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
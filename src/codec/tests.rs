@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn encoding() {
+    let version = Version::from_str("1!2.0.256rc1.post1+ubuntu.2").unwrap();
+    assert_eq!(
+        version.to_bytes(),
+        [
+            1, 1, // epoch
+            1, 1, 2, 1, 0, 1, 2, 1, 0, 0, // release
+            4, 1, 1, // rc1
+            1, 1, 1, // post1
+            8, 255, 255, 255, 255, 255, 255, 255, 255, // no dev
+            1, b'u', b'b', b'u', b'n', b't', b'u', 0, 2, 1, 2, 0, // local
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    for version in [
+        "0",
+        "1.0",
+        "1.2.3",
+        "2!1.0.dev3",
+        "1.0a1.post2.dev3",
+        "1.0b0",
+        "1.0.post0",
+        "1.0.post1.dev1",
+        "1.0+local.10.abc",
+        "18446744073709551615.1",
+    ] {
+        let version = Version::from_str(version).unwrap();
+        let decoded = Version::from_bytes(&version.to_bytes()).unwrap();
+        assert_eq!(decoded, version);
+        assert_eq!(decoded.to_bytes(), version.to_bytes());
+    }
+    // Trailing zeros are removed
+    let version = Version::from_str("1.0.0").unwrap();
+    assert_eq!(
+        Version::from_bytes(&version.to_bytes())
+            .unwrap()
+            .to_string(),
+        "1"
+    );
+}
+
+#[test]
+fn invalid() {
+    let bytes = Version::from_str("1.0+local").unwrap().to_bytes();
+    assert_eq!(
+        Version::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+        BytesErrorKind::Truncated.into()
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Version::from_bytes(&trailing).unwrap_err(),
+        BytesErrorKind::Trailing {
+            offset: bytes.len()
+        }
+        .into()
+    );
+    // A number with a leading zero byte
+    assert_eq!(
+        Version::from_bytes(&[1, 0, 0]).unwrap_err(),
+        BytesErrorKind::Invalid { offset: 0 }.into()
+    );
+    // Stage 7
+    assert_eq!(
+        Version::from_bytes(&[0, 0, 7, 0, 0, 0, 0]).unwrap_err(),
+        BytesErrorKind::Invalid { offset: 2 }.into()
+    );
+}
+
+/// Bytes that `to_bytes` never writes are rejected instead of decoding to a version with another
+/// encoding.
+#[test]
+fn invalid_not_canonical() {
+    let replace = |version: &str, from: &[u8], to: &[u8]| {
+        let mut bytes = Version::from_str(version).unwrap().to_bytes();
+        let pos = bytes
+            .windows(from.len())
+            .position(|window| window == from)
+            .unwrap();
+        bytes.splice(pos..pos + from.len(), to.iter().copied());
+        bytes
+    };
+    let invalid = [
+        // Uppercase local segment
+        replace("1.0+abc", b"abc", b"ABC"),
+        // A digit-only local string segment, which is a number
+        replace("1.0+abc", b"abc", b"123"),
+        // An empty local string segment
+        replace("1.0+abc", b"abc", b""),
+        // A trailing zero in the release, `1.0`
+        [0, 1, 1, 1, 1, 0, 0, 5, 0, 0, 0, 0].to_vec(),
+        // Stage 5 (final) with a pre-release number, a post-release or a dev release number
+        [0, 1, 1, 1, 0, 5, 1, 1, 0, 0, 0].to_vec(),
+        [0, 1, 1, 1, 0, 5, 0, 1, 1, 1, 0, 0].to_vec(),
+        [0, 1, 1, 1, 0, 5, 0, 0, 1, 1, 0].to_vec(),
+        // Stage 1 (dev) with a pre-release number
+        [0, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0].to_vec(),
+        // Stage 6 (post) without a post-release
+        [0, 1, 1, 1, 0, 6, 0, 0, 0, 0].to_vec(),
+    ];
+    for bytes in invalid {
+        assert!(
+            matches!(
+                *Version::from_bytes(&bytes).unwrap_err().kind,
+                BytesErrorKind::Invalid { .. }
+            ),
+            "{bytes:?}"
+        );
+    }
+    // The valid versions those were derived from round-trip
+    for version in ["1.0+abc", "1", "1.dev1", "1.post1", "1a1"] {
+        let version = Version::from_str(version).unwrap();
+        let bytes = version.to_bytes();
+        let decoded = Version::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, version);
+        assert_eq!(decoded.to_bytes(), bytes);
+    }
+}
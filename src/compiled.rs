@@ -0,0 +1,198 @@
+//! A matcher for checking many versions against the same specifiers.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use crate::version::sortable_tuple;
+use crate::{Operator, Version, VersionSpecifier, VersionSpecifiers};
+
+/// [`VersionSpecifiers`] preprocessed for matching a large number of versions.
+///
+/// All `>=` clauses are merged into a single lower bound and all `<=` clauses into a single upper
+/// bound, and `!=` clauses without a local version become a hash set. The remaining clauses are
+/// checked one by one. Whether pre-releases are accepted is decided once, see
+/// [`PrereleasePolicy`]. Matching doesn't allocate, except for `===`, which compares the version
+/// strings.
+///
+/// With the default [`PrereleasePolicy::Allow`], [`CompiledSpecifiers::contains`] gives the same
+/// result as [`VersionSpecifiers::contains`].
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{CompiledSpecifiers, Version, VersionSpecifiers};
+///
+/// let specifiers = VersionSpecifiers::from_str(">=1.16, <=2.0, !=1.19, !=1.20").unwrap();
+/// let compiled = CompiledSpecifiers::new(&specifiers);
+/// assert!(compiled.contains(&Version::from_str("1.18").unwrap()));
+/// assert!(!compiled.contains(&Version::from_str("1.19").unwrap()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompiledSpecifiers {
+    /// The highest `>=` bound.
+    lower: Option<Version>,
+    /// The lowest `<=` bound.
+    upper: Option<Version>,
+    /// The versions excluded with `!=`, which don't have a local version.
+    excluded: HashSet<PublicVersion>,
+    /// All other clauses.
+    rest: Vec<VersionSpecifier>,
+    /// Whether a clause other than `!=` has a pre-release or dev release version, e.g.
+    /// `>=1.0rc1`.
+    mentions_prerelease: bool,
+    /// Whether all pre-releases and dev releases are rejected, as decided by the policy.
+    reject_prereleases: bool,
+}
+
+impl CompiledSpecifiers {
+    /// Preprocess the specifiers.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(clauses = specifiers.len(), excluded, rest)
+        )
+    )]
+    pub fn new(specifiers: &VersionSpecifiers) -> Self {
+        let mut compiled = Self::default();
+        for specifier in specifiers.iter() {
+            let version = specifier.version();
+            // Local versions are only allowed with `==`, `!=` and `===`, but `!=` with a local
+            // version doesn't ignore the local version of the candidate.
+            if specifier.any_prerelease()
+                && !matches!(
+                    specifier.operator(),
+                    Operator::NotEqual | Operator::NotEqualStar
+                )
+            {
+                compiled.mentions_prerelease = true;
+            }
+            match specifier.operator() {
+                Operator::GreaterThanEqual => {
+                    let lower = compiled.lower.get_or_insert_with(|| version.clone());
+                    if version > lower {
+                        *lower = version.clone();
+                    }
+                }
+                Operator::LessThanEqual => {
+                    let upper = compiled.upper.get_or_insert_with(|| version.clone());
+                    if version < upper {
+                        *upper = version.clone();
+                    }
+                }
+                Operator::NotEqual if !version.is_local() => {
+                    compiled.excluded.insert(PublicVersion(version.clone()));
+                }
+                _ => compiled.rest.push(specifier.clone()),
+            }
+        }
+        #[cfg(feature = "instrument")]
+        tracing::Span::current()
+            .record("excluded", compiled.excluded.len())
+            .record("rest", compiled.rest.len());
+        compiled
+    }
+
+    /// Decide whether pre-releases are accepted.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{CompiledSpecifiers, PrereleasePolicy, Version, VersionSpecifiers};
+    ///
+    /// let compiled = CompiledSpecifiers::new(&VersionSpecifiers::from_str(">=1.0").unwrap())
+    ///     .with_prerelease_policy(PrereleasePolicy::IfMentioned);
+    /// assert!(!compiled.contains(&Version::from_str("2.0rc1").unwrap()));
+    ///
+    /// let compiled = CompiledSpecifiers::new(&VersionSpecifiers::from_str(">=1.0rc1").unwrap())
+    ///     .with_prerelease_policy(PrereleasePolicy::IfMentioned);
+    /// assert!(compiled.contains(&Version::from_str("2.0rc1").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn with_prerelease_policy(mut self, policy: PrereleasePolicy) -> Self {
+        self.reject_prereleases = match policy {
+            PrereleasePolicy::Allow => false,
+            PrereleasePolicy::Reject => true,
+            PrereleasePolicy::IfMentioned => !self.mentions_prerelease,
+        };
+        self
+    }
+
+    /// Whether all specifiers match the given version.
+    pub fn contains(&self, version: &Version) -> bool {
+        if self.reject_prereleases && version.any_prerelease() {
+            return false;
+        }
+        if let Some(lower) = &self.lower {
+            if version.cmp_public(lower) == Ordering::Less {
+                return false;
+            }
+        }
+        if let Some(upper) = &self.upper {
+            if version.cmp_public(upper) == Ordering::Greater {
+                return false;
+            }
+        }
+        // Cloning the version only increments a reference count
+        if !self.excluded.is_empty() && self.excluded.contains(&PublicVersion(version.clone())) {
+            return false;
+        }
+        self.rest
+            .iter()
+            .all(|specifier| specifier.contains(version))
+    }
+}
+
+/// Whether [`CompiledSpecifiers::contains`] accepts pre-releases and dev releases, see
+/// [`CompiledSpecifiers::with_prerelease_policy`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PrereleasePolicy {
+    /// Accept pre-releases that match the specifiers, like [`VersionSpecifiers::contains`].
+    #[default]
+    Allow,
+    /// Reject all pre-releases and dev releases.
+    Reject,
+    /// Accept pre-releases only if a specifier other than `!=` has a pre-release version, e.g.
+    /// `>=1.0rc1`, like the default of packaging.
+    IfMentioned,
+}
+
+/// A version that is compared and hashed without its local version, like `!=` does.
+#[derive(Debug, Clone)]
+struct PublicVersion(Version);
+
+impl PartialEq for PublicVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.cmp_public(&other.0) == Ordering::Equal
+    }
+}
+
+impl Eq for PublicVersion {}
+
+impl Hash for PublicVersion {
+    /// Hash the same parts that [`Version::cmp_public`] compares.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.epoch().hash(state);
+        // Skip trailing zeros, which the comparison pads
+        for number in self
+            .0
+            .release()
+            .iter()
+            .rev()
+            .skip_while(|number| **number == 0)
+        {
+            number.hash(state);
+        }
+        let (stage, pre, post, dev, _local) = sortable_tuple(&self.0);
+        (stage, pre, post, dev).hash(state);
+    }
+}
+
+impl From<&VersionSpecifiers> for CompiledSpecifiers {
+    fn from(specifiers: &VersionSpecifiers) -> Self {
+        Self::new(specifiers)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn same_as_specifiers() {
+    let specifiers = [
+        "",
+        ">=1.0, >=1.2, <=3, <=2.0.post1",
+        "!=1.2, !=1.2.0, !=2.0, !=1.5+local",
+        ">=1.0, <2.0, !=1.5.*, ~=1.2",
+        ">1.2, <2.0",
+        "==1.2+local",
+        "===1.2",
+        "!=1.5, !=2.0rc1, !=1.2.post1",
+        "!=1.5.*, !=2.*",
+        "==1.*, !=1.2.*",
+        "~=1.2, ~=1.5.0",
+        "~=2.0rc1",
+        "<2.0",
+        ">=2.0rc1, !=2.0",
+    ];
+    let versions = [
+        "0.9",
+        "1.0",
+        "1.2",
+        "1.2+local",
+        "1.2.post1",
+        "1.2.1",
+        "1.5a1",
+        "1.5",
+        "1.5.0.dev1",
+        "1.5+local",
+        "1.6",
+        "2.0.dev1",
+        "2.0rc1",
+        "2.0rc1+local",
+        "2.0",
+        "2.0+local",
+        "2.0.post1",
+        "2.0.post2",
+        "3",
+    ];
+    for specifiers in specifiers {
+        let specifiers = VersionSpecifiers::from_str(specifiers).unwrap();
+        let compiled = CompiledSpecifiers::new(&specifiers);
+        for version in versions {
+            let version = Version::from_str(version).unwrap();
+            assert_eq!(
+                compiled.contains(&version),
+                specifiers.contains(&version),
+                "`{specifiers}` contains `{version}`"
+            );
+        }
+    }
+}
+
+#[test]
+fn prerelease_policy() {
+    let cases = [
+        (">=1.0", "2.0rc1", [true, false, false]),
+        (">=1.0", "2.0.dev1", [true, false, false]),
+        (">=1.0", "2.0", [true, true, true]),
+        (">=1.0rc1", "2.0rc1", [true, false, true]),
+        ("~=2.0rc1", "2.0rc2", [true, false, true]),
+        ("==2.0.dev1", "2.0.dev1", [true, false, true]),
+        // Excluding a pre-release doesn't opt into pre-releases
+        (">=1.0, !=2.0rc1", "2.0rc2", [true, false, false]),
+        ("", "2.0rc1", [true, false, false]),
+    ];
+    let policies = [
+        PrereleasePolicy::Allow,
+        PrereleasePolicy::Reject,
+        PrereleasePolicy::IfMentioned,
+    ];
+    for (specifiers, version, expected) in cases {
+        let specifiers = VersionSpecifiers::from_str(specifiers).unwrap();
+        let version = Version::from_str(version).unwrap();
+        for (policy, expected) in policies.into_iter().zip(expected) {
+            let compiled = CompiledSpecifiers::new(&specifiers).with_prerelease_policy(policy);
+            assert_eq!(
+                compiled.contains(&version),
+                expected,
+                "`{specifiers}` contains `{version}` with {policy:?}"
+            );
+        }
+    }
+}
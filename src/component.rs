@@ -0,0 +1,70 @@
+//! An implementation of the WIT world in `wit/pep440.wit`, so the crate can be used as a
+//! [WebAssembly component](https://component-model.bytecodealliance.org/).
+//!
+//! Build with `cargo build --target wasm32-wasip2 --features component --release`. On other
+//! targets, the feature has no effect.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::{PrereleaseKind, Version, VersionSpecifiers};
+
+wit_bindgen::generate!({
+    path: "wit",
+    world: "pep440-component",
+});
+
+use exports::konstin::pep440::pep440 as wit;
+
+struct Component;
+
+impl wit::Guest for Component {
+    fn parse_version(version: String) -> Result<wit::Version, String> {
+        let version = parse(&version)?;
+        Ok(wit::Version {
+            epoch: version.epoch(),
+            release: version.release().to_vec(),
+            pre: version.pre().map(|pre| wit::Prerelease {
+                kind: match pre.kind {
+                    PrereleaseKind::Alpha => wit::PrereleaseKind::Alpha,
+                    PrereleaseKind::Beta => wit::PrereleaseKind::Beta,
+                    PrereleaseKind::Rc => wit::PrereleaseKind::Rc,
+                },
+                number: pre.number,
+            }),
+            post: version.post(),
+            dev: version.dev(),
+            local: version.local_str(),
+        })
+    }
+
+    fn normalize_version(version: String) -> Result<String, String> {
+        Ok(parse(&version)?.to_string())
+    }
+
+    fn compare_versions(a: String, b: String) -> Result<i8, String> {
+        Ok(match parse(&a)?.cmp(&parse(&b)?) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        })
+    }
+
+    fn specifiers_contain(specifiers: String, version: String) -> Result<bool, String> {
+        Ok(parse_specifiers(&specifiers)?.contains(&parse(&version)?))
+    }
+
+    fn normalize_specifiers(specifiers: String) -> Result<String, String> {
+        Ok(parse_specifiers(&specifiers)?.to_string())
+    }
+}
+
+fn parse(version: &str) -> Result<Version, String> {
+    Version::from_str(version).map_err(|err| err.to_string())
+}
+
+fn parse_specifiers(specifiers: &str) -> Result<VersionSpecifiers, String> {
+    VersionSpecifiers::from_str(specifiers).map_err(|err| err.to_string())
+}
+
+export!(Component);
@@ -0,0 +1,186 @@
+//! Translate conda version constraints into PEP 440 specifiers.
+
+use std::str::FromStr;
+
+use crate::version_specifier::VersionSpecifierParseError;
+use crate::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// A conda version constraint, such as `>=1.0,<2|>=3` or `=1.2`, as a union of PEP 440
+/// specifiers.
+///
+/// `,` binds tighter than `|`, so `>=1.0,<2|>=3` means `(>=1.0 and <2) or >=3`. Besides the PEP
+/// 440 operators, the conda spellings are translated:
+///
+/// * A version without an operator is an exact match: `1.2.3` is `==1.2.3`.
+/// * `=` is a prefix match: `=1.2` is `==1.2.*`.
+/// * A trailing `*` or `.*` is a prefix match: `1.2.*` and `1.2*` are `==1.2.*`.
+/// * A wildcard on an ordering operator is ignored: `>=1.2.*` is `>=1.2`.
+/// * `*` and an empty constraint match all versions.
+///
+/// Parentheses and build strings are not supported.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{CondaSpecifiers, Version};
+///
+/// let specifiers = CondaSpecifiers::from_str(">=1.0,<2|=3.1").unwrap();
+/// assert_eq!(specifiers.to_string(), ">=1.0, <2|==3.1.*");
+/// assert!(specifiers.contains(&Version::from_str("3.1.4").unwrap()));
+/// assert!(!specifiers.contains(&Version::from_str("2.5").unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CondaSpecifiers {
+    /// The alternatives separated by `|`.
+    alternatives: Vec<VersionSpecifiers>,
+}
+
+impl CondaSpecifiers {
+    /// The alternatives, of which a version must match at least one.
+    pub fn alternatives(&self) -> &[VersionSpecifiers] {
+        &self.alternatives
+    }
+
+    /// Whether the version matches any of the alternatives.
+    pub fn contains(&self, version: &Version) -> bool {
+        self.alternatives
+            .iter()
+            .any(|specifiers| specifiers.contains(version))
+    }
+
+    /// The alternatives, of which a version must match at least one.
+    pub fn into_alternatives(self) -> Vec<VersionSpecifiers> {
+        self.alternatives
+    }
+}
+
+impl FromStr for CondaSpecifiers {
+    type Err = CondaSpecifiersError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let alternatives = input
+            .split('|')
+            .map(|alternative| {
+                alternative
+                    .split(',')
+                    .filter_map(|clause| translate_clause(clause).transpose())
+                    .collect::<Result<VersionSpecifiers, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { alternatives })
+    }
+}
+
+/// Translate a single conda clause, or return `None` for a clause that matches all versions.
+fn translate_clause(clause: &str) -> Result<Option<VersionSpecifier>, CondaSpecifiersError> {
+    let trimmed = clause.trim();
+    if trimmed.is_empty() || trimmed == "*" {
+        return Ok(None);
+    }
+    let operator_len = trimmed
+        .find(|c: char| !matches!(c, '=' | '!' | '~' | '<' | '>'))
+        .unwrap_or(trimmed.len());
+    let (operator, version) = trimmed.split_at(operator_len);
+    let version = version.trim_start();
+    // `1.2*` is the same as `1.2.*`
+    let (version, wildcard) = match version.strip_suffix('*') {
+        Some(version) => (version.strip_suffix('.').unwrap_or(version), true),
+        None => (version, false),
+    };
+    let translated = match (operator, wildcard) {
+        ("" | "=" | "==", true) | ("=", false) => format!("=={version}.*"),
+        ("", false) => format!("=={version}"),
+        ("!=", true) => format!("!={version}.*"),
+        (operator, _) => format!("{operator}{version}"),
+    };
+    VersionSpecifier::from_str(&translated)
+        .map(Some)
+        .map_err(|err| {
+            CondaErrorKind::InvalidClause {
+                clause: trimmed.to_string(),
+                err,
+            }
+            .into()
+        })
+}
+
+/// Writes the alternatives as PEP 440 specifiers separated by `|`.
+impl std::fmt::Display for CondaSpecifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, specifiers) in self.alternatives.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("|")?;
+            }
+            write!(f, "{specifiers}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<VersionSpecifiers> for CondaSpecifiers {
+    fn from(specifiers: VersionSpecifiers) -> Self {
+        Self {
+            alternatives: vec![specifiers],
+        }
+    }
+}
+
+#[cfg(feature = "version-ranges")]
+impl From<CondaSpecifiers> for version_ranges::Ranges<Version> {
+    /// The union of the ranges of the alternatives.
+    fn from(specifiers: CondaSpecifiers) -> Self {
+        specifiers
+            .alternatives
+            .into_iter()
+            .fold(Self::empty(), |union, specifiers| {
+                union.union(&Self::from(specifiers))
+            })
+    }
+}
+
+/// An error that occurs when translating a conda version constraint fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CondaSpecifiersError {
+    kind: Box<CondaErrorKind>,
+}
+
+impl std::error::Error for CondaSpecifiersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self.kind {
+            CondaErrorKind::InvalidClause { ref err, .. } => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for CondaSpecifiersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.kind {
+            CondaErrorKind::InvalidClause {
+                ref clause,
+                ref err,
+            } => {
+                write!(f, "Failed to translate conda constraint `{clause}`: {err}")
+            }
+        }
+    }
+}
+
+/// The kind of error that occurs when translating a conda version constraint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum CondaErrorKind {
+    /// The translated clause isn't a valid PEP 440 specifier.
+    InvalidClause {
+        clause: String,
+        err: VersionSpecifierParseError,
+    },
+}
+
+impl From<CondaErrorKind> for CondaSpecifiersError {
+    fn from(kind: CondaErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
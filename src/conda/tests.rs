@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn translate() {
+    let cases = [
+        ("", ""),
+        ("*", ""),
+        ("1.2.3", "==1.2.3"),
+        ("=1.2", "==1.2.*"),
+        ("=1.2.*", "==1.2.*"),
+        ("1.2*", "==1.2.*"),
+        ("1.2.*", "==1.2.*"),
+        ("==1.2", "==1.2"),
+        ("!=1.2*", "!=1.2.*"),
+        (">=1.2.*", ">=1.2"),
+        ("~=1.4.2", "~=1.4.2"),
+        (" >= 1.0 , < 2 ", ">=1.0, <2"),
+        (">=1.0,<2|>=3", ">=1.0, <2|>=3"),
+        ("1.2|1.4|*", "==1.2|==1.4|"),
+    ];
+    for (input, expected) in cases {
+        let specifiers = CondaSpecifiers::from_str(input).unwrap();
+        assert_eq!(specifiers.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn contains() {
+    let specifiers = CondaSpecifiers::from_str(">=1.0,<2|=3.1|4.0").unwrap();
+    for (version, expected) in [
+        ("0.9", false),
+        ("1.5", true),
+        ("2.0", false),
+        ("3.1.2", true),
+        ("3.2", false),
+        ("4.0", true),
+        ("4.0.1", false),
+    ] {
+        assert_eq!(
+            specifiers.contains(&Version::from_str(version).unwrap()),
+            expected,
+            "{version}"
+        );
+    }
+    #[cfg(feature = "version-ranges")]
+    {
+        let ranges = version_ranges::Ranges::from(specifiers.clone());
+        for version in ["0.9", "1.5", "2.0", "3.1.2", "3.2", "4.0", "4.0.1"] {
+            let version = Version::from_str(version).unwrap();
+            assert_eq!(ranges.contains(&version), specifiers.contains(&version));
+        }
+    }
+}
+
+#[test]
+fn invalid() {
+    for input in [">=1.0,(<2|>3)", "1.2=py_0", ">=foo", "=>1.0"] {
+        let err = CondaSpecifiers::from_str(input).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Failed to translate conda constraint"),
+            "{input}: {err}"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Collect version specifiers from multiple sources and report which sources conflict.
+
+use std::fmt::{Debug, Display};
+
+use version_ranges::Ranges;
+
+use crate::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// Version specifiers together with a source tag for each clause, such as the requirement or the
+/// file it came from.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{ConstraintSet, VersionSpecifiers};
+///
+/// let mut constraints = ConstraintSet::new();
+/// constraints.add(VersionSpecifiers::from_str(">=2.0").unwrap(), "requirements.txt");
+/// constraints.add(VersionSpecifiers::from_str("<3").unwrap(), "constraints.txt");
+/// constraints.add(VersionSpecifiers::from_str("<1.5").unwrap(), "pyproject.toml");
+///
+/// let conflict = constraints.conflict().unwrap();
+/// assert_eq!(
+///     conflict.to_string(),
+///     "`>=2.0` (from requirements.txt) conflicts with `<1.5` (from pyproject.toml)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintSet<S> {
+    clauses: Vec<(VersionSpecifier, S)>,
+}
+
+impl<S> Default for ConstraintSet<S> {
+    fn default() -> Self {
+        Self {
+            clauses: Vec::new(),
+        }
+    }
+}
+
+impl<S: Clone> ConstraintSet<S> {
+    /// An empty set, which matches all versions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add all clauses of the specifiers, tagged with their source.
+    pub fn add(&mut self, specifiers: VersionSpecifiers, source: S) {
+        for specifier in specifiers {
+            self.clauses.push((specifier, source.clone()));
+        }
+    }
+
+    /// Add all clauses of another set, keeping their sources.
+    pub fn merge(&mut self, other: ConstraintSet<S>) {
+        self.clauses.extend(other.clauses);
+    }
+
+    /// The clauses with their sources, in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&VersionSpecifier, &S)> {
+        self.clauses
+            .iter()
+            .map(|(specifier, source)| (specifier, source))
+    }
+
+    /// Returns `true` if there are no clauses.
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// All clauses combined, without their sources.
+    pub fn specifiers(&self) -> VersionSpecifiers {
+        self.clauses
+            .iter()
+            .map(|(specifier, _)| specifier.clone())
+            .collect()
+    }
+
+    /// Whether the version matches all clauses.
+    pub fn contains(&self, version: &Version) -> bool {
+        self.clauses
+            .iter()
+            .all(|(specifier, _)| specifier.contains(version))
+    }
+
+    /// If no version can satisfy all clauses, return a minimal set of clauses that already can't
+    /// be satisfied together.
+    ///
+    /// Minimal means that removing any clause from the conflict makes it satisfiable, so the
+    /// conflict only contains the clauses that are actually to blame. If there are multiple
+    /// conflicts, one of them is returned.
+    pub fn conflict(&self) -> Option<Conflict<S>> {
+        let ranges: Vec<Ranges<Version>> = self
+            .clauses
+            .iter()
+            .map(|(specifier, _)| Ranges::from(specifier.clone()))
+            .collect();
+        let intersection = |included: &[bool]| {
+            ranges
+                .iter()
+                .zip(included)
+                .filter(|(_, included)| **included)
+                .fold(Ranges::full(), |acc, (range, _)| acc.intersection(range))
+        };
+
+        let mut included = vec![true; ranges.len()];
+        if !intersection(&included).is_empty() {
+            return None;
+        }
+        // Drop every clause that isn't needed for the conflict
+        for idx in 0..included.len() {
+            included[idx] = false;
+            if !intersection(&included).is_empty() {
+                included[idx] = true;
+            }
+        }
+
+        let clauses = self
+            .clauses
+            .iter()
+            .zip(&included)
+            .filter(|(_, included)| **included)
+            .map(|(clause, _)| clause.clone())
+            .collect();
+        Some(Conflict { clauses })
+    }
+}
+
+impl<S: Clone> Extend<(VersionSpecifiers, S)> for ConstraintSet<S> {
+    fn extend<T: IntoIterator<Item = (VersionSpecifiers, S)>>(&mut self, iter: T) {
+        for (specifiers, source) in iter {
+            self.add(specifiers, source);
+        }
+    }
+}
+
+impl<S: Clone> FromIterator<(VersionSpecifiers, S)> for ConstraintSet<S> {
+    fn from_iter<T: IntoIterator<Item = (VersionSpecifiers, S)>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Clauses from a [`ConstraintSet`] that can't be satisfied together, see
+/// [`ConstraintSet::conflict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict<S> {
+    clauses: Vec<(VersionSpecifier, S)>,
+}
+
+impl<S> Conflict<S> {
+    /// The conflicting clauses with their sources, in insertion order.
+    pub fn clauses(&self) -> &[(VersionSpecifier, S)] {
+        &self.clauses
+    }
+
+    /// The sources of the conflicting clauses, in insertion order and possibly repeated.
+    pub fn sources(&self) -> impl Iterator<Item = &S> {
+        self.clauses.iter().map(|(_, source)| source)
+    }
+}
+
+impl<S: Display> Display for Conflict<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, (specifier, source)) in self.clauses.iter().enumerate() {
+            match idx {
+                0 => {}
+                1 => write!(f, " conflicts with ")?,
+                _ => write!(f, " and ")?,
+            }
+            write!(f, "`{specifier}` (from {source})")?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: Display + Debug> std::error::Error for Conflict<S> {}
+
+#[cfg(test)]
+mod tests;
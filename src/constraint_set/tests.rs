@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn specifiers(specifiers: &str) -> VersionSpecifiers {
+    VersionSpecifiers::from_str(specifiers).unwrap()
+}
+
+#[test]
+fn satisfiable() {
+    let constraints: ConstraintSet<&str> = [
+        (specifiers(">=1.0, <3"), "a"),
+        (specifiers("!=2.0.*"), "b"),
+        (specifiers(">=1.5"), "c"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(constraints.conflict(), None);
+    assert_eq!(
+        constraints.specifiers().to_string(),
+        ">=1.0, >=1.5, !=2.0.*, <3"
+    );
+    assert!(constraints.contains(&Version::from_str("2.1").unwrap()));
+    assert!(!constraints.contains(&Version::from_str("2.0.1").unwrap()));
+    assert_eq!(ConstraintSet::<&str>::new().conflict(), None);
+}
+
+#[test]
+fn minimal_conflict() {
+    let mut constraints = ConstraintSet::new();
+    constraints.add(specifiers(">=1.0"), "a");
+    constraints.add(specifiers(">=2.0, <4"), "b");
+    constraints.add(specifiers("!=3.1"), "c");
+
+    let mut other = ConstraintSet::new();
+    other.add(specifiers("<1.5"), "d");
+    constraints.merge(other);
+
+    let conflict = constraints.conflict().unwrap();
+    assert_eq!(conflict.sources().copied().collect::<Vec<_>>(), ["b", "d"]);
+    assert_eq!(
+        conflict.to_string(),
+        "`>=2.0` (from b) conflicts with `<1.5` (from d)"
+    );
+}
+
+#[test]
+fn conflict_between_three() {
+    let constraints: ConstraintSet<usize> = [
+        (specifiers("<5"), 1),
+        (specifiers(">=1.0"), 2),
+        (specifiers("!=1.*"), 3),
+        (specifiers("<2.dev0"), 4),
+    ]
+    .into_iter()
+    .collect();
+    let conflict = constraints.conflict().unwrap();
+    assert_eq!(
+        conflict.to_string(),
+        "`>=1.0` (from 2) conflicts with `!=1.*` (from 3) and `<2.dev0` (from 4)"
+    );
+}
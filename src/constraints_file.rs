@@ -0,0 +1,293 @@
+//! Parse pip constraints files into the version specifiers for each package.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::{RequirementParts, VersionSpecifiers, VersionSpecifiersParseError};
+
+/// The contents of a pip constraints file, such as one passed with `pip install -c`.
+///
+/// Each line is a package name with optional version specifiers and an optional marker, e.g.
+/// `requests >=2.0, <3 ; python_version >= "3.8"`. Comments, blank lines and options such as
+/// `--index-url` are skipped, and a line ending in `\` continues on the next line. Constraints
+/// can't have extras or a URL.
+///
+/// The constraints are grouped by the normalized package name, so `Foo_Bar` and `foo-bar` are
+/// the same package.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::ConstraintsFile;
+///
+/// let constraints = ConstraintsFile::from_str("# Pinned by CI\nrequests>=2.0\nRequests <3\n").unwrap();
+/// assert_eq!(constraints.specifiers("requests").to_string(), ">=2.0, <3");
+/// assert_eq!(constraints.get("requests")[1].line(), 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConstraintsFile {
+    /// The constraints by normalized package name, in file order.
+    constraints: BTreeMap<String, Vec<Constraint>>,
+}
+
+impl ConstraintsFile {
+    /// The constraints for a package, in file order, or an empty slice if there are none.
+    ///
+    /// The name is normalized, so it doesn't have to be spelled like in the file.
+    pub fn get(&self, name: &str) -> &[Constraint] {
+        self.constraints
+            .get(&normalize_name(name))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The combined specifiers of all constraints for a package that don't have a marker.
+    pub fn specifiers(&self, name: &str) -> VersionSpecifiers {
+        self.get(name)
+            .iter()
+            .filter(|constraint| constraint.marker.is_none())
+            .flat_map(|constraint| constraint.specifiers.iter().cloned())
+            .collect()
+    }
+
+    /// All constraints, ordered by normalized package name and then in file order.
+    pub fn iter(&self) -> impl Iterator<Item = &Constraint> {
+        self.constraints.values().flatten()
+    }
+
+    /// The number of packages with constraints.
+    pub fn len(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// Returns `true` if there are no constraints.
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+}
+
+impl FromStr for ConstraintsFile {
+    type Err = ConstraintsFileError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut constraints: BTreeMap<String, Vec<Constraint>> = BTreeMap::new();
+        for (line, span, text) in logical_lines(input) {
+            let text = strip_comment(&text).trim();
+            if text.is_empty() || text.starts_with('-') {
+                continue;
+            }
+            let constraint = Constraint::parse(text, line, span)?;
+            constraints
+                .entry(normalize_name(&constraint.name))
+                .or_default()
+                .push(constraint);
+        }
+        Ok(Self { constraints })
+    }
+}
+
+/// A single line of a [`ConstraintsFile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint {
+    name: String,
+    specifiers: VersionSpecifiers,
+    marker: Option<String>,
+    line: usize,
+    span: Range<usize>,
+}
+
+impl Constraint {
+    fn parse(text: &str, line: usize, span: Range<usize>) -> Result<Self, ConstraintsFileError> {
+        let Some(parts) = RequirementParts::split(text) else {
+            return Err(ConstraintsErrorKind::InvalidLine {
+                line,
+                text: text.to_string(),
+            }
+            .into());
+        };
+        if parts.extras().is_some() {
+            return Err(ConstraintsErrorKind::Extras {
+                line,
+                name: parts.name().to_string(),
+            }
+            .into());
+        }
+        if parts.url().is_some() {
+            return Err(ConstraintsErrorKind::Url {
+                line,
+                name: parts.name().to_string(),
+            }
+            .into());
+        }
+        let specifiers = parts
+            .parse_specifiers()
+            .map_err(|err| ConstraintsErrorKind::InvalidSpecifiers { line, err })?;
+        Ok(Self {
+            name: parts.name().to_string(),
+            specifiers,
+            marker: parts.marker().map(ToString::to_string),
+            line,
+            span,
+        })
+    }
+
+    /// The package name as written, e.g. `Requests`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The version specifiers, which are empty if there are none.
+    pub fn specifiers(&self) -> &VersionSpecifiers {
+        &self.specifiers
+    }
+
+    /// The marker after `;` as written, e.g. `python_version < '3.9'`, if there is one.
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+
+    /// The 1-based number of the line the constraint starts on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The byte range of the constraint in the file, including continuation lines, but without
+    /// the final line break.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+/// Join lines ending in `\` with the following line, returning the 1-based number of the first
+/// line, the byte range in the input and the joined text of each logical line.
+fn logical_lines(input: &str) -> Vec<(usize, Range<usize>, String)> {
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, usize, String)> = None;
+    let mut offset = 0;
+    for (idx, physical) in input.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += physical.len();
+        let content = physical
+            .strip_suffix('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .unwrap_or(physical);
+        let (line, line_start, mut text) = current
+            .take()
+            .unwrap_or_else(|| (idx + 1, start, String::new()));
+        if let Some(continued) = content.strip_suffix('\\') {
+            text.push_str(continued);
+            current = Some((line, line_start, text));
+        } else {
+            text.push_str(content);
+            lines.push((line, line_start..start + content.len(), text));
+        }
+    }
+    // A continuation on the last line
+    if let Some((line, line_start, text)) = current {
+        lines.push((line, line_start..input.len(), text));
+    }
+    lines
+}
+
+/// Remove a comment, which starts with `#` at the start of the line or after whitespace.
+fn strip_comment(line: &str) -> &str {
+    let mut previous = None;
+    for (idx, c) in line.char_indices() {
+        if c == '#' && previous.is_none_or(char::is_whitespace) {
+            return &line[..idx];
+        }
+        previous = Some(c);
+    }
+    line
+}
+
+/// Normalize a package name by lowercasing it and replacing runs of `-`, `_` and `.` with `-`.
+fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// An error that occurs when parsing a constraints file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConstraintsFileError {
+    kind: Box<ConstraintsErrorKind>,
+}
+
+impl ConstraintsFileError {
+    /// The 1-based number of the line the invalid constraint starts on.
+    pub fn line(&self) -> usize {
+        match *self.kind {
+            ConstraintsErrorKind::InvalidLine { line, .. }
+            | ConstraintsErrorKind::Extras { line, .. }
+            | ConstraintsErrorKind::Url { line, .. }
+            | ConstraintsErrorKind::InvalidSpecifiers { line, .. } => line,
+        }
+    }
+}
+
+impl std::error::Error for ConstraintsFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self.kind {
+            ConstraintsErrorKind::InvalidSpecifiers { ref err, .. } => Some(err),
+            ConstraintsErrorKind::InvalidLine { .. }
+            | ConstraintsErrorKind::Extras { .. }
+            | ConstraintsErrorKind::Url { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ConstraintsFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.kind {
+            ConstraintsErrorKind::InvalidLine { line, ref text } => {
+                write!(f, "Line {line}: Expected a package name, found `{text}`")
+            }
+            ConstraintsErrorKind::Extras { line, ref name } => {
+                write!(f, "Line {line}: Constraints can't have extras (`{name}`)")
+            }
+            ConstraintsErrorKind::Url { line, ref name } => {
+                write!(f, "Line {line}: Constraints can't have a URL (`{name}`)")
+            }
+            ConstraintsErrorKind::InvalidSpecifiers { line, ref err } => {
+                write!(f, "Line {line}: {err:#}")
+            }
+        }
+    }
+}
+
+/// The kind of error that occurs when parsing a constraints file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum ConstraintsErrorKind {
+    /// The line doesn't start with a package name.
+    InvalidLine { line: usize, text: String },
+    /// The constraint has extras, e.g. `foo[bar]`.
+    Extras { line: usize, name: String },
+    /// The constraint has a URL, e.g. `foo @ https://...`.
+    Url { line: usize, name: String },
+    /// The version specifiers are invalid. The span of the error points into the line with the
+    /// continuations joined.
+    InvalidSpecifiers {
+        line: usize,
+        err: VersionSpecifiersParseError,
+    },
+}
+
+impl From<ConstraintsErrorKind> for ConstraintsFileError {
+    fn from(kind: ConstraintsErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use indoc::indoc;
+
+use super::*;
+
+#[test]
+fn parse() {
+    let input = indoc! {r#"
+        # Constraints for CI
+        --index-url https://example.com/simple
+
+        requests >=2.0  # below 2.0 is broken
+        Requests<3
+        numpy==1.26.* ; python_version >= "3.9"
+        foo_bar (>=1.0)
+        urllib3 >=1.26, \
+            <2
+        six
+    "#};
+    let constraints = ConstraintsFile::from_str(input).unwrap();
+    assert_eq!(constraints.len(), 5);
+    assert_eq!(constraints.specifiers("requests").to_string(), ">=2.0, <3");
+    assert_eq!(constraints.specifiers("Foo.Bar").to_string(), ">=1.0");
+    assert_eq!(constraints.specifiers("urllib3").to_string(), ">=1.26, <2");
+    assert!(constraints.specifiers("six").is_empty());
+    assert!(constraints.get("missing").is_empty());
+
+    // Constraints with a marker are kept, but not combined
+    assert!(constraints.specifiers("numpy").is_empty());
+    let numpy = &constraints.get("numpy")[0];
+    assert_eq!(numpy.marker(), Some(r#"python_version >= "3.9""#));
+    assert_eq!(numpy.specifiers().to_string(), "==1.26.*");
+
+    let requests = constraints.get("requests");
+    assert_eq!(requests[0].name(), "requests");
+    assert_eq!(requests[0].line(), 4);
+    assert_eq!(
+        &input[requests[0].span()],
+        "requests >=2.0  # below 2.0 is broken"
+    );
+    assert_eq!(requests[1].line(), 5);
+
+    let urllib3 = &constraints.get("urllib3")[0];
+    assert_eq!(urllib3.line(), 8);
+    assert_eq!(&input[urllib3.span()], "urllib3 >=1.26, \\\n    <2");
+
+    let names: Vec<&str> = constraints.iter().map(Constraint::name).collect();
+    assert_eq!(
+        names,
+        ["foo_bar", "numpy", "requests", "Requests", "six", "urllib3"]
+    );
+}
+
+#[test]
+fn empty() {
+    assert!(ConstraintsFile::from_str("").unwrap().is_empty());
+    assert!(ConstraintsFile::from_str("# comment\n\n")
+        .unwrap()
+        .is_empty());
+    // A trailing continuation
+    let constraints = ConstraintsFile::from_str("foo >=1 \\").unwrap();
+    assert_eq!(constraints.specifiers("foo").to_string(), ">=1");
+}
+
+#[test]
+fn errors() {
+    let err = ConstraintsFile::from_str("foo >=1\nfoo[bar] <2").unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert_eq!(
+        err.to_string(),
+        "Line 2: Constraints can't have extras (`foo`)"
+    );
+
+    let err = ConstraintsFile::from_str("foo @ https://example.com/foo.tar.gz").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Line 1: Constraints can't have a URL (`foo`)"
+    );
+
+    let err = ConstraintsFile::from_str(">=1.0").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Line 1: Expected a package name, found `>=1.0`"
+    );
+
+    let err = ConstraintsFile::from_str("\nfoo >=1.0, \\\n  2.0").unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert_eq!(
+        err.to_string(),
+        "Line 2: error at 10..16: Unexpected end of version specifier, expected operator"
+    );
+}
@@ -0,0 +1,303 @@
+//! Editor diagnostics for requirements and `requires-python` values.
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::{
+    Boundedness, Operator, RequirementParts, Version, VersionSpecifier, VersionSpecifiers,
+};
+
+/// How serious a [`Diagnostic`] is, matching the severities of the language server protocol.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The input is invalid or can't be satisfied.
+    Error,
+    /// The input is valid, but likely not what was intended.
+    Warning,
+    /// A suggestion, such as adding an upper bound.
+    Hint,
+}
+
+/// A replacement of a byte range in the input, such as a quick fix for a [`Diagnostic`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Edit {
+    /// The byte range in the input to replace, which is empty for an insertion.
+    pub range: Range<usize>,
+    /// The text to insert instead.
+    pub replacement: String,
+}
+
+impl Edit {
+    /// Apply the edit to the input that the diagnostics were computed for.
+    pub fn apply(&self, input: &str) -> String {
+        let mut output = input.to_string();
+        output.replace_range(self.range.clone(), &self.replacement);
+        output
+    }
+}
+
+/// A problem in the input, see [`diagnostics`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Diagnostic {
+    /// The byte range in the input that the diagnostic refers to.
+    pub range: Range<usize>,
+    /// How serious the problem is.
+    pub severity: Severity,
+    /// A machine-readable code, e.g. `non-canonical`.
+    pub code: &'static str,
+    /// A human-readable description of the problem.
+    pub message: String,
+    /// An edit that fixes the problem, if there is an unambiguous one.
+    pub fix: Option<Edit>,
+}
+
+/// Check requirements or `requires-python` values, one per line, for problems an editor should
+/// show.
+///
+/// Lines may either be requirements such as `foo[bar] >=1.0, <2 ; python_version < "3.9"` or bare
+/// specifiers such as a `requires-python` value (`>=3.8`). Comments, blank lines and pip options
+/// (`-r other.txt`) are skipped, as are requirements with a URL.
+///
+/// The codes are:
+///
+/// * `invalid` (error): A specifier doesn't parse.
+/// * `operator-typo` (error): `=>`, `=<` or `=` instead of `>=`, `<=` or `==`, with a fix.
+/// * `missing-operator` (error): A version without an operator, with a fix that adds `==`.
+/// * `contradiction` (error): No version can satisfy all specifiers. Without the `version-ranges`
+///   feature, only contradicting bounds are detected, e.g. `>2, <1` but not `==1.0, !=1.0`.
+/// * `non-canonical` (warning): The version isn't normalized, with a fix that normalizes it.
+/// * `arbitrary-equality` (warning): `===` is used.
+/// * `unbounded` (warning): There is neither a lower nor an upper bound.
+/// * `no-upper-bound` (hint): There is only a lower bound, with a fix that adds `<` the next
+///   major version.
+///
+/// The ranges are byte offsets into the input, which a language server has to convert to its
+/// position encoding.
+///
+/// ```rust
+/// use pep440_rs::{diagnostics, Severity};
+///
+/// let input = "requests =>2.0, <3\nnumpy >=1.26.0";
+/// let diagnostics = diagnostics(input);
+/// assert_eq!(diagnostics[0].code, "operator-typo");
+/// assert_eq!(diagnostics[0].severity, Severity::Error);
+/// let fixed = diagnostics[0].fix.as_ref().unwrap().apply(input);
+/// assert_eq!(fixed, "requests >=2.0, <3\nnumpy >=1.26.0");
+/// assert_eq!(diagnostics[1].code, "no-upper-bound");
+/// ```
+pub fn diagnostics(input: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if let Some(specifiers) = specifier_span(line) {
+            check_specifiers(
+                input,
+                offset + specifiers.start..offset + specifiers.end,
+                &mut diagnostics,
+            );
+        }
+        offset += line.len();
+    }
+    diagnostics
+}
+
+/// The byte range of the version specifiers in a requirement or constraint line, or `None` if
+/// the line doesn't constrain a version.
+fn specifier_span(line: &str) -> Option<Range<usize>> {
+    // Strip comments
+    let line = line.split('#').next().unwrap_or_default().trim_end();
+    let start = line.len() - line.trim_start().len();
+    let content = &line[start..];
+    if content.is_empty() || content.starts_with('-') {
+        return None;
+    }
+    // A `requires-python` value or a bare specifier
+    if content.starts_with(['=', '!', '~', '<', '>']) {
+        let end = content.find(';').unwrap_or(content.len());
+        let end = content[..end].trim_end().len();
+        return Some(start..start + end);
+    }
+    let parts = RequirementParts::split(content)?;
+    if parts.url().is_some() {
+        return None;
+    }
+    let span = parts.specifiers_span();
+    Some(start + span.start..start + span.end)
+}
+
+fn check_specifiers(input: &str, span: Range<usize>, diagnostics: &mut Vec<Diagnostic>) {
+    let mut parsed = Vec::new();
+    let mut valid = true;
+    let mut clause_start = span.start;
+    for clause in input[span.clone()].split(',') {
+        let start = clause_start + (clause.len() - clause.trim_start().len());
+        let end = clause_start + clause.trim_end().len();
+        clause_start += clause.len() + 1;
+        if start >= end {
+            continue;
+        }
+        match check_clause(input, start..end, diagnostics) {
+            Some(specifier) => parsed.push(specifier),
+            None => valid = false,
+        }
+    }
+    if !valid {
+        return;
+    }
+
+    let specifiers = VersionSpecifiers::from_iter(parsed);
+    if is_unsatisfiable(&specifiers) {
+        diagnostics.push(Diagnostic {
+            range: span.clone(),
+            severity: Severity::Error,
+            code: "contradiction",
+            message: format!("`{}` can't be satisfied by any version", &input[span]),
+            fix: None,
+        });
+        return;
+    }
+    match specifiers.boundedness() {
+        Boundedness::Unbounded => diagnostics.push(Diagnostic {
+            range: span.clone(),
+            severity: Severity::Warning,
+            code: "unbounded",
+            message: if span.is_empty() {
+                "Missing version constraint".to_string()
+            } else {
+                format!("`{}` has neither a lower nor an upper bound", &input[span])
+            },
+            fix: None,
+        }),
+        Boundedness::LowerOnly(lower) => {
+            let major = lower.version().release()[0];
+            let upper = Version::new([major.saturating_add(1)]).with_epoch(lower.version().epoch());
+            diagnostics.push(Diagnostic {
+                range: span.clone(),
+                severity: Severity::Hint,
+                code: "no-upper-bound",
+                message: format!("`{}` has no upper bound", &input[span.clone()]),
+                fix: Some(Edit {
+                    range: span.end..span.end,
+                    replacement: format!(", <{upper}"),
+                }),
+            });
+        }
+        Boundedness::Pinned(_) | Boundedness::Bounded { .. } | Boundedness::UpperOnly(_) => {}
+    }
+}
+
+/// Whether no version can satisfy the specifiers.
+///
+/// With the `version-ranges` feature, exclusions count, too, e.g. for `==1.0, !=1.0`, otherwise
+/// only the bounds are compared.
+fn is_unsatisfiable(specifiers: &VersionSpecifiers) -> bool {
+    #[cfg(feature = "version-ranges")]
+    {
+        version_ranges::Ranges::<Version>::from(specifiers.clone()).is_empty()
+    }
+    #[cfg(not(feature = "version-ranges"))]
+    {
+        specifiers.bounding_range().is_none()
+    }
+}
+
+/// Check a single clause, returning the specifier if it parses.
+fn check_clause(
+    input: &str,
+    span: Range<usize>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<VersionSpecifier> {
+    let clause = &input[span.clone()];
+    let typo = if clause.starts_with("=>") {
+        Some((2, ">="))
+    } else if clause.starts_with("=<") {
+        Some((2, "<="))
+    } else if clause.starts_with('=') && !clause.starts_with("==") {
+        Some((1, "=="))
+    } else {
+        None
+    };
+    if let Some((len, operator)) = typo {
+        diagnostics.push(Diagnostic {
+            range: span.start..span.start + len,
+            severity: Severity::Error,
+            code: "operator-typo",
+            message: format!(
+                "`{}` is not an operator, use `{operator}` instead",
+                &clause[..len]
+            ),
+            fix: Some(Edit {
+                range: span.start..span.start + len,
+                replacement: operator.to_string(),
+            }),
+        });
+        return None;
+    }
+    if clause.starts_with(|c: char| c.is_ascii_digit()) {
+        diagnostics.push(Diagnostic {
+            range: span.clone(),
+            severity: Severity::Error,
+            code: "missing-operator",
+            message: format!("`{clause}` is missing an operator, such as `=={clause}`"),
+            fix: Some(Edit {
+                range: span.start..span.start,
+                replacement: "==".to_string(),
+            }),
+        });
+        return None;
+    }
+
+    let specifier = match VersionSpecifier::from_str(clause) {
+        Ok(specifier) => specifier,
+        Err(err) => {
+            diagnostics.push(Diagnostic {
+                range: span,
+                severity: Severity::Error,
+                code: "invalid",
+                message: format!("Failed to parse `{clause}`: {err}"),
+                fix: None,
+            });
+            return None;
+        }
+    };
+    if *specifier.operator() == Operator::ExactEqual {
+        diagnostics.push(Diagnostic {
+            range: span.clone(),
+            severity: Severity::Warning,
+            code: "arbitrary-equality",
+            message: format!(
+                "`{clause}` uses arbitrary equality (`===`), which is heavily discouraged"
+            ),
+            fix: None,
+        });
+    } else {
+        let operator_len = clause.len()
+            - clause
+                .trim_start_matches(['=', '!', '~', '<', '>'])
+                .trim_start()
+                .len();
+        let version = &clause[operator_len..];
+        let canonical = if specifier.operator().is_star() {
+            format!("{}.*", specifier.version())
+        } else {
+            specifier.version().to_string()
+        };
+        if version != canonical {
+            let range = span.start + operator_len..span.end;
+            diagnostics.push(Diagnostic {
+                range: range.clone(),
+                severity: Severity::Warning,
+                code: "non-canonical",
+                message: format!("`{version}` is not normalized, use `{canonical}` instead"),
+                fix: Some(Edit {
+                    range,
+                    replacement: canonical,
+                }),
+            });
+        }
+    }
+    Some(specifier)
+}
+
+#[cfg(test)]
+mod tests;
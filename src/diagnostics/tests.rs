@@ -0,0 +1,109 @@
+use super::*;
+
+/// The code, the text the range points to and the input with the fix applied.
+fn check(input: &str) -> Vec<(&'static str, &str, Option<String>)> {
+    diagnostics(input)
+        .into_iter()
+        .map(|diagnostic| {
+            (
+                diagnostic.code,
+                &input[diagnostic.range],
+                diagnostic.fix.map(|fix| fix.apply(input)),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn clean() {
+    let input = indoc::indoc! {"
+        # Comment
+        -r other.txt
+        requests >=2.0, <3
+        numpy[extra] ~=1.26 ; python_version >= '3.9'
+        foo @ https://example.com/foo.tar.gz
+        ==3.12.*
+    "};
+    assert_eq!(check(input), []);
+}
+
+#[test]
+fn fixes() {
+    assert_eq!(
+        check("foo =>1.0, <2"),
+        [("operator-typo", "=>", Some("foo >=1.0, <2".to_string()))]
+    );
+    assert_eq!(
+        check("foo >1, =<2"),
+        [("operator-typo", "=<", Some("foo >1, <=2".to_string()))]
+    );
+    assert_eq!(
+        check("=3.12"),
+        [("operator-typo", "=", Some("==3.12".to_string()))]
+    );
+    assert_eq!(
+        check("foo (>=1, 2.0)"),
+        [(
+            "missing-operator",
+            "2.0",
+            Some("foo (>=1, ==2.0)".to_string())
+        )]
+    );
+    assert_eq!(
+        check("foo >= 01.0-alpha1, <2"),
+        [(
+            "non-canonical",
+            "01.0-alpha1",
+            Some("foo >= 1.0a1, <2".to_string())
+        )]
+    );
+    assert_eq!(
+        check(">=3.8 # comment"),
+        [(
+            "no-upper-bound",
+            ">=3.8",
+            Some(">=3.8, <4 # comment".to_string())
+        )]
+    );
+    assert_eq!(
+        check("foo >1!2.5"),
+        [(
+            "no-upper-bound",
+            ">1!2.5",
+            Some("foo >1!2.5, <1!3".to_string())
+        )]
+    );
+}
+
+#[test]
+fn without_fixes() {
+    assert_eq!(check("foo >=2, <1"), [("contradiction", ">=2, <1", None)]);
+    assert_eq!(check("foo"), [("unbounded", "", None)]);
+    assert_eq!(check("foo !=1.0"), [("unbounded", "!=1.0", None)]);
+    assert_eq!(
+        check("foo ===1.0"),
+        [("arbitrary-equality", "===1.0", None)]
+    );
+    assert_eq!(
+        check("foo >=1.0, <2.0+cpu"),
+        [("invalid", "<2.0+cpu", None)]
+    );
+}
+
+#[cfg(feature = "version-ranges")]
+#[test]
+fn contradiction_with_exclusion() {
+    assert_eq!(
+        check("foo ==1.0, !=1.0"),
+        [("contradiction", "==1.0, !=1.0", None)]
+    );
+}
+
+#[test]
+fn ranges_across_lines() {
+    let input = "foo >=1, <2\r\nbar >=1.0, <2 , ==1.0-1\n";
+    let diagnostics = diagnostics(input);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(&input[diagnostics[0].range.clone()], "1.0-1");
+}
@@ -0,0 +1,93 @@
+//! Convert versions to the version strings of Linux distributions.
+//!
+//! Python packages in distributions keep the upstream version, but PEP 440 pre-, post- and dev
+//! releases have to be spelled so that the distribution's version comparison orders them
+//! correctly: `1.0rc1` must come before `1.0`, which plain `1.0rc1` doesn't in either `dpkg` or
+//! `rpm`.
+
+use std::fmt::Write;
+
+use crate::{LocalSegment, Version};
+
+impl Version {
+    /// The version as a Debian upstream version, following the `~` convention of the Debian
+    /// Python policy.
+    ///
+    /// * A non-zero epoch becomes the Debian epoch, e.g. `1!2.0` is `1:2.0`.
+    /// * Pre-releases are prefixed with `~`, e.g. `1.0rc1` is `1.0~rc1`.
+    /// * Post-releases use `+post`, e.g. `1.0.post1` is `1.0+post1`.
+    /// * Dev releases use `~dev`, and `~~dev` for dev releases of a final release, so that they
+    ///   sort before its pre-releases, e.g. `1.0.dev1` is `1.0~~dev1`.
+    /// * Local versions are kept, e.g. `1.0+cpu` is `1.0+cpu`.
+    ///
+    /// `dpkg --compare-versions` orders the converted versions like PEP 440 does, as long as they
+    /// don't have a local version and trailing zeros in the release don't make the difference:
+    /// `1.0` and `1.1` keep their order, but `1.0.0a1` sorts after `1.0` in Debian.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let version = Version::from_str("1!2.0rc1.post2.dev3").unwrap();
+    /// assert_eq!(version.to_debian_version(), "1:2.0~rc1+post2~dev3");
+    /// ```
+    pub fn to_debian_version(&self) -> String {
+        self.to_distro_version("+post")
+    }
+
+    /// The version as an RPM version, following the Fedora Python packaging guidelines.
+    ///
+    /// * A non-zero epoch becomes the RPM epoch, e.g. `1!2.0` is `1:2.0`.
+    /// * Pre-releases are prefixed with `~`, e.g. `1.0rc1` is `1.0~rc1`.
+    /// * Post-releases use `^post`, e.g. `1.0.post1` is `1.0^post1`.
+    /// * Dev releases use `~dev`, and `~~dev` for dev releases of a final release, so that they
+    ///   sort before its pre-releases, e.g. `1.0.dev1` is `1.0~~dev1`.
+    /// * Local versions are kept, e.g. `1.0+cpu` is `1.0+cpu`.
+    ///
+    /// `rpmvercmp` orders the converted versions like PEP 440 does, with the same limitations as
+    /// [`Version::to_debian_version`].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let version = Version::from_str("2.0.post1").unwrap();
+    /// assert_eq!(version.to_rpm_version(), "2.0^post1");
+    /// ```
+    pub fn to_rpm_version(&self) -> String {
+        self.to_distro_version("^post")
+    }
+
+    /// Write the version with `post_prefix` as prefix of the post-release number.
+    fn to_distro_version(&self, post_prefix: &str) -> String {
+        let mut output = String::new();
+        if self.epoch() != 0 {
+            write!(output, "{}:", self.epoch()).unwrap();
+        }
+        write!(output, "{}", self.release()).unwrap();
+        if let Some(pre) = self.pre() {
+            write!(output, "~{}{}", pre.kind, pre.number).unwrap();
+        }
+        if let Some(post) = self.post() {
+            write!(output, "{post_prefix}{post}").unwrap();
+        }
+        if let Some(dev) = self.dev() {
+            if self.is_pre() || self.is_post() {
+                write!(output, "~dev{dev}").unwrap();
+            } else {
+                write!(output, "~~dev{dev}").unwrap();
+            }
+        }
+        for (idx, segment) in self.local().iter().enumerate() {
+            output.push(if idx == 0 { '+' } else { '.' });
+            match segment {
+                LocalSegment::String(string) => output.push_str(string),
+                LocalSegment::Number(number) => write!(output, "{number}").unwrap(),
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use super::*;
+
+/// Compare Debian versions without revision like `dpkg --compare-versions`.
+fn dpkg_cmp(a: &str, b: &str) -> Ordering {
+    let (epoch_a, a) = split_epoch(a);
+    let (epoch_b, b) = split_epoch(b);
+    epoch_a.cmp(&epoch_b).then_with(|| dpkg_upstream_cmp(a, b))
+}
+
+/// `verrevcmp` from dpkg.
+fn dpkg_upstream_cmp(a: &str, b: &str) -> Ordering {
+    fn order(byte: Option<&u8>) -> i32 {
+        match byte {
+            None => 0,
+            Some(b'~') => -1,
+            Some(byte) if byte.is_ascii_digit() => 0,
+            Some(byte) if byte.is_ascii_alphabetic() => i32::from(*byte),
+            Some(byte) => i32::from(*byte) + 256,
+        }
+    }
+    let is_digit = |byte: Option<&u8>| byte.is_some_and(u8::is_ascii_digit);
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        while (i < a.len() && !is_digit(a.get(i))) || (j < b.len() && !is_digit(b.get(j))) {
+            let (order_a, order_b) = (order(a.get(i)), order(b.get(j)));
+            if order_a != order_b {
+                return order_a.cmp(&order_b);
+            }
+            i += 1;
+            j += 1;
+        }
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(j) == Some(&b'0') {
+            j += 1;
+        }
+        let mut first_diff = Ordering::Equal;
+        while is_digit(a.get(i)) && is_digit(b.get(j)) {
+            if first_diff == Ordering::Equal {
+                first_diff = a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+        if is_digit(a.get(i)) {
+            return Ordering::Greater;
+        }
+        if is_digit(b.get(j)) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compare RPM versions with an optional epoch like `rpmvercmp`.
+fn rpm_cmp(a: &str, b: &str) -> Ordering {
+    let (epoch_a, a) = split_epoch(a);
+    let (epoch_b, b) = split_epoch(b);
+    epoch_a.cmp(&epoch_b).then_with(|| rpmvercmp(a, b))
+}
+
+/// `rpmvercmp` from rpm.
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    let separator = |c: char| !c.is_ascii_alphanumeric() && c != '~' && c != '^';
+    let (mut a, mut b) = (a, b);
+    loop {
+        a = a.trim_start_matches(separator);
+        b = b.trim_start_matches(separator);
+        if a.starts_with('~') || b.starts_with('~') {
+            if !a.starts_with('~') {
+                return Ordering::Greater;
+            }
+            if !b.starts_with('~') {
+                return Ordering::Less;
+            }
+            a = &a[1..];
+            b = &b[1..];
+            continue;
+        }
+        if a.starts_with('^') || b.starts_with('^') {
+            if a.is_empty() {
+                return Ordering::Less;
+            }
+            if b.is_empty() {
+                return Ordering::Greater;
+            }
+            if !a.starts_with('^') {
+                return Ordering::Greater;
+            }
+            if !b.starts_with('^') {
+                return Ordering::Less;
+            }
+            a = &a[1..];
+            b = &b[1..];
+            continue;
+        }
+        if a.is_empty() || b.is_empty() {
+            break;
+        }
+        let numeric = a.starts_with(|c: char| c.is_ascii_digit());
+        let in_segment = |c: char| {
+            if numeric {
+                c.is_ascii_digit()
+            } else {
+                c.is_ascii_alphabetic()
+            }
+        };
+        let end_a = a.find(|c| !in_segment(c)).unwrap_or(a.len());
+        let end_b = b.find(|c| !in_segment(c)).unwrap_or(b.len());
+        let (segment_a, segment_b) = (&a[..end_a], &b[..end_b]);
+        if segment_b.is_empty() {
+            return if numeric {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        let ordering = if numeric {
+            let segment_a = segment_a.trim_start_matches('0');
+            let segment_b = segment_b.trim_start_matches('0');
+            segment_a
+                .len()
+                .cmp(&segment_b.len())
+                .then_with(|| segment_a.cmp(segment_b))
+        } else {
+            segment_a.cmp(segment_b)
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        a = &a[end_a..];
+        b = &b[end_b..];
+    }
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, _) => Ordering::Greater,
+    }
+}
+
+fn split_epoch(version: &str) -> (u64, &str) {
+    match version.split_once(':') {
+        Some((epoch, version)) => (epoch.parse().unwrap(), version),
+        None => (0, version),
+    }
+}
+
+#[test]
+fn conversions() {
+    let cases = [
+        ("1.0", "1.0", "1.0"),
+        ("1!2.0", "1:2.0", "1:2.0"),
+        ("1.0a1", "1.0~a1", "1.0~a1"),
+        ("1.0b2", "1.0~b2", "1.0~b2"),
+        ("1.0rc3", "1.0~rc3", "1.0~rc3"),
+        ("1.0.post1", "1.0+post1", "1.0^post1"),
+        ("1.0.dev1", "1.0~~dev1", "1.0~~dev1"),
+        ("1.0a1.dev1", "1.0~a1~dev1", "1.0~a1~dev1"),
+        ("1.0.post1.dev1", "1.0+post1~dev1", "1.0^post1~dev1"),
+        ("1.0+cpu.1", "1.0+cpu.1", "1.0+cpu.1"),
+    ];
+    for (input, debian, rpm) in cases {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(version.to_debian_version(), debian, "{input}");
+        assert_eq!(version.to_rpm_version(), rpm, "{input}");
+    }
+}
+
+/// The comparisons themselves, and why the conversion is needed.
+#[test]
+fn distro_comparisons() {
+    assert_eq!(dpkg_cmp("1.0rc1", "1.0"), Ordering::Greater);
+    assert_eq!(dpkg_cmp("1.0~rc1", "1.0"), Ordering::Less);
+    assert_eq!(dpkg_cmp("1:0.1", "2.0"), Ordering::Greater);
+    assert_eq!(rpm_cmp("1.0rc1", "1.0"), Ordering::Greater);
+    assert_eq!(rpm_cmp("1.0^post1", "1.0.1"), Ordering::Less);
+    assert_eq!(rpm_cmp("1.0^post1", "1.0"), Ordering::Greater);
+}
+
+/// The distribution version comparisons agree with PEP 440 for all pairs.
+#[test]
+fn ordering_preserved() {
+    let mut versions = Vec::new();
+    for release in ["1.0", "1.1", "1.10", "2.0", "1!0.5"] {
+        for suffix in [
+            "",
+            ".dev0",
+            ".dev1",
+            "a1.dev1",
+            "a1",
+            "a1.post1",
+            "a1.post1.dev1",
+            "a2",
+            "b1",
+            "rc1.dev1",
+            "rc1",
+            "rc1.post1",
+            ".post1.dev0",
+            ".post1",
+            ".post1.dev1",
+            ".post2",
+        ] {
+            versions.push(Version::from_str(&format!("{release}{suffix}")).unwrap());
+        }
+    }
+    for a in &versions {
+        for b in &versions {
+            let (debian_a, debian_b) = (a.to_debian_version(), b.to_debian_version());
+            assert_eq!(
+                dpkg_cmp(&debian_a, &debian_b),
+                a.cmp(b),
+                "{a} ({debian_a}) vs. {b} ({debian_b})"
+            );
+            let (rpm_a, rpm_b) = (a.to_rpm_version(), b.to_rpm_version());
+            assert_eq!(
+                rpm_cmp(&rpm_a, &rpm_b),
+                a.cmp(b),
+                "{a} ({rpm_a}) vs. {b} ({rpm_b})"
+            );
+        }
+    }
+}
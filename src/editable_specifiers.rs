@@ -0,0 +1,268 @@
+//! A lossless representation of a specifier list for editing it in place.
+
+use std::str::FromStr;
+
+use crate::{
+    Operator, Version, VersionSpecifier, VersionSpecifierBuildError, VersionSpecifiers,
+    VersionSpecifiersParseError,
+};
+
+/// A specifier list that remembers how it was written, including the whitespace, the spelling of
+/// the operators and versions and a trailing `#` comment.
+///
+/// Displaying it returns the input unchanged, and the edit operations only touch the part of the
+/// string they change, so tools that edit dependency declarations don't reformat the rest of the
+/// user's file.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{EditableSpecifiers, Version};
+///
+/// let mut specifiers = EditableSpecifiers::from_str(">= 1.16 ,<2.0  # numpy 2 breaks us").unwrap();
+/// specifiers.set_version(0, Version::from_str("1.18").unwrap()).unwrap();
+/// specifiers.exclude(Version::from_str("1.19.1").unwrap());
+/// assert_eq!(specifiers.to_string(), ">= 1.18 ,<2.0,!=1.19.1  # numpy 2 breaks us");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditableSpecifiers {
+    /// The comma separated clauses, in the order they were written.
+    clauses: Vec<Clause>,
+    /// The whitespace of a list without clauses.
+    blank: String,
+    /// A trailing comment, starting with `#`.
+    comment: Option<String>,
+}
+
+/// A single clause such as ` >= 1.16 `, split into its tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Clause {
+    /// The whitespace before the operator.
+    leading: String,
+    /// The operator as written, e.g. `==` in `==1.0.*`.
+    operator: String,
+    /// The whitespace between the operator and the version.
+    space: String,
+    /// The version as written, including a trailing `.*`.
+    version: String,
+    /// The whitespace after the version.
+    trailing: String,
+    /// The parsed clause.
+    specifier: VersionSpecifier,
+}
+
+impl Clause {
+    /// Split a clause that [`VersionSpecifier::from_str`] accepted into its tokens.
+    fn parse(text: &str, specifier: VersionSpecifier) -> Self {
+        let (leading, rest) = split_whitespace_prefix(text);
+        let operator_len = rest
+            .find(|c: char| !matches!(c, '=' | '!' | '~' | '<' | '>'))
+            .unwrap_or(rest.len());
+        let (operator, rest) = rest.split_at(operator_len);
+        let (space, rest) = split_whitespace_prefix(rest);
+        let version_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (version, trailing) = rest.split_at(version_len);
+        Self {
+            leading: leading.to_string(),
+            operator: operator.to_string(),
+            space: space.to_string(),
+            version: version.to_string(),
+            trailing: trailing.to_string(),
+            specifier,
+        }
+    }
+
+    /// A new clause in the normalized form of the specifier.
+    fn from_specifier(leading: String, trailing: String, specifier: VersionSpecifier) -> Self {
+        let operator = specifier.operator().to_string();
+        let version = specifier.to_string()[operator.len()..].to_string();
+        Self {
+            leading,
+            operator,
+            space: String::new(),
+            version,
+            trailing,
+            specifier,
+        }
+    }
+}
+
+/// Split `text` into its leading whitespace and the rest.
+fn split_whitespace_prefix(text: &str) -> (&str, &str) {
+    let rest = text.trim_start();
+    text.split_at(text.len() - rest.len())
+}
+
+impl EditableSpecifiers {
+    /// The number of clauses.
+    pub fn len(&self) -> usize {
+        self.clauses.len()
+    }
+
+    /// Returns `true` if there are no clauses.
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// The clauses in the order they were written.
+    pub fn iter(&self) -> impl Iterator<Item = &VersionSpecifier> {
+        self.clauses.iter().map(|clause| &clause.specifier)
+    }
+
+    /// The clause at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&VersionSpecifier> {
+        self.clauses.get(index).map(|clause| &clause.specifier)
+    }
+
+    /// All clauses as normalized [`VersionSpecifiers`].
+    pub fn specifiers(&self) -> VersionSpecifiers {
+        self.iter().cloned().collect()
+    }
+
+    /// The trailing comment including the `#`, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Replace the version of the clause at `index`, e.g. to raise a lower bound, keeping the
+    /// operator and the whitespace as written.
+    ///
+    /// Returns an error if the operator doesn't allow the version, e.g. a local version with
+    /// `>=`. Wildcard operators keep their `.*` and only allow a version with an epoch and a
+    /// release, e.g. `2.0` but not `2.0rc1`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    pub fn set_version(
+        &mut self,
+        index: usize,
+        version: Version,
+    ) -> Result<(), VersionSpecifierBuildError> {
+        let clause = &mut self.clauses[index];
+        let operator = *clause.specifier.operator();
+        let mut specifier = VersionSpecifier::from_version(operator, version)?;
+        let version = specifier.version().to_string();
+        clause.version = match operator {
+            Operator::EqualStar | Operator::NotEqualStar => format!("{version}.*"),
+            _ => version,
+        };
+        if operator == Operator::ExactEqual {
+            specifier.verbatim = Some(clause.version.as_str().into());
+        }
+        clause.specifier = specifier;
+        Ok(())
+    }
+
+    /// Append a clause, in the normalized form and separated like the existing clauses.
+    ///
+    /// Whitespace before a trailing comment stays in front of the comment.
+    pub fn push(&mut self, specifier: VersionSpecifier) {
+        let Some(last) = self.clauses.last_mut() else {
+            let trailing = std::mem::take(&mut self.blank);
+            self.clauses
+                .push(Clause::from_specifier(String::new(), trailing, specifier));
+            return;
+        };
+        let trailing = std::mem::take(&mut last.trailing);
+        // Follow the existing style after commas, or the normalized `, ` for a single clause
+        let leading = match self.clauses.get(1) {
+            Some(second) => second.leading.clone(),
+            None => " ".to_string(),
+        };
+        self.clauses
+            .push(Clause::from_specifier(leading, trailing, specifier));
+    }
+
+    /// Add a `!=<version>` clause, unless the version is already excluded by an equal `!=`
+    /// clause.
+    pub fn exclude(&mut self, version: Version) {
+        let excluded = self.iter().any(|specifier| {
+            *specifier.operator() == Operator::NotEqual && *specifier.version() == version
+        });
+        if !excluded {
+            self.push(VersionSpecifier::not_equals_version(version));
+        }
+    }
+
+    /// Remove and return the clause at `index`, together with its comma.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> VersionSpecifier {
+        let removed = self.clauses.remove(index);
+        if self.clauses.is_empty() {
+            self.blank = removed.leading + &removed.trailing;
+        } else if index == 0 {
+            // The new first clause takes the whitespace at the start of the list
+            self.clauses[0].leading = removed.leading;
+        } else if index == self.clauses.len() {
+            // The new last clause takes the whitespace before the comment
+            self.clauses[index - 1].trailing = removed.trailing;
+        }
+        removed.specifier
+    }
+}
+
+impl FromStr for EditableSpecifiers {
+    type Err = VersionSpecifiersParseError;
+
+    /// Parse a specifier list with an optional trailing `#` comment.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (text, comment) = match input.find('#') {
+            Some(start) => (&input[..start], Some(input[start..].to_string())),
+            None => (input, None),
+        };
+        if text.trim().is_empty() {
+            return Ok(Self {
+                clauses: Vec::new(),
+                blank: text.to_string(),
+                comment,
+            });
+        }
+        // Parse with the regular parser for its error messages, the clauses are in input order
+        let specifiers = crate::version_specifier::parse_version_specifiers(text)?;
+        let clauses = text
+            .split(',')
+            .zip(specifiers)
+            .map(|(text, specifier)| Clause::parse(text, specifier))
+            .collect();
+        Ok(Self {
+            clauses,
+            blank: String::new(),
+            comment,
+        })
+    }
+}
+
+/// Writes the list as it was written, with the edits applied.
+impl std::fmt::Display for EditableSpecifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.clauses.is_empty() {
+            f.write_str(&self.blank)?;
+        }
+        for (idx, clause) in self.clauses.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            write!(
+                f,
+                "{}{}{}{}{}",
+                clause.leading, clause.operator, clause.space, clause.version, clause.trailing
+            )?;
+        }
+        if let Some(comment) = &self.comment {
+            f.write_str(comment)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&EditableSpecifiers> for VersionSpecifiers {
+    fn from(specifiers: &EditableSpecifiers) -> Self {
+        specifiers.specifiers()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use crate::SpecifierErrorKind;
+
+use super::*;
+
+fn editable(input: &str) -> EditableSpecifiers {
+    EditableSpecifiers::from_str(input).unwrap()
+}
+
+fn version(version: &str) -> Version {
+    Version::from_str(version).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    for input in [
+        "",
+        "   ",
+        "# only a comment",
+        ">=1.0",
+        " >= 1.0 , < 2.0 ",
+        ">=1.0,<2.0,!=1.5.*",
+        "~= 1.4.2  # compatible",
+        "== V1.0.*",
+        "===1.0.0",
+        "\t<3\t,\t>=2",
+    ] {
+        assert_eq!(editable(input).to_string(), input);
+    }
+    let specifiers = editable(" >= 1.0 , < 2.0 # comment");
+    assert_eq!(specifiers.len(), 2);
+    assert_eq!(specifiers.comment(), Some("# comment"));
+    assert_eq!(
+        specifiers.specifiers(),
+        VersionSpecifiers::from_str(">=1.0,<2.0").unwrap()
+    );
+}
+
+#[test]
+fn parse_error() {
+    let err = EditableSpecifiers::from_str(">=1.0, <2.0, foo  # comment").unwrap_err();
+    assert_eq!(
+        err,
+        VersionSpecifiers::from_str(">=1.0, <2.0, foo  ").unwrap_err()
+    );
+}
+
+#[test]
+fn set_version() {
+    let mut specifiers = editable(">= 1.0 , == 2.1.*, ===1.0.0  # pinned");
+    specifiers.set_version(0, version("1.2")).unwrap();
+    specifiers.set_version(1, version("2.2")).unwrap();
+    specifiers.set_version(2, version("1.0")).unwrap();
+    assert_eq!(
+        specifiers.to_string(),
+        ">= 1.2 , == 2.2.*, ===1.0  # pinned"
+    );
+    assert_eq!(
+        specifiers.specifiers(),
+        VersionSpecifiers::from_str(">=1.2, ==2.2.*, ===1.0").unwrap()
+    );
+    assert!(specifiers.set_version(0, version("1.3+local")).is_err());
+    assert_eq!(
+        specifiers.to_string(),
+        ">= 1.2 , == 2.2.*, ===1.0  # pinned"
+    );
+}
+
+#[test]
+fn set_version_star() {
+    let mut specifiers = editable("!= 1.5.* ,== 2.*");
+    specifiers.set_version(0, version("1!1.6")).unwrap();
+    specifiers.set_version(1, version("3.0")).unwrap();
+    assert_eq!(specifiers.to_string(), "!= 1!1.6.* ,== 3.0.*");
+    // The wildcard must follow the release
+    for (invalid, kind) in [
+        ("2.0rc1", SpecifierErrorKind::StarNotReleaseOnly),
+        ("2.0.post1", SpecifierErrorKind::StarNotReleaseOnly),
+        ("2.0.dev1", SpecifierErrorKind::StarNotReleaseOnly),
+        ("2.0+local", SpecifierErrorKind::OperatorWithLocal),
+    ] {
+        let err = specifiers.set_version(1, version(invalid)).unwrap_err();
+        assert_eq!(err.kind(), kind, "{invalid}");
+    }
+    assert_eq!(specifiers.to_string(), "!= 1!1.6.* ,== 3.0.*");
+    // Everything that was written still parses
+    assert_eq!(
+        VersionSpecifiers::from_str(&specifiers.to_string()).unwrap(),
+        specifiers.specifiers()
+    );
+}
+
+#[test]
+fn push_and_exclude() {
+    let mut specifiers = editable(">=1.0");
+    specifiers.exclude(version("1.5"));
+    specifiers.exclude(version("1.5.0"));
+    assert_eq!(specifiers.to_string(), ">=1.0, !=1.5");
+
+    let mut specifiers = editable(">=1.0,<2  # comment");
+    specifiers.push(VersionSpecifier::from_str("!= 1.5.*").unwrap());
+    assert_eq!(specifiers.to_string(), ">=1.0,<2,!=1.5.*  # comment");
+
+    let mut specifiers = editable("  # comment");
+    specifiers.exclude(version("1.5"));
+    assert_eq!(specifiers.to_string(), "!=1.5  # comment");
+}
+
+#[test]
+fn remove() {
+    let mut specifiers = editable(" >=1.0 , <2 ,!=1.5  # comment");
+    assert_eq!(specifiers.remove(2).to_string(), "!=1.5");
+    assert_eq!(specifiers.to_string(), " >=1.0 , <2  # comment");
+    assert_eq!(specifiers.remove(0).to_string(), ">=1.0");
+    assert_eq!(specifiers.to_string(), " <2  # comment");
+    specifiers.remove(0);
+    assert_eq!(specifiers.to_string(), "   # comment");
+    assert!(specifiers.is_empty());
+
+    let mut specifiers = editable(">=1.0, <2, !=1.5");
+    specifiers.remove(1);
+    assert_eq!(specifiers.to_string(), ">=1.0, !=1.5");
+}
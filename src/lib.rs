@@ -12,6 +12,11 @@
 //! assert!(version_specifiers.contains(&version));
 //! ```
 //!
+//! Versions and specifiers serialize as strings with serde. Since all string-like types, such as
+//! [`Version`], [`VersionSpecifier`], [`VersionSpecifiers`], [`Operator`] and
+//! [`ReleaseOnlyVersion`], implement `Display` and `FromStr`, they also work with
+//! `#[serde_as(as = "DisplayFromStr")]` from `serde_with`, including in `Option` and `Vec`.
+//!
 //! PEP 440 has a lot of unintuitive features, including:
 //!
 //! * An epoch that you can prefix the version which, e.g. `1!1.2.3`. Lower epoch always means lower
@@ -34,23 +39,81 @@
 //!   the version matching needs to catch all sorts of special cases
 #![warn(missing_docs)]
 
+#[cfg(feature = "version-ranges")]
+pub use constraint_set::{Conflict, ConstraintSet};
+#[cfg(feature = "osv")]
+pub use osv::{OsvError, OsvEvent, OsvRange, OsvRangeKind};
+#[cfg(feature = "sampler")]
+pub use sampler::VersionSampler;
+#[cfg(feature = "version-ranges")]
+pub use specifiers_diff::{BoundChange, SpecifiersDiff};
 #[cfg(feature = "version-ranges")]
 pub use version_ranges::{release_specifier_to_range, release_specifiers_to_ranges};
 pub use {
+    codec::VersionBytesError,
+    compiled::{CompiledSpecifiers, PrereleasePolicy},
+    conda::{CondaSpecifiers, CondaSpecifiersError},
+    constraints_file::{Constraint, ConstraintsFile, ConstraintsFileError},
+    diagnostics::{diagnostics, Diagnostic, Edit, Severity},
+    editable_specifiers::EditableSpecifiers,
+    matcher::VersionMatcher,
+    release_only::ReleaseOnlyVersion,
+    requirement::RequirementParts,
+    scheme_migration::SchemeMigration,
+    statistics::{group_by_release, VersionStatistics},
     version::{
-        LocalSegment, Operator, OperatorParseError, Prerelease, PrereleaseKind, Version,
-        VersionParseError, VersionPattern, VersionPatternParseError, MIN_VERSION,
+        canonicalize_version, canonicalize_versions, is_valid_version, suggest_nearest, Bump,
+        CanonicalVersion, Channel, ComparisonReason, LocalSegment, Operator, OperatorParseError,
+        ParseWarning, Prerelease, PrereleaseKind, Release, Version, VersionErrorKind,
+        VersionParseError, VersionParts, VersionPartsError, VersionPattern,
+        VersionPatternParseError, MIN_VERSION,
     },
+    version_info::{PythonVersionInfo, ReleaseLevel, VersionInfoError},
+    version_map::VersionMap,
     version_specifier::{
-        VersionSpecifier, VersionSpecifierBuildError, VersionSpecifiers,
-        VersionSpecifiersParseError,
+        is_valid_specifier, is_valid_specifiers, Boundedness, MismatchReason, Pin,
+        SpecifierErrorKind, VersionSpecifier, VersionSpecifierBuildError,
+        VersionSpecifierParseError, VersionSpecifiers, VersionSpecifiersParseError,
     },
+    version_store::{VersionId, VersionStore},
 };
 
+#[cfg(feature = "capi")]
+pub mod capi;
+mod codec;
+mod compiled;
+// The component exports have names such as `konstin:pep440/pep440#parse-version`, which the host
+// linkers reject for the cdylib, so it's only built for WebAssembly
+#[cfg(all(feature = "component", target_arch = "wasm32"))]
+mod component;
+mod conda;
+#[cfg(feature = "version-ranges")]
+mod constraint_set;
+mod constraints_file;
+mod diagnostics;
+#[cfg(feature = "distro")]
+mod distro;
+mod editable_specifiers;
+mod matcher;
+#[cfg(feature = "osv")]
+mod osv;
+mod release_only;
+mod requirement;
+#[cfg(feature = "sampler")]
+mod sampler;
+mod scheme_migration;
+#[cfg(feature = "version-ranges")]
+mod specifiers_diff;
+mod statistics;
 mod version;
+mod version_info;
+mod version_map;
 mod version_specifier;
+mod version_store;
 
 #[cfg(test)]
 mod tests;
 #[cfg(feature = "version-ranges")]
 mod version_ranges;
+#[cfg(feature = "wasm")]
+mod wasm;
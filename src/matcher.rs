@@ -0,0 +1,65 @@
+//! A trait for anything that decides whether a version matches, so that marker evaluators and
+//! resolvers can accept any of this crate's specifier types.
+
+use crate::{CompiledSpecifiers, Version, VersionSpecifier, VersionSpecifiers};
+
+/// Decides whether a version matches, implemented by all specifier types of this crate.
+///
+/// Code that evaluates markers or filters candidates can be generic over this trait instead of
+/// depending on a concrete specifier type, so it keeps working when a caller switches from
+/// [`VersionSpecifiers`] to, e.g., [`CompiledSpecifiers`].
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{CompiledSpecifiers, Version, VersionMatcher, VersionSpecifiers};
+///
+/// fn matching<'a>(matcher: &impl VersionMatcher, versions: &'a [Version]) -> Vec<&'a Version> {
+///     versions.iter().filter(|version| matcher.matches(version)).collect()
+/// }
+///
+/// let versions = [Version::from_str("1.0").unwrap(), Version::from_str("2.0").unwrap()];
+/// let specifiers = VersionSpecifiers::from_str(">=1.5").unwrap();
+/// assert_eq!(matching(&specifiers, &versions), [&versions[1]]);
+/// assert_eq!(matching(&CompiledSpecifiers::new(&specifiers), &versions), [&versions[1]]);
+/// ```
+pub trait VersionMatcher {
+    /// Whether the version matches.
+    fn matches(&self, version: &Version) -> bool;
+}
+
+impl VersionMatcher for VersionSpecifier {
+    fn matches(&self, version: &Version) -> bool {
+        self.contains(version)
+    }
+}
+
+impl VersionMatcher for VersionSpecifiers {
+    fn matches(&self, version: &Version) -> bool {
+        self.contains(version)
+    }
+}
+
+impl VersionMatcher for CompiledSpecifiers {
+    fn matches(&self, version: &Version) -> bool {
+        self.contains(version)
+    }
+}
+
+#[cfg(feature = "version-ranges")]
+impl<S: Clone> VersionMatcher for crate::ConstraintSet<S> {
+    fn matches(&self, version: &Version) -> bool {
+        self.contains(version)
+    }
+}
+
+impl<T: VersionMatcher + ?Sized> VersionMatcher for &T {
+    fn matches(&self, version: &Version) -> bool {
+        (**self).matches(version)
+    }
+}
+
+impl<T: VersionMatcher + ?Sized> VersionMatcher for Box<T> {
+    fn matches(&self, version: &Version) -> bool {
+        (**self).matches(version)
+    }
+}
@@ -0,0 +1,196 @@
+//! Evaluate the affected ranges of [OSV](https://ossf.github.io/osv-schema/) vulnerability
+//! advisories for PyPI packages.
+
+use std::ops::Bound;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use version_ranges::Ranges;
+
+use crate::{Version, VersionParseError};
+
+/// A range from the `affected[].ranges` of an OSV advisory, which deserializes from the OSV JSON
+/// format.
+///
+/// The events are evaluated as the OSV schema describes: After sorting them by version, an
+/// `introduced` event starts an affected interval, which ends before a `fixed` event or after a
+/// `last_affected` event. Versions at or above a `limit` event are never affected. The version
+/// `0` in `introduced` means that all versions up to the next event are affected.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{OsvRange, Version};
+///
+/// let range: OsvRange = serde_json::from_str(r#"{
+///     "type": "ECOSYSTEM",
+///     "events": [{"introduced": "0"}, {"fixed": "2.31.0"}]
+/// }"#).unwrap();
+/// assert!(range.is_affected(&Version::from_str("2.31.0rc1").unwrap()).unwrap());
+/// assert!(!range.is_affected(&Version::from_str("2.31.0").unwrap()).unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OsvRange {
+    /// How the versions in the events are ordered.
+    #[serde(rename = "type")]
+    pub kind: OsvRangeKind,
+    /// The events in the order of the advisory.
+    pub events: Vec<OsvEvent>,
+}
+
+/// The `type` of an [`OsvRange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OsvRangeKind {
+    /// Versions of the package's ecosystem, which are PEP 440 versions for PyPI.
+    Ecosystem,
+    /// Semantic versions, which are evaluated as PEP 440 versions.
+    Semver,
+    /// Git commits, which can't be evaluated as versions.
+    Git,
+}
+
+/// An event in an [`OsvRange`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OsvEvent {
+    /// The version that introduced the vulnerability, or `0` for all versions.
+    Introduced(String),
+    /// The first version that isn't affected anymore.
+    Fixed(String),
+    /// The last version that is affected.
+    LastAffected(String),
+    /// An upper limit, at and above which no version is affected.
+    Limit(String),
+}
+
+impl OsvRange {
+    /// The affected versions.
+    pub fn to_ranges(&self) -> Result<Ranges<Version>, OsvError> {
+        if self.kind == OsvRangeKind::Git {
+            return Err(OsvErrorKind::GitRange.into());
+        }
+
+        // `None` is the `0` of `introduced`, which sorts before all versions
+        let mut events = Vec::with_capacity(self.events.len());
+        let mut limit = Ranges::full();
+        for event in &self.events {
+            match event {
+                OsvEvent::Introduced(version) if version == "0" => events.push((None, event)),
+                OsvEvent::Introduced(version)
+                | OsvEvent::Fixed(version)
+                | OsvEvent::LastAffected(version) => {
+                    events.push((Some(parse_version(version)?), event));
+                }
+                OsvEvent::Limit(version) if version == "*" => {}
+                OsvEvent::Limit(version) => {
+                    limit =
+                        limit.intersection(&Ranges::strictly_lower_than(parse_version(version)?));
+                }
+            }
+        }
+        // Stable, so that events for the same version are evaluated in the order given
+        events.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut affected = Ranges::empty();
+        let mut start: Option<Bound<Version>> = None;
+        for (version, event) in events {
+            match (event, version) {
+                (OsvEvent::Introduced(_), version) if start.is_none() => {
+                    start = Some(version.map_or(Bound::Unbounded, Bound::Included));
+                }
+                (OsvEvent::Fixed(_), Some(version)) => {
+                    if let Some(start) = start.take() {
+                        affected = affected.union(&Ranges::from_range_bounds((
+                            start,
+                            Bound::Excluded(version),
+                        )));
+                    }
+                }
+                (OsvEvent::LastAffected(_), Some(version)) => {
+                    if let Some(start) = start.take() {
+                        affected = affected.union(&Ranges::from_range_bounds((
+                            start,
+                            Bound::Included(version),
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = start {
+            affected = affected.union(&Ranges::from_range_bounds((start, Bound::Unbounded)));
+        }
+        Ok(affected.intersection(&limit))
+    }
+
+    /// Whether the version is affected.
+    ///
+    /// Use [`OsvRange::to_ranges`] when checking many versions against the same range.
+    pub fn is_affected(&self, version: &Version) -> Result<bool, OsvError> {
+        Ok(self.to_ranges()?.contains(version))
+    }
+}
+
+fn parse_version(version: &str) -> Result<Version, OsvError> {
+    Version::from_str(version).map_err(|err| {
+        OsvErrorKind::InvalidVersion {
+            version: version.to_string(),
+            err,
+        }
+        .into()
+    })
+}
+
+/// An error that occurs when evaluating an [`OsvRange`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OsvError {
+    kind: Box<OsvErrorKind>,
+}
+
+impl std::error::Error for OsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self.kind {
+            OsvErrorKind::InvalidVersion { ref err, .. } => Some(err),
+            OsvErrorKind::GitRange => None,
+        }
+    }
+}
+
+impl std::fmt::Display for OsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.kind {
+            OsvErrorKind::InvalidVersion { ref version, .. } => {
+                write!(f, "Invalid version `{version}` in OSV range")
+            }
+            OsvErrorKind::GitRange => {
+                write!(
+                    f,
+                    "OSV ranges of type `GIT` can't be evaluated for versions"
+                )
+            }
+        }
+    }
+}
+
+/// The kind of error that occurs when evaluating an [`OsvRange`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum OsvErrorKind {
+    /// An event has a version that isn't a valid PEP 440 version.
+    InvalidVersion {
+        version: String,
+        err: VersionParseError,
+    },
+    /// The range is a `GIT` range of commits.
+    GitRange,
+}
+
+impl From<OsvErrorKind> for OsvError {
+    fn from(kind: OsvErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+use super::*;
+
+fn parse(json: &str) -> OsvRange {
+    serde_json::from_str(json).unwrap()
+}
+
+fn affected(range: &OsvRange, versions: &[&str]) -> Vec<bool> {
+    versions
+        .iter()
+        .map(|version| {
+            range
+                .is_affected(&Version::from_str(version).unwrap())
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn introduced_fixed() {
+    // Two affected intervals, with the events out of order
+    let range = parse(
+        r#"{
+            "type": "ECOSYSTEM",
+            "events": [
+                {"introduced": "2.0"},
+                {"fixed": "2.0.5"},
+                {"introduced": "0"},
+                {"fixed": "1.4.2"}
+            ]
+        }"#,
+    );
+    assert_eq!(
+        affected(
+            &range,
+            &[
+                "0.1",
+                "1.4.2rc1",
+                "1.4.2",
+                "1.9",
+                "2.0.dev1",
+                "2.0",
+                "2.0.4.post1",
+                "2.0.5"
+            ]
+        ),
+        [true, true, false, false, false, true, true, false]
+    );
+    let version = |version: &str| Version::from_str(version).unwrap();
+    assert_eq!(
+        range.to_ranges().unwrap(),
+        Ranges::strictly_lower_than(version("1.4.2"))
+            .union(&Ranges::between(version("2.0"), version("2.0.5")))
+    );
+}
+
+#[test]
+fn last_affected_and_limit() {
+    let range = parse(
+        r#"{
+            "type": "SEMVER",
+            "events": [{"introduced": "1.0.0"}, {"last_affected": "1.2.0"}]
+        }"#,
+    );
+    assert_eq!(
+        affected(&range, &["0.9", "1.0.0", "1.2.0", "1.2.0.post1"]),
+        [false, true, true, false]
+    );
+
+    let range = parse(
+        r#"{
+            "type": "ECOSYSTEM",
+            "events": [{"introduced": "1.0"}, {"limit": "3.0"}]
+        }"#,
+    );
+    assert_eq!(
+        affected(&range, &["1.0", "2.9", "3.0"]),
+        [true, true, false]
+    );
+
+    // Without an end, all later versions are affected
+    let range = parse(r#"{"type": "ECOSYSTEM", "events": [{"introduced": "1.0"}]}"#);
+    assert_eq!(affected(&range, &["0.9", "99"]), [false, true]);
+}
+
+#[test]
+fn errors() {
+    let git = parse(
+        r#"{
+            "type": "GIT",
+            "repo": "https://github.com/psf/requests",
+            "events": [{"introduced": "0"}, {"fixed": "a1b2c3"}]
+        }"#,
+    );
+    assert_eq!(
+        git.to_ranges().unwrap_err().to_string(),
+        "OSV ranges of type `GIT` can't be evaluated for versions"
+    );
+
+    let invalid = parse(r#"{"type": "ECOSYSTEM", "events": [{"fixed": "1.0-foo"}]}"#);
+    assert_eq!(
+        invalid.to_ranges().unwrap_err().to_string(),
+        "Invalid version `1.0-foo` in OSV range"
+    );
+}
+
+#[test]
+fn serialize() {
+    let range = OsvRange {
+        kind: OsvRangeKind::Ecosystem,
+        events: vec![
+            OsvEvent::Introduced("0".to_string()),
+            OsvEvent::LastAffected("1.0".to_string()),
+        ],
+    };
+    assert_eq!(
+        serde_json::to_string(&range).unwrap(),
+        r#"{"type":"ECOSYSTEM","events":[{"introduced":"0"},{"last_affected":"1.0"}]}"#
+    );
+}
@@ -0,0 +1,218 @@
+//! A version that consists of only an epoch and up to three release numbers.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::version::ErrorKind;
+use crate::{Version, VersionParseError};
+
+/// A version with an optional epoch and one to three release numbers, such as `3.12` or `3.12.1`,
+/// but no pre-, post-, dev or local version.
+///
+/// This is the shape of interpreter versions such as `python_version` and
+/// `python_full_version`, where the other components are invalid. Unlike [`Version`], it is
+/// `Copy` and doesn't allocate.
+///
+/// Like for [`Version`], trailing zeros don't matter for comparisons, so `3.12` and `3.12.0` are
+/// equal, but they are kept for display.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{ReleaseOnlyVersion, Version};
+///
+/// let version = Version::parse_release_only("3.12").unwrap();
+/// assert_eq!(version.release(), [3, 12]);
+/// assert_eq!(version.minor(), Some(12));
+/// assert!(version > ReleaseOnlyVersion::from_str("3.9.18").unwrap());
+/// assert!(Version::parse_release_only("3.13rc1").is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ReleaseOnlyVersion {
+    epoch: u64,
+    /// Unused release numbers are zero.
+    release: [u64; 3],
+    len: u8,
+}
+
+impl ReleaseOnlyVersion {
+    /// A version with the given release numbers and no epoch.
+    ///
+    /// This is a `const fn`, so unlike a [`Version`], it can be declared as a constant:
+    ///
+    /// ```rust
+    /// use pep440_rs::{ReleaseOnlyVersion, Version};
+    ///
+    /// static MIN_PYTHON: ReleaseOnlyVersion = ReleaseOnlyVersion::new(&[3, 9]);
+    ///
+    /// assert!(Version::from(MIN_PYTHON) < Version::new([3, 12]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// When there are no or more than three release numbers.
+    pub const fn new(release: &[u64]) -> Self {
+        match Self::from_parts(0, release) {
+            Some(version) => version,
+            None => panic!("release must have one to three numbers"),
+        }
+    }
+
+    /// A version with the given epoch and release, or `None` if there are no or more than three
+    /// release numbers.
+    pub const fn from_parts(epoch: u64, release: &[u64]) -> Option<Self> {
+        if release.is_empty() || release.len() > 3 {
+            return None;
+        }
+        let mut numbers = [0; 3];
+        let mut idx = 0;
+        while idx < release.len() {
+            numbers[idx] = release[idx];
+            idx += 1;
+        }
+        Some(Self {
+            epoch,
+            release: numbers,
+            len: release.len() as u8,
+        })
+    }
+
+    /// The epoch, usually `0`.
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// The release numbers as written, e.g. `[3, 12]` for `3.12`.
+    pub const fn release(&self) -> &[u64] {
+        self.release.split_at(self.len as usize).0
+    }
+
+    /// The first release number, e.g. `3` in `3.12.1`.
+    pub const fn major(&self) -> u64 {
+        self.release[0]
+    }
+
+    /// The second release number, e.g. `12` in `3.12.1`, if there is one.
+    pub const fn minor(&self) -> Option<u64> {
+        if self.len >= 2 {
+            Some(self.release[1])
+        } else {
+            None
+        }
+    }
+
+    /// The third release number, e.g. `1` in `3.12.1`, if there is one.
+    pub const fn patch(&self) -> Option<u64> {
+        if self.len >= 3 {
+            Some(self.release[2])
+        } else {
+            None
+        }
+    }
+}
+
+impl Version {
+    /// Parse a version that may only have an epoch and one to three release numbers, such as an
+    /// interpreter version.
+    ///
+    /// Spellings that normalize to such a version are accepted, e.g. `v3.12`.
+    pub fn parse_release_only(version: &str) -> Result<ReleaseOnlyVersion, VersionParseError> {
+        ReleaseOnlyVersion::from_str(version)
+    }
+}
+
+impl FromStr for ReleaseOnlyVersion {
+    type Err = VersionParseError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        Self::try_from(&Version::from_str(version)?)
+    }
+}
+
+/// Fails if the version has a pre-, post-, dev or local version, or more than three release
+/// numbers.
+impl TryFrom<&Version> for ReleaseOnlyVersion {
+    type Error = VersionParseError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        let release_only = (version.is_stable() && !version.is_post() && !version.is_local())
+            .then(|| Self::from_parts(version.epoch(), &version.release()))
+            .flatten();
+        release_only.ok_or_else(|| {
+            ErrorKind::NotReleaseOnly {
+                version: version.to_string(),
+            }
+            .into()
+        })
+    }
+}
+
+impl TryFrom<Version> for ReleaseOnlyVersion {
+    type Error = VersionParseError;
+
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        Self::try_from(&version)
+    }
+}
+
+impl From<ReleaseOnlyVersion> for Version {
+    fn from(version: ReleaseOnlyVersion) -> Self {
+        Version::new(version.release()).with_epoch(version.epoch)
+    }
+}
+
+impl PartialEq for ReleaseOnlyVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ReleaseOnlyVersion {}
+
+impl PartialOrd for ReleaseOnlyVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The unused release numbers are zero, so comparing all three compares the padded releases.
+impl Ord for ReleaseOnlyVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.epoch, self.release).cmp(&(other.epoch, other.release))
+    }
+}
+
+impl Hash for ReleaseOnlyVersion {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.epoch, self.release).hash(state);
+    }
+}
+
+impl std::fmt::Display for ReleaseOnlyVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}!", self.epoch)?;
+        }
+        write!(f, "{}", crate::Release::from(self.release()))
+    }
+}
+
+/// Shows the version like [`Version`](crate::Version), e.g. `ReleaseOnlyVersion("3.12")`, or the
+/// epoch and the release with `{:#?}`.
+impl std::fmt::Debug for ReleaseOnlyVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return f
+                .debug_struct("ReleaseOnlyVersion")
+                .field("epoch", &self.epoch)
+                .field("release", &self.release())
+                .finish();
+        }
+        f.debug_tuple("ReleaseOnlyVersion")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests;
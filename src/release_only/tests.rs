@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn parse() {
+    for (input, epoch, release) in [
+        ("3", 0, &[3][..]),
+        ("3.12", 0, &[3, 12]),
+        ("v3.12.01", 0, &[3, 12, 1]),
+        ("1!3.12", 1, &[3, 12]),
+    ] {
+        let version = Version::parse_release_only(input).unwrap();
+        assert_eq!(version.epoch(), epoch, "{input}");
+        assert_eq!(version.release(), release, "{input}");
+        assert_eq!(Version::from(version), Version::from_str(input).unwrap());
+    }
+    for input in [
+        "3.12rc1",
+        "3.12.post1",
+        "3.12.dev0",
+        "3.12+local",
+        "3.12.1.1",
+    ] {
+        assert_eq!(
+            Version::parse_release_only(input).unwrap_err(),
+            ErrorKind::NotReleaseOnly {
+                version: Version::from_str(input).unwrap().to_string()
+            }
+            .into(),
+            "{input}"
+        );
+    }
+    assert!(Version::parse_release_only("3.x").is_err());
+}
+
+#[test]
+fn ordering() {
+    let version = |version| ReleaseOnlyVersion::from_str(version).unwrap();
+    assert_eq!(version("3.12"), version("3.12.0"));
+    assert_eq!(version("3.12").to_string(), "3.12");
+    assert_eq!(
+        format!("{:?}", version("3.12")),
+        r#"ReleaseOnlyVersion("3.12")"#
+    );
+    assert!(version("3.9") < version("3.12"));
+    assert!(version("3.12") < version("3.12.1"));
+    assert!(version("1!2") > version("3.12"));
+
+    let state = std::collections::hash_map::RandomState::new();
+    use std::hash::BuildHasher;
+    assert_eq!(
+        state.hash_one(version("3.12")),
+        state.hash_one(version("3.12.0"))
+    );
+}
+
+#[test]
+fn accessors() {
+    let version = ReleaseOnlyVersion::new(&[3, 12, 1]);
+    assert_eq!(version.major(), 3);
+    assert_eq!(version.minor(), Some(12));
+    assert_eq!(version.patch(), Some(1));
+    assert_eq!(ReleaseOnlyVersion::new(&[3]).minor(), None);
+    assert_eq!(ReleaseOnlyVersion::from_parts(0, &[]), None);
+    assert_eq!(ReleaseOnlyVersion::from_parts(0, &[1, 2, 3, 4]), None);
+}
+
+#[test]
+fn constant() {
+    const MIN_PYTHON: ReleaseOnlyVersion = ReleaseOnlyVersion::new(&[3, 9]);
+    const MINOR: Option<u64> = MIN_PYTHON.minor();
+    assert_eq!(MIN_PYTHON.release(), [3, 9]);
+    assert_eq!(MINOR, Some(9));
+    assert_eq!(Version::from(MIN_PYTHON), Version::new([3, 9]));
+}
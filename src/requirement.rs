@@ -0,0 +1,149 @@
+//! Split a PEP 508 requirement into its parts without parsing the name, extras or markers.
+
+use std::ops::Range;
+use std::str::FromStr;
+
+use unscanny::Scanner;
+
+use crate::{VersionSpecifiers, VersionSpecifiersParseError};
+
+/// The parts of a requirement such as `requests[socks]>=2.0,<3; python_version<'3.9'`, as
+/// substrings of the requirement.
+///
+/// Only the version specifiers are parsed, with [`RequirementParts::parse_specifiers`]. The name,
+/// the extras, the URL and the marker are returned as written, for callers that don't need a
+/// full PEP 508 parser.
+///
+/// ```rust
+/// use pep440_rs::RequirementParts;
+///
+/// let requirement = "requests[socks] >=2.0,<3 ; python_version < '3.9'";
+/// let parts = RequirementParts::split(requirement).unwrap();
+/// assert_eq!(parts.name(), "requests");
+/// assert_eq!(parts.extras(), Some("socks"));
+/// assert_eq!(parts.specifiers(), ">=2.0,<3");
+/// assert_eq!(parts.marker(), Some("python_version < '3.9'"));
+/// assert_eq!(parts.parse_specifiers().unwrap().to_string(), ">=2.0, <3");
+///
+/// let err = RequirementParts::split("requests >=2.0,<3x").unwrap().parse_specifiers().unwrap_err();
+/// assert_eq!(err.line(), "requests >=2.0,<3x");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementParts<'a> {
+    requirement: &'a str,
+    name: &'a str,
+    extras: Option<&'a str>,
+    specifiers: Range<usize>,
+    url: Option<&'a str>,
+    marker: Option<&'a str>,
+}
+
+impl<'a> RequirementParts<'a> {
+    /// Split a requirement, or return `None` if it doesn't start with a name or has an unclosed
+    /// `[` or trailing input after a URL.
+    ///
+    /// The version specifiers may be wrapped in parentheses, as in `requests (>=2.0)`.
+    pub fn split(requirement: &'a str) -> Option<Self> {
+        let mut s = Scanner::new(requirement);
+        s.eat_whitespace();
+        let name = s.eat_while(|c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+        if name.is_empty() {
+            return None;
+        }
+        s.eat_whitespace();
+
+        let extras = if s.eat_if('[') {
+            let extras = s.eat_until(']');
+            if !s.eat_if(']') {
+                return None;
+            }
+            Some(extras.trim())
+        } else {
+            None
+        };
+        s.eat_whitespace();
+
+        let (url, specifiers) = if s.eat_if('@') {
+            s.eat_whitespace();
+            let url = s.eat_until(char::is_whitespace);
+            s.eat_whitespace();
+            if !s.done() && !s.at(';') {
+                return None;
+            }
+            (Some(url), s.cursor()..s.cursor())
+        } else {
+            let start = s.cursor();
+            s.eat_until(';');
+            (None, trim_span(requirement, start..s.cursor()))
+        };
+        let specifiers = match requirement[specifiers.clone()]
+            .strip_prefix('(')
+            .and_then(|inner| inner.strip_suffix(')'))
+        {
+            Some(_) => trim_span(requirement, specifiers.start + 1..specifiers.end - 1),
+            None => specifiers,
+        };
+
+        let marker = s.eat_if(';').then(|| s.after().trim());
+        Some(Self {
+            requirement,
+            name,
+            extras,
+            specifiers,
+            url,
+            marker,
+        })
+    }
+
+    /// The package name as written, e.g. `requests`.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The extras between the brackets as written, e.g. `socks` or `socks, http2`, if there are
+    /// brackets.
+    pub fn extras(&self) -> Option<&'a str> {
+        self.extras
+    }
+
+    /// The version specifiers as written, without parentheses, e.g. `>=2.0,<3`. Empty if there
+    /// are none.
+    pub fn specifiers(&self) -> &'a str {
+        &self.requirement[self.specifiers.clone()]
+    }
+
+    /// The byte range of the version specifiers in the requirement.
+    pub fn specifiers_span(&self) -> Range<usize> {
+        self.specifiers.clone()
+    }
+
+    /// The URL after `@`, e.g. `https://example.com/foo.whl`, if there is one.
+    pub fn url(&self) -> Option<&'a str> {
+        self.url
+    }
+
+    /// The marker after `;` as written, e.g. `python_version < '3.9'`, if there is one.
+    pub fn marker(&self) -> Option<&'a str> {
+        self.marker
+    }
+
+    /// Parse the version specifiers.
+    ///
+    /// The error points into the whole requirement, so it underlines the invalid specifier
+    /// within the requirement.
+    pub fn parse_specifiers(&self) -> Result<VersionSpecifiers, VersionSpecifiersParseError> {
+        VersionSpecifiers::from_str(self.specifiers())
+            .map_err(|err| err.within(self.requirement, self.specifiers.start))
+    }
+}
+
+/// Shrink the span to exclude leading and trailing whitespace.
+fn trim_span(string: &str, span: Range<usize>) -> Range<usize> {
+    let part = &string[span.clone()];
+    let start = span.start + (part.len() - part.trim_start().len());
+    let end = span.end - (part.len() - part.trim_end().len());
+    start..end.max(start)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn split() {
+    let parts = RequirementParts::split("requests[socks]>=2.0,<3; python_version<'3.9'").unwrap();
+    assert_eq!(parts.name(), "requests");
+    assert_eq!(parts.extras(), Some("socks"));
+    assert_eq!(parts.specifiers(), ">=2.0,<3");
+    assert_eq!(parts.specifiers_span(), 15..23);
+    assert_eq!(parts.url(), None);
+    assert_eq!(parts.marker(), Some("python_version<'3.9'"));
+
+    let parts = RequirementParts::split("  foo.bar-baz [ a, b ] ( >= 1.0 ) ").unwrap();
+    assert_eq!(parts.name(), "foo.bar-baz");
+    assert_eq!(parts.extras(), Some("a, b"));
+    assert_eq!(parts.specifiers(), ">= 1.0");
+    assert_eq!(parts.marker(), None);
+
+    let parts = RequirementParts::split("foo").unwrap();
+    assert_eq!(parts.specifiers(), "");
+    assert_eq!(
+        parts.parse_specifiers().unwrap(),
+        VersionSpecifiers::empty()
+    );
+
+    let parts = RequirementParts::split("foo; sys_platform == 'win32'").unwrap();
+    assert_eq!(parts.specifiers(), "");
+    assert_eq!(parts.marker(), Some("sys_platform == 'win32'"));
+}
+
+#[test]
+fn split_url() {
+    let parts =
+        RequirementParts::split("foo @ https://example.com/foo.whl ; extra == 'bar'").unwrap();
+    assert_eq!(parts.url(), Some("https://example.com/foo.whl"));
+    assert_eq!(parts.specifiers(), "");
+    assert_eq!(parts.marker(), Some("extra == 'bar'"));
+    assert_eq!(
+        RequirementParts::split("foo @ https://example.com/foo.whl bar"),
+        None
+    );
+}
+
+#[test]
+fn split_invalid() {
+    assert_eq!(RequirementParts::split(">=1.0"), None);
+    assert_eq!(RequirementParts::split(""), None);
+    assert_eq!(RequirementParts::split("foo[bar >=1.0"), None);
+}
+
+#[test]
+fn error_span() {
+    let requirement = "foo[bar] >=1.0, <2.0x ; python_version < '3.9'";
+    let err = RequirementParts::split(requirement)
+        .unwrap()
+        .parse_specifiers()
+        .unwrap_err();
+    assert_eq!(err.line(), requirement);
+    let standalone = VersionSpecifiers::from_str(">=1.0, <2.0x").unwrap_err();
+    // The underline is shifted by the length of `foo[bar] `
+    let underline =
+        |err: &VersionSpecifiersParseError| err.to_string().lines().last().unwrap().to_string();
+    assert_eq!(
+        underline(&err),
+        format!("{}{}", " ".repeat(9), underline(&standalone))
+    );
+}
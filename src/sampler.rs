@@ -0,0 +1,152 @@
+//! Random versions that match specifiers, for randomized tests of resolvers.
+
+use rand_core::Rng;
+
+use crate::{LocalSegment, Prerelease, PrereleaseKind, Version, VersionSpecifiers};
+
+/// Draws random versions that match [`VersionSpecifiers`].
+///
+/// The candidates are variations of the versions in the specifiers, so they cluster around the
+/// bounds where matching is most subtle, with random pre-, post-, dev and local segments. Each
+/// candidate is checked with [`VersionSpecifiers::contains`], so every sampled version matches.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{VersionSampler, VersionSpecifiers};
+/// # struct Counter(u64);
+/// # impl rand_core::TryRng for Counter {
+/// #     type Error = std::convert::Infallible;
+/// #     fn try_next_u32(&mut self) -> Result<u32, Self::Error> { Ok(self.try_next_u64()? as u32) }
+/// #     fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+/// #         self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+/// #         Ok((self.0 ^ (self.0 >> 31)).wrapping_mul(0xbf58_476d_1ce4_e5b9))
+/// #     }
+/// #     fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+/// #         for chunk in dst.chunks_mut(8) {
+/// #             chunk.copy_from_slice(&self.try_next_u64()?.to_le_bytes()[..chunk.len()]);
+/// #         }
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let mut rng = Counter(0);
+///
+/// let specifiers = VersionSpecifiers::from_str(">=1.16, !=1.19.*, <2").unwrap();
+/// let sampler = VersionSampler::new(&specifiers).with_prerelease_probability(0.5);
+/// for _ in 0..100 {
+///     let version = sampler.sample(&mut rng).unwrap();
+///     assert!(specifiers.contains(&version));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct VersionSampler<'a> {
+    specifiers: &'a VersionSpecifiers,
+    prerelease_probability: f64,
+    local_probability: f64,
+    max_attempts: usize,
+}
+
+impl<'a> VersionSampler<'a> {
+    /// A sampler for the specifiers, which by default adds pre-release and local segments to
+    /// 10% of the candidates each.
+    pub fn new(specifiers: &'a VersionSpecifiers) -> Self {
+        Self {
+            specifiers,
+            prerelease_probability: 0.1,
+            local_probability: 0.1,
+            max_attempts: 1000,
+        }
+    }
+
+    /// The probability that a candidate gets a pre-release or dev segment.
+    #[must_use]
+    pub fn with_prerelease_probability(mut self, probability: f64) -> Self {
+        self.prerelease_probability = probability;
+        self
+    }
+
+    /// The probability that a candidate gets a local version.
+    #[must_use]
+    pub fn with_local_probability(mut self, probability: f64) -> Self {
+        self.local_probability = probability;
+        self
+    }
+
+    /// How many candidates to try before giving up, 1000 by default.
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// A random version that matches the specifiers, or `None` if no candidate matched, e.g.
+    /// because no version can match the specifiers.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Version> {
+        (0..self.max_attempts)
+            .map(|_| self.candidate(rng))
+            .find(|candidate| self.specifiers.contains(candidate))
+    }
+
+    /// A random variation of a version in the specifiers, which may not match.
+    fn candidate<R: Rng + ?Sized>(&self, rng: &mut R) -> Version {
+        let base = if self.specifiers.is_empty() {
+            Version::new([below(rng, 5)])
+        } else {
+            let index = below(rng, self.specifiers.len() as u64) as usize;
+            self.specifiers[index].version().clone()
+        };
+
+        let mut release = base.release().to_vec();
+        match below(rng, 3) {
+            0 => release.truncate(1 + below(rng, release.len() as u64) as usize),
+            1 => release.push(below(rng, 3)),
+            _ => {}
+        }
+        let index = below(rng, release.len() as u64) as usize;
+        let delta = below(rng, 5) as i64 - 2;
+        release[index] = release[index].saturating_add_signed(delta);
+        let mut version = Version::new(release).with_epoch(base.epoch());
+
+        if chance(rng, self.prerelease_probability) {
+            if chance(rng, 0.7) {
+                let kind = [
+                    PrereleaseKind::Alpha,
+                    PrereleaseKind::Beta,
+                    PrereleaseKind::Rc,
+                ][below(rng, 3) as usize];
+                version = version.with_pre(Some(Prerelease {
+                    kind,
+                    number: below(rng, 3),
+                }));
+            }
+            if chance(rng, 0.5) {
+                version = version.with_dev(Some(below(rng, 3)));
+            }
+        }
+        if chance(rng, 0.2) {
+            version = version.with_post(Some(below(rng, 3)));
+        }
+        if chance(rng, self.local_probability) {
+            let segment = if chance(rng, 0.5) {
+                LocalSegment::Number(below(rng, 10))
+            } else {
+                LocalSegment::String(["cpu", "cu118", "local"][below(rng, 3) as usize].to_string())
+            };
+            version = version.with_local(vec![segment]);
+        }
+        version
+    }
+}
+
+/// A random number in `0..bound`.
+fn below<R: Rng + ?Sized>(rng: &mut R, bound: u64) -> u64 {
+    rng.next_u64() % bound
+}
+
+/// `true` with the given probability.
+fn chance<R: Rng + ?Sized>(rng: &mut R, probability: f64) -> bool {
+    // The 53 high bits as a float in `0..1`
+    ((rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use rand_core::TryRng;
+
+use super::*;
+
+/// SplitMix64, to test with a fixed seed.
+struct SplitMix(u64);
+
+impl TryRng for SplitMix {
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok((self.try_next_u64()? >> 32) as u32)
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Ok(z ^ (z >> 31))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        for chunk in dst.chunks_mut(8) {
+            let bytes = self.try_next_u64()?.to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn samples_match() {
+    let mut rng = SplitMix(42);
+    for specifiers in [
+        "",
+        ">=1.16, !=1.19.*, <2",
+        "~=2.4.1",
+        "==1.0.*, !=1.0.3",
+        ">1.0.post1",
+        "==1.0",
+        "<1.0rc1",
+    ] {
+        let specifiers = VersionSpecifiers::from_str(specifiers).unwrap();
+        let sampler = VersionSampler::new(&specifiers)
+            .with_prerelease_probability(0.3)
+            .with_local_probability(0.3);
+        let samples: Vec<Version> = (0..200)
+            .map(|_| sampler.sample(&mut rng).unwrap())
+            .collect();
+        assert!(samples.iter().all(|version| specifiers.contains(version)));
+        // The samples vary
+        assert!(
+            samples.iter().any(|version| *version != samples[0]),
+            "{specifiers}"
+        );
+    }
+}
+
+#[test]
+fn knobs() {
+    let mut rng = SplitMix(7);
+    let specifiers = VersionSpecifiers::from_str(">=1.0").unwrap();
+    let sampler = VersionSampler::new(&specifiers)
+        .with_prerelease_probability(0.0)
+        .with_local_probability(0.0);
+    for _ in 0..200 {
+        let version = sampler.sample(&mut rng).unwrap();
+        assert!(
+            !version.any_prerelease() && !version.is_local(),
+            "{version}"
+        );
+    }
+    let sampler = VersionSampler::new(&specifiers).with_local_probability(1.0);
+    assert!(sampler.sample(&mut rng).unwrap().is_local());
+}
+
+#[test]
+fn unsatisfiable() {
+    let mut rng = SplitMix(0);
+    let specifiers = VersionSpecifiers::from_str(">=2, <1").unwrap();
+    let sampler = VersionSampler::new(&specifiers).with_max_attempts(50);
+    assert_eq!(sampler.sample(&mut rng), None);
+}
@@ -0,0 +1,93 @@
+//! Keep versions ordered across a change of a project's version scheme.
+
+use std::collections::BTreeMap;
+
+use crate::{Version, VersionSpecifiers};
+
+/// Rewrite the versions of a project that changed its version scheme, so that all versions are
+/// ordered by when they were released.
+///
+/// The versions that match the `legacy` specifiers belong to the old scheme. Versions of the new
+/// scheme are moved onto a higher epoch, by default `1!`, so that they sort after every legacy
+/// version, such as when a project moves from date-based `2013.10` to `1.0`. Alternatively,
+/// legacy versions can be mapped onto the new scheme one by one with a table; with
+/// [`SchemeMigration::with_epoch`]`(0)`, the new versions are left unchanged.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{SchemeMigration, Version, VersionSpecifiers};
+///
+/// let migration = SchemeMigration::new(VersionSpecifiers::from_str(">=2000").unwrap());
+/// let old = migration.migrate(&Version::from_str("2013.10").unwrap());
+/// let new = migration.migrate(&Version::from_str("1.0").unwrap());
+/// assert_eq!(old.to_string(), "2013.10");
+/// assert_eq!(new.to_string(), "1!1.0");
+/// assert!(new > old);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemeMigration {
+    /// The versions of the old scheme.
+    legacy: VersionSpecifiers,
+    /// The lowest epoch of the new scheme.
+    epoch: u64,
+    /// Legacy versions and their replacement in the new scheme.
+    table: BTreeMap<Version, Version>,
+}
+
+impl SchemeMigration {
+    /// A migration in which the versions matching `legacy` belong to the old scheme and the new
+    /// scheme starts at epoch 1.
+    pub fn new(legacy: VersionSpecifiers) -> Self {
+        Self {
+            legacy,
+            epoch: 1,
+            table: BTreeMap::new(),
+        }
+    }
+
+    /// Move versions of the new scheme to this epoch instead, unless they already have a higher
+    /// one.
+    #[must_use]
+    pub fn with_epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Replace the legacy version with a version of the new scheme, which is returned as is.
+    #[must_use]
+    pub fn with_mapping(mut self, legacy: Version, new: Version) -> Self {
+        self.table.insert(legacy, new);
+        self
+    }
+
+    /// Whether the version belongs to the old scheme.
+    pub fn is_legacy(&self, version: &Version) -> bool {
+        self.legacy.contains(version)
+    }
+
+    /// The version rewritten for the new scheme.
+    ///
+    /// Legacy versions are replaced through the table, or returned unchanged if they aren't in
+    /// it. The other versions get the epoch of the new scheme.
+    pub fn migrate(&self, version: &Version) -> Version {
+        if self.is_legacy(version) {
+            self.table
+                .get(version)
+                .cloned()
+                .unwrap_or_else(|| version.clone())
+        } else if version.epoch() < self.epoch {
+            version.clone().with_epoch(self.epoch)
+        } else {
+            version.clone()
+        }
+    }
+}
+
+impl Extend<(Version, Version)> for SchemeMigration {
+    fn extend<I: IntoIterator<Item = (Version, Version)>>(&mut self, iter: I) {
+        self.table.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests;
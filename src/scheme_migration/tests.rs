@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn version(version: &str) -> Version {
+    Version::from_str(version).unwrap()
+}
+
+fn migrate(migration: &SchemeMigration, versions: &[&str]) -> Vec<String> {
+    versions
+        .iter()
+        .map(|v| migration.migrate(&version(v)).to_string())
+        .collect()
+}
+
+#[test]
+fn epoch() {
+    let migration = SchemeMigration::new(VersionSpecifiers::from_str(">=2000").unwrap());
+    // In release order
+    let versions = ["2012.1", "2013.10.post1", "1.0a1", "1.0", "1!2.0", "2!0.1"];
+    let migrated = migrate(&migration, &versions);
+    assert_eq!(
+        migrated,
+        [
+            "2012.1",
+            "2013.10.post1",
+            "1!1.0a1",
+            "1!1.0",
+            "1!2.0",
+            "2!0.1"
+        ]
+    );
+    let mut sorted: Vec<Version> = migrated.iter().map(|v| version(v)).collect();
+    sorted.sort();
+    assert_eq!(
+        sorted.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        migrated
+    );
+}
+
+#[test]
+fn table() {
+    let mut migration = SchemeMigration::new(VersionSpecifiers::from_str("<2020.1").unwrap())
+        .with_epoch(0)
+        .with_mapping(version("4.0"), version("2019.3"));
+    migration.extend([(version("5.2"), version("2019.11"))]);
+    assert!(migration.is_legacy(&version("5.2")));
+    assert!(!migration.is_legacy(&version("2020.1rc1")));
+    assert_eq!(
+        migrate(&migration, &["4.0.0", "5.2", "5.3", "2020.1", "2021.4"]),
+        ["2019.3", "2019.11", "5.3", "2020.1", "2021.4"]
+    );
+}
@@ -0,0 +1,110 @@
+//! Report what a change of version specifiers allows and forbids.
+
+use std::ops::Bound;
+
+use version_ranges::Ranges;
+
+use crate::{Version, VersionSpecifiers};
+
+impl VersionSpecifiers {
+    /// Compare the versions that two specifier lists allow, e.g. for describing a change of a
+    /// dependency declaration.
+    ///
+    /// ```rust
+    /// use std::ops::Bound;
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifiers};
+    ///
+    /// let old = VersionSpecifiers::from_str(">=1.0, <2").unwrap();
+    /// let new = VersionSpecifiers::from_str(">=1.2, <3").unwrap();
+    /// let diff = VersionSpecifiers::diff(&old, &new);
+    /// assert!(diff.admitted().contains(&Version::from_str("2.5").unwrap()));
+    /// assert!(diff.excluded().contains(&Version::from_str("1.1").unwrap()));
+    /// let lower = diff.lower_bound().unwrap();
+    /// assert_eq!(lower.new, Bound::Included(Version::from_str("1.2").unwrap()));
+    /// ```
+    pub fn diff(old: &Self, new: &Self) -> SpecifiersDiff {
+        let old_range = Ranges::from(old.clone());
+        let new_range = Ranges::from(new.clone());
+        let admitted = new_range.intersection(&old_range.complement());
+        let excluded = old_range.intersection(&new_range.complement());
+
+        let (mut lower_bound, mut upper_bound) = (None, None);
+        if let (Some((old_lower, old_upper)), Some((new_lower, new_upper))) =
+            (old.bounding_range(), new.bounding_range())
+        {
+            if old_lower != new_lower {
+                lower_bound = Some(BoundChange {
+                    old: old_lower,
+                    new: new_lower,
+                });
+            }
+            if old_upper != new_upper {
+                upper_bound = Some(BoundChange {
+                    old: old_upper,
+                    new: new_upper,
+                });
+            }
+        }
+
+        SpecifiersDiff {
+            admitted,
+            excluded,
+            lower_bound,
+            upper_bound,
+        }
+    }
+}
+
+/// The difference between two specifier lists, see [`VersionSpecifiers::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecifiersDiff {
+    admitted: Ranges<Version>,
+    excluded: Ranges<Version>,
+    lower_bound: Option<BoundChange>,
+    upper_bound: Option<BoundChange>,
+}
+
+impl SpecifiersDiff {
+    /// The versions that the new specifiers allow, but the old ones didn't.
+    pub fn admitted(&self) -> &Ranges<Version> {
+        &self.admitted
+    }
+
+    /// The versions that the old specifiers allowed, but the new ones don't.
+    pub fn excluded(&self) -> &Ranges<Version> {
+        &self.excluded
+    }
+
+    /// How the lowest allowed version moved, if it did.
+    ///
+    /// Bounds are only compared if both the old and the new specifiers can be satisfied.
+    pub fn lower_bound(&self) -> Option<&BoundChange> {
+        self.lower_bound.as_ref()
+    }
+
+    /// How the highest allowed version moved, if it did.
+    ///
+    /// Bounds are only compared if both the old and the new specifiers can be satisfied.
+    pub fn upper_bound(&self) -> Option<&BoundChange> {
+        self.upper_bound.as_ref()
+    }
+
+    /// Returns `true` if both specifier lists allow the same versions.
+    pub fn is_empty(&self) -> bool {
+        self.admitted.is_empty() && self.excluded.is_empty()
+    }
+}
+
+/// A bound of the allowed versions that moved, see [`SpecifiersDiff::lower_bound`] and
+/// [`SpecifiersDiff::upper_bound`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundChange {
+    /// The bound of the old specifiers.
+    pub old: Bound<Version>,
+    /// The bound of the new specifiers.
+    pub new: Bound<Version>,
+}
+
+#[cfg(test)]
+mod tests;
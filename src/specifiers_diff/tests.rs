@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn diff(old: &str, new: &str) -> SpecifiersDiff {
+    VersionSpecifiers::diff(
+        &VersionSpecifiers::from_str(old).unwrap(),
+        &VersionSpecifiers::from_str(new).unwrap(),
+    )
+}
+
+fn version(version: &str) -> Version {
+    Version::from_str(version).unwrap()
+}
+
+#[test]
+fn widen_upper_bound() {
+    let diff = diff(">=1.0, <2", ">=1.0, <3");
+    assert!(diff.excluded().is_empty());
+    assert!(diff.admitted().contains(&version("2.0")));
+    assert!(diff.admitted().contains(&version("2.9.post1")));
+    assert!(!diff.admitted().contains(&version("1.5")));
+    assert!(!diff.admitted().contains(&version("3.0")));
+    assert_eq!(diff.lower_bound(), None);
+    assert_eq!(
+        diff.upper_bound(),
+        Some(&BoundChange {
+            old: Bound::Excluded(version("2")),
+            new: Bound::Excluded(version("3")),
+        })
+    );
+}
+
+#[test]
+fn exclusion_without_bound_move() {
+    let diff = diff(">=1.0, <2", ">=1.0, <2, !=1.5.*");
+    assert!(diff.admitted().is_empty());
+    assert!(diff.excluded().contains(&version("1.5.3")));
+    assert!(!diff.excluded().contains(&version("1.6")));
+    assert_eq!(diff.lower_bound(), None);
+    assert_eq!(diff.upper_bound(), None);
+}
+
+#[test]
+fn equivalent() {
+    assert!(diff(">=1.0, <2", "<2.0, >=1").is_empty());
+    assert!(diff("~=1.2", ">=1.2, ==1.*").is_empty());
+    assert!(!diff("", "==1.0").is_empty());
+}
+
+#[test]
+fn unsatisfiable() {
+    let diff = diff(">=2, <1", ">=1.0");
+    assert!(diff.excluded().is_empty());
+    assert_eq!(
+        diff.admitted(),
+        &Ranges::from(VersionSpecifiers::from_str(">=1.0").unwrap())
+    );
+    assert_eq!(diff.lower_bound(), None);
+}
@@ -0,0 +1,222 @@
+//! Summaries of a collection of versions, such as all versions of a package on an index.
+
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+
+use crate::{Channel, Version};
+
+/// The latest versions and the number of versions per channel of a collection of versions,
+/// computed in a single pass.
+///
+/// Pre-releases are versions with a pre-release or dev segment, all other versions are stable.
+/// The latest versions per major and minor release only consider stable versions and group by
+/// the release numbers, ignoring the epoch.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{Channel, Version, VersionStatistics};
+///
+/// let versions: Vec<Version> = ["1.25.2", "1.26.0", "1.26.4", "2.0.0rc1", "2.0.0", "2.1.0b1"]
+///     .into_iter()
+///     .map(|version| Version::from_str(version).unwrap())
+///     .collect();
+/// let statistics = VersionStatistics::from_versions(&versions);
+/// assert_eq!(statistics.latest_stable().unwrap().to_string(), "2.0.0");
+/// assert_eq!(statistics.latest_prerelease().unwrap().to_string(), "2.1.0b1");
+/// assert_eq!(statistics.latest_for_major(1).unwrap().to_string(), "1.26.4");
+/// assert_eq!(statistics.latest_for_minor(1, 25).unwrap().to_string(), "1.25.2");
+/// assert_eq!(statistics.count(Channel::Stable), 4);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionStatistics {
+    total: usize,
+    latest_stable: Option<Version>,
+    latest_prerelease: Option<Version>,
+    latest_per_major: BTreeMap<u64, Version>,
+    latest_per_minor: BTreeMap<(u64, u64), Version>,
+    channels: BTreeMap<Channel, usize>,
+}
+
+impl VersionStatistics {
+    /// Statistics without any versions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compute the statistics for the given versions.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", skip_all, fields(total))
+    )]
+    pub fn from_versions<'a>(versions: impl IntoIterator<Item = &'a Version>) -> Self {
+        let mut statistics = Self::new();
+        for version in versions {
+            statistics.add(version);
+        }
+        #[cfg(feature = "instrument")]
+        tracing::Span::current().record("total", statistics.total);
+        statistics
+    }
+
+    /// Add a version to the statistics.
+    pub fn add(&mut self, version: &Version) {
+        self.total += 1;
+        *self.channels.entry(version.channel()).or_default() += 1;
+        if version.any_prerelease() {
+            keep_latest(&mut self.latest_prerelease, version);
+            return;
+        }
+        keep_latest(&mut self.latest_stable, version);
+        let release = version.release();
+        let major = release[0];
+        let minor = release.get(1).copied().unwrap_or(0);
+        keep_latest_in(&mut self.latest_per_major, major, version);
+        keep_latest_in(&mut self.latest_per_minor, (major, minor), version);
+    }
+
+    /// The number of versions.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of versions in the channel.
+    pub fn count(&self, channel: Channel) -> usize {
+        self.channels.get(&channel).copied().unwrap_or(0)
+    }
+
+    /// The highest stable version.
+    pub fn latest_stable(&self) -> Option<&Version> {
+        self.latest_stable.as_ref()
+    }
+
+    /// The highest pre-release or dev release, which may be older than the latest stable
+    /// version.
+    pub fn latest_prerelease(&self) -> Option<&Version> {
+        self.latest_prerelease.as_ref()
+    }
+
+    /// The highest stable version with the given major release number, e.g. `1.26.4` for `1`.
+    pub fn latest_for_major(&self, major: u64) -> Option<&Version> {
+        self.latest_per_major.get(&major)
+    }
+
+    /// The highest stable version with the given major and minor release number, e.g. `1.26.4`
+    /// for `1.26`. A missing minor number counts as `0`.
+    pub fn latest_for_minor(&self, major: u64, minor: u64) -> Option<&Version> {
+        self.latest_per_minor.get(&(major, minor))
+    }
+
+    /// The highest stable version for each major release number, in ascending order.
+    pub fn latest_per_major(&self) -> impl Iterator<Item = (u64, &Version)> {
+        self.latest_per_major
+            .iter()
+            .map(|(major, version)| (*major, version))
+    }
+
+    /// The highest stable version for each major and minor release number, in ascending order.
+    pub fn latest_per_minor(&self) -> impl Iterator<Item = ((u64, u64), &Version)> {
+        self.latest_per_minor
+            .iter()
+            .map(|(minor, version)| (*minor, version))
+    }
+}
+
+impl<'a> Extend<&'a Version> for VersionStatistics {
+    fn extend<T: IntoIterator<Item = &'a Version>>(&mut self, iter: T) {
+        for version in iter {
+            self.add(version);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a Version> for VersionStatistics {
+    fn from_iter<T: IntoIterator<Item = &'a Version>>(iter: T) -> Self {
+        Self::from_versions(iter)
+    }
+}
+
+/// Group versions by their first `prefix_len` release numbers, e.g. all `1.4.x` versions for a
+/// `prefix_len` of 2, for presenting release trains.
+///
+/// The groups and the versions within each group are sorted in ascending order. Each group is
+/// keyed by a version with the epoch and the release prefix, padded with zeros, so `2` falls
+/// into the `2.0` group. Versions with different epochs are in different groups.
+///
+/// # Panics
+///
+/// When `prefix_len` is 0.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{group_by_release, Version};
+///
+/// let versions = ["1.4.1", "1.3", "1.4.0rc1", "1.4", "2.0"]
+///     .map(|version| Version::from_str(version).unwrap());
+/// let groups: Vec<(String, Vec<String>)> = group_by_release(&versions, 2)
+///     .into_iter()
+///     .map(|(train, versions)| {
+///         (train.to_string(), versions.iter().map(ToString::to_string).collect())
+///     })
+///     .collect();
+/// assert_eq!(
+///     groups,
+///     [
+///         ("1.3".to_string(), vec!["1.3".to_string()]),
+///         ("1.4".to_string(), vec!["1.4.0rc1".to_string(), "1.4".to_string(), "1.4.1".to_string()]),
+///         ("2.0".to_string(), vec!["2.0".to_string()]),
+///     ]
+/// );
+/// ```
+#[cfg_attr(
+    feature = "instrument",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(prefix_len = prefix_len, versions, groups)
+    )
+)]
+pub fn group_by_release<T: Borrow<Version>>(
+    versions: impl IntoIterator<Item = T>,
+    prefix_len: usize,
+) -> Vec<(Version, Vec<T>)> {
+    assert!(prefix_len > 0, "the release prefix must not be empty");
+    let mut versions: Vec<T> = versions.into_iter().collect();
+    versions.sort_by(|a, b| a.borrow().cmp(b.borrow()));
+
+    let mut groups: Vec<(Version, Vec<T>)> = Vec::new();
+    for version in versions {
+        let release = version.borrow().release();
+        let prefix = (0..prefix_len).map(|idx| release.get(idx).copied().unwrap_or(0));
+        let train = Version::new(prefix).with_epoch(version.borrow().epoch());
+        match groups.last_mut() {
+            Some((last, group)) if *last == train => group.push(version),
+            _ => groups.push((train, vec![version])),
+        }
+    }
+    #[cfg(feature = "instrument")]
+    tracing::Span::current()
+        .record(
+            "versions",
+            groups.iter().map(|(_, group)| group.len()).sum::<usize>(),
+        )
+        .record("groups", groups.len());
+    groups
+}
+
+/// Replace `latest` if `version` is higher.
+fn keep_latest(latest: &mut Option<Version>, version: &Version) {
+    if latest.as_ref().is_none_or(|latest| version > latest) {
+        *latest = Some(version.clone());
+    }
+}
+
+/// Replace the version for `key` if `version` is higher.
+fn keep_latest_in<K: Ord>(latest: &mut BTreeMap<K, Version>, key: K, version: &Version) {
+    let latest = latest.entry(key).or_insert_with(|| version.clone());
+    if version > latest {
+        *latest = version.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn statistics() {
+    let versions: Vec<Version> = [
+        "0.9",
+        "1.0",
+        "1.0.post1",
+        "1.1",
+        "1.1.1",
+        "1.2.dev0",
+        "1.2a1",
+        "1.2rc1",
+        "1.2rc1.post1",
+        "2",
+        "2.0.1",
+        "2.1b1",
+        "1!0.1",
+    ]
+    .into_iter()
+    .map(|version| Version::from_str(version).unwrap())
+    .collect();
+    let statistics: VersionStatistics = versions.iter().collect();
+    assert_eq!(statistics.total(), versions.len());
+    assert_eq!(statistics.count(Channel::Dev), 1);
+    assert_eq!(statistics.count(Channel::Alpha), 1);
+    assert_eq!(statistics.count(Channel::Beta), 1);
+    assert_eq!(statistics.count(Channel::Rc), 2);
+    assert_eq!(statistics.count(Channel::Stable), 7);
+    assert_eq!(statistics.count(Channel::Post), 1);
+    assert_eq!(statistics.latest_stable().unwrap().to_string(), "1!0.1");
+    assert_eq!(statistics.latest_prerelease().unwrap().to_string(), "2.1b1");
+    let latest_per_major: Vec<(u64, String)> = statistics
+        .latest_per_major()
+        .map(|(major, version)| (major, version.to_string()))
+        .collect();
+    assert_eq!(
+        latest_per_major,
+        [
+            (0, "1!0.1".to_string()),
+            (1, "1.1.1".to_string()),
+            (2, "2.0.1".to_string())
+        ]
+    );
+    let latest_per_minor: Vec<((u64, u64), String)> = statistics
+        .latest_per_minor()
+        .map(|(minor, version)| (minor, version.to_string()))
+        .collect();
+    assert_eq!(
+        latest_per_minor,
+        [
+            ((0, 1), "1!0.1".to_string()),
+            ((0, 9), "0.9".to_string()),
+            ((1, 0), "1.0.post1".to_string()),
+            ((1, 1), "1.1.1".to_string()),
+            ((2, 0), "2.0.1".to_string()),
+        ]
+    );
+    assert_eq!(statistics.latest_for_minor(1, 2), None);
+}
+
+#[test]
+fn empty() {
+    let statistics = VersionStatistics::new();
+    assert_eq!(statistics.total(), 0);
+    assert_eq!(statistics.latest_stable(), None);
+    assert_eq!(statistics.latest_prerelease(), None);
+    assert_eq!(statistics.count(Channel::Stable), 0);
+}
+
+#[test]
+fn group_by_release_trains() {
+    let versions: Vec<Version> = [
+        "2",
+        "1.4.1",
+        "1!1.4",
+        "1.4.0.1",
+        "1.4rc1",
+        "1.3.9",
+        "2.0.0.post1",
+    ]
+    .into_iter()
+    .map(|version| Version::from_str(version).unwrap())
+    .collect();
+    let format = |groups: Vec<(Version, Vec<&Version>)>| -> Vec<String> {
+        groups
+            .into_iter()
+            .map(|(train, versions)| {
+                let versions: Vec<String> = versions.iter().map(ToString::to_string).collect();
+                format!("{train}: {}", versions.join(" "))
+            })
+            .collect()
+    };
+    assert_eq!(
+        format(group_by_release(&versions, 1)),
+        [
+            "1: 1.3.9 1.4rc1 1.4.0.1 1.4.1",
+            "2: 2 2.0.0.post1",
+            "1!1: 1!1.4"
+        ]
+    );
+    assert_eq!(
+        format(group_by_release(&versions, 3)),
+        [
+            "1.3.9: 1.3.9",
+            "1.4.0: 1.4rc1 1.4.0.1",
+            "1.4.1: 1.4.1",
+            "2.0.0: 2 2.0.0.post1",
+            "1!1.4.0: 1!1.4"
+        ]
+    );
+    assert!(group_by_release(Vec::<Version>::new(), 2).is_empty());
+}
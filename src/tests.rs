@@ -1,6 +1,10 @@
-use super::{Version, VersionSpecifier, VersionSpecifiers};
+use super::{Pin, Version, VersionSpecifier, VersionSpecifiers};
 use std::str::FromStr;
 
+mod corpus;
+mod packaging;
+mod properties;
+
 #[test]
 fn test_version() {
     let version = Version::from_str("1.19").unwrap();
@@ -9,3 +13,97 @@ fn test_version() {
     let version_specifiers = VersionSpecifiers::from_str(">=1.16, <2.0").unwrap();
     assert!(version_specifiers.contains(&version));
 }
+
+#[test]
+fn test_satisfies() {
+    let version = Version::from_str("1.19").unwrap();
+    assert!(version.satisfies(&VersionSpecifier::from_str("== 1.*").unwrap()));
+    assert!(!version.satisfies(&VersionSpecifier::from_str("<1.19").unwrap()));
+    assert!(version.satisfies_all(&VersionSpecifiers::from_str(">=1.16, <2.0").unwrap()));
+    assert!(!version.satisfies_all(&VersionSpecifiers::from_str(">=1.16, !=1.19").unwrap()));
+}
+
+#[test]
+fn test_pin() {
+    let version = Version::from_str("1.0.0-rc1+ubuntu-1").unwrap();
+    let pinned = VersionSpecifiers::pinned(&version, Pin::Exact);
+    assert_eq!(pinned.to_string(), "==1.0.0rc1+ubuntu.1");
+    assert!(pinned.contains(&version));
+    assert!(!pinned.contains(&Version::from_str("1.0.0rc1+ubuntu.2").unwrap()));
+
+    let pinned = VersionSpecifiers::pinned(&version, Pin::Public);
+    assert_eq!(pinned.to_string(), "==1.0.0rc1");
+    assert!(pinned.contains(&Version::from_str("1.0.0rc1+ubuntu.2").unwrap()));
+    assert!(!pinned.contains(&Version::from_str("1.0.0").unwrap()));
+
+    let version = Version::from_str("1.0+ubuntu-1").unwrap();
+    let pinned = VersionSpecifiers::pinned(&version, Pin::Arbitrary);
+    assert_eq!(pinned.to_string(), "===1.0+ubuntu.1");
+    assert_eq!(
+        VersionSpecifiers::from_str("===1.0+ubuntu.1").unwrap(),
+        pinned
+    );
+    assert!(pinned.contains(&version));
+    // `==` would match both
+    assert!(!pinned.contains(&Version::from_str("1.0.0+ubuntu.1").unwrap()));
+    assert!(!pinned.contains(&Version::from_str("1.0").unwrap()));
+}
+
+#[test]
+fn test_try_from_str() {
+    /// Generic code that only knows `TryFrom`.
+    fn parse<T>(input: &str) -> Option<(T, T)>
+    where
+        T: for<'a> TryFrom<&'a str> + TryFrom<String>,
+    {
+        let borrowed = T::try_from(input).ok()?;
+        let owned = T::try_from(input.to_string()).ok()?;
+        Some((borrowed, owned))
+    }
+
+    let (version, owned) = parse::<Version>("1.0-rc1").unwrap();
+    assert_eq!(version, owned);
+    assert_eq!(version.to_string(), "1.0rc1");
+    let (specifier, _) = parse::<VersionSpecifier>(">= 1.0").unwrap();
+    assert_eq!(specifier.to_string(), ">=1.0");
+    let (specifiers, _) = parse::<VersionSpecifiers>(">=1.0, <2").unwrap();
+    assert_eq!(specifiers.to_string(), ">=1.0, <2");
+    let (operator, _) = parse::<crate::Operator>("~=").unwrap();
+    assert_eq!(operator, crate::Operator::TildeEqual);
+
+    assert!(parse::<Version>("1.0-foo").is_none());
+    assert!(parse::<VersionSpecifier>("1.0").is_none());
+    assert!(parse::<VersionSpecifiers>(">=1.0,").is_none());
+    assert!(parse::<crate::Operator>("=>").is_none());
+}
+
+/// `serde_with::DisplayFromStr` and similar adapters need `Display` and `FromStr` with a
+/// displayable error.
+#[test]
+fn test_display_from_str() {
+    fn display_from_str<T>(input: &str) -> String
+    where
+        T: std::fmt::Display + FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match T::from_str(input) {
+            Ok(value) => value.to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    assert_eq!(display_from_str::<Version>("1.0-rc1"), "1.0rc1");
+    assert_eq!(display_from_str::<VersionSpecifier>("== 1.*"), "==1.*");
+    assert_eq!(display_from_str::<VersionSpecifiers>(">=1,<2"), ">=1, <2");
+    assert_eq!(display_from_str::<crate::Operator>("~="), "~=");
+    assert_eq!(
+        display_from_str::<crate::ReleaseOnlyVersion>("3.12"),
+        "3.12"
+    );
+    assert_eq!(display_from_str::<crate::Prerelease>("rc1"), "rc1");
+    assert_eq!(
+        display_from_str::<crate::CondaSpecifiers>(">=1,<2|3"),
+        ">=1, <2|==3"
+    );
+    assert!(display_from_str::<Version>("1.0-foo").contains("foo"));
+}
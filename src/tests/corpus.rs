@@ -0,0 +1,81 @@
+//! Regression tests against a golden corpus of version strings and specifiers in the styles found
+//! on PyPI, see `src/tests/corpus`.
+//!
+//! Each corpus file is plain text with `#` comments, so new cases can be added without touching
+//! the code.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::{Version, VersionSpecifiers};
+
+/// The non-empty, non-comment lines of a corpus file.
+fn entries(corpus: &str) -> impl Iterator<Item = &str> {
+    corpus
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+}
+
+/// Split an `<input><TAB><expected>` line.
+fn split_tab(line: &str) -> (&str, &str) {
+    line.split_once('\t')
+        .unwrap_or_else(|| panic!("corpus line without a tab: `{line}`"))
+}
+
+#[test]
+fn corpus_versions() {
+    for line in entries(include_str!("corpus/versions.txt")) {
+        let (input, expected) = split_tab(line);
+        let version = Version::from_str(input)
+            .unwrap_or_else(|err| panic!("failed to parse `{input}`: {err}"));
+        assert_eq!(version.to_string(), expected, "normalizing `{input}`");
+        // The normalized form is stable
+        let reparsed = Version::from_str(expected).unwrap();
+        assert_eq!(reparsed.to_string(), expected);
+        assert_eq!(reparsed, version);
+    }
+}
+
+#[test]
+fn corpus_invalid_versions() {
+    for input in entries(include_str!("corpus/invalid.txt")) {
+        assert!(
+            Version::from_str(input).is_err(),
+            "`{input}` is not a valid version"
+        );
+    }
+}
+
+#[test]
+fn corpus_ordering() {
+    for line in entries(include_str!("corpus/ordering.txt")) {
+        let mut parts = line.split_whitespace();
+        let (Some(a), Some(operator), Some(b), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            panic!("invalid ordering line: `{line}`");
+        };
+        let expected = match operator {
+            "<" => Ordering::Less,
+            "==" => Ordering::Equal,
+            ">" => Ordering::Greater,
+            _ => panic!("invalid operator in ordering line: `{line}`"),
+        };
+        let a = Version::from_str(a).unwrap();
+        let b = Version::from_str(b).unwrap();
+        assert_eq!(a.cmp(&b), expected, "{line}");
+        assert_eq!(b.cmp(&a), expected.reverse(), "{line} (reversed)");
+    }
+}
+
+#[test]
+fn corpus_specifiers() {
+    for line in entries(include_str!("corpus/specifiers.txt")) {
+        let (input, expected) = split_tab(line);
+        let specifiers = VersionSpecifiers::from_str(input)
+            .unwrap_or_else(|err| panic!("failed to parse `{input}`: {err}"));
+        assert_eq!(specifiers.to_string(), expected, "normalizing `{input}`");
+        let reparsed = VersionSpecifiers::from_str(expected).unwrap();
+        assert_eq!(reparsed, specifiers);
+    }
+}
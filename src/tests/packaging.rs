@@ -0,0 +1,231 @@
+//! Differential tests against [pypa/packaging](https://github.com/pypa/packaging).
+//!
+//! These need a Python interpreter with `packaging` installed (or a `pip` that vendors it), so
+//! they are ignored by default. Run them with:
+//!
+//! ```shell
+//! cargo test packaging -- --ignored
+//! ```
+//!
+//! The interpreter can be overridden with `PEP440_PYTHON`.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{Version, VersionSpecifiers};
+
+/// The output of `test/packaging_oracle.py`.
+#[derive(Deserialize)]
+struct Oracle {
+    /// The normalized version, or `None` if the version is invalid.
+    versions: Vec<Option<String>>,
+    /// The indices of the valid versions, sorted by version.
+    order: Vec<usize>,
+    /// Whether the specifiers are valid.
+    specifiers: Vec<bool>,
+    /// For each specifier, whether it contains each valid version.
+    contains: Vec<Option<Vec<bool>>>,
+}
+
+fn run_oracle(versions: &[String], specifiers: &[String]) -> Oracle {
+    let python = std::env::var("PEP440_PYTHON").unwrap_or_else(|_| "python3".to_string());
+    let script = concat!(env!("CARGO_MANIFEST_DIR"), "/test/packaging_oracle.py");
+    let mut child = Command::new(python)
+        .arg(script)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start python");
+    let request = serde_json::json!({ "versions": versions, "specifiers": specifiers });
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(request.to_string().as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success(), "the packaging oracle failed");
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+/// Version strings covering all components and many of their alternative spellings, plus some
+/// invalid ones.
+fn version_corpus() -> Vec<String> {
+    let epochs = ["", "1!"];
+    let releases = ["0", "1", "1.0", "1.0.0", "1.2", "1.2.3", "01.02", "2023.10"];
+    let pres = ["", "a1", "b2", "rc1", "c3", "-alpha.1", "pre", "Preview2"];
+    let posts = ["", ".post1", "-1", "post", ".rev2", "_r3"];
+    let devs = ["", ".dev0", "dev1", "-DEV"];
+    let locals = ["", "+local", "+ubuntu-1", "+1.Abc"];
+    let mut versions = Vec::new();
+    for epoch in epochs {
+        for release in releases {
+            for pre in pres {
+                for post in posts {
+                    for dev in devs {
+                        for local in locals {
+                            versions.push(format!("{epoch}{release}{pre}{post}{dev}{local}"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    versions.extend(
+        [
+            "v1.0",
+            " 1.0 ",
+            "1.0.*",
+            "1..0",
+            "abc",
+            "1.0+",
+            "1.0a1a2",
+            "1.0-",
+            "",
+            "1.0+a..b",
+            "1!",
+            "1.0 extra",
+            "١.٠",
+        ]
+        .map(ToString::to_string),
+    );
+    versions
+}
+
+/// Specifiers combining each operator with interesting versions.
+fn specifier_corpus() -> Vec<String> {
+    let operators = ["==", "!=", "<=", ">=", "<", ">", "~=", "==="];
+    let versions = [
+        "1",
+        "1.0",
+        "1.2",
+        "1.2.3",
+        "1.0a1",
+        "1.0.post1",
+        "1.0.dev0",
+        "1.0+local",
+        "1!1.0",
+        "1.2.*",
+        "1.*",
+        "2023.10",
+    ];
+    let mut specifiers = Vec::new();
+    for operator in operators {
+        for version in versions {
+            specifiers.push(format!("{operator}{version}"));
+        }
+    }
+    specifiers.extend(
+        [
+            ">=1.0, <2.0",
+            ">=1.0a1, !=1.2.*, <2",
+            "~=1.2, !=1.2.3",
+            ">1.0.post1, <=1.2.3+local",
+            "=>1.0",
+            ">=1.0,",
+        ]
+        .map(ToString::to_string),
+    );
+    specifiers
+}
+
+#[test]
+#[ignore = "Requires python with packaging"]
+fn packaging_differential() {
+    let versions = version_corpus();
+    let specifiers = specifier_corpus();
+    let oracle = run_oracle(&versions, &specifiers);
+
+    let mut divergences = Vec::new();
+
+    // Parsing and normalization
+    let parsed: Vec<Option<Version>> = versions
+        .iter()
+        .map(|version| Version::from_str(version).ok())
+        .collect();
+    for ((input, ours), theirs) in versions.iter().zip(&parsed).zip(&oracle.versions) {
+        let ours = ours.as_ref().map(ToString::to_string);
+        if ours != *theirs {
+            divergences.push(format!(
+                "version `{input}`: pep440_rs {ours:?}, packaging {theirs:?}"
+            ));
+        }
+    }
+
+    // Ordering, using the versions that are valid in both implementations
+    let valid: Vec<usize> = (0..versions.len())
+        .filter(|idx| parsed[*idx].is_some() && oracle.versions[*idx].is_some())
+        .collect();
+    let mut order = valid.clone();
+    order.sort_by(|a, b| parsed[*a].cmp(&parsed[*b]));
+    let theirs_order: Vec<usize> = oracle
+        .order
+        .iter()
+        .copied()
+        .filter(|idx| valid.contains(idx))
+        .collect();
+    if let Some((ours, theirs)) = order
+        .iter()
+        .zip(&theirs_order)
+        .find(|(ours, theirs)| ours != theirs)
+    {
+        divergences.push(format!(
+            "ordering: pep440_rs sorts `{}` where packaging sorts `{}`",
+            versions[*ours], versions[*theirs]
+        ));
+    }
+
+    // Parsing and matching specifiers
+    // The oracle only reports `contains` for the versions that are valid for packaging.
+    let packaging_valid: BTreeMap<usize, usize> = oracle
+        .versions
+        .iter()
+        .enumerate()
+        .filter(|(_, version)| version.is_some())
+        .enumerate()
+        .map(|(position, (idx, _))| (idx, position))
+        .collect();
+    for ((input, theirs_valid), theirs_contains) in specifiers
+        .iter()
+        .zip(&oracle.specifiers)
+        .zip(&oracle.contains)
+    {
+        let ours = VersionSpecifiers::from_str(input).ok();
+        if ours.is_some() != *theirs_valid {
+            divergences.push(format!(
+                "specifiers `{input}`: pep440_rs valid: {}, packaging valid: {theirs_valid}",
+                ours.is_some()
+            ));
+        }
+        let (Some(ours), Some(theirs_contains)) = (ours, theirs_contains) else {
+            continue;
+        };
+        for idx in &valid {
+            let version = parsed[*idx].as_ref().unwrap();
+            let theirs = theirs_contains[packaging_valid[idx]];
+            if ours.contains(version) != theirs {
+                divergences.push(format!(
+                    "`{input}` contains `{}`: pep440_rs {}, packaging {theirs}",
+                    versions[*idx],
+                    ours.contains(version)
+                ));
+            }
+        }
+    }
+
+    assert!(
+        divergences.is_empty(),
+        "{} divergences from packaging, showing the first 50:\n{}",
+        divergences.len(),
+        divergences
+            .iter()
+            .take(50)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -0,0 +1,251 @@
+//! Property-based tests for the invariants that the rest of the crate relies on.
+
+use std::cmp::Ordering;
+use std::hash::BuildHasher;
+use std::ops::RangeBounds;
+use std::str::FromStr;
+
+use proptest::prelude::*;
+
+use crate::{
+    is_valid_specifier, is_valid_version, ComparisonReason, CompiledSpecifiers, LocalSegment,
+    Operator, Prerelease, PrereleaseKind, Version, VersionSpecifier, VersionSpecifiers,
+};
+
+/// Small numbers, so that generated versions collide and compare equal often enough.
+fn number() -> impl Strategy<Value = u64> {
+    prop_oneof![0..4u64, Just(10)]
+}
+
+fn prerelease() -> impl Strategy<Value = Prerelease> {
+    (
+        prop_oneof![
+            Just(PrereleaseKind::Alpha),
+            Just(PrereleaseKind::Beta),
+            Just(PrereleaseKind::Rc),
+        ],
+        number(),
+    )
+        .prop_map(|(kind, number)| Prerelease { kind, number })
+}
+
+fn local_segment() -> impl Strategy<Value = LocalSegment> {
+    prop_oneof![
+        number().prop_map(LocalSegment::Number),
+        "[a-z][a-z0-9]{0,3}".prop_map(LocalSegment::String),
+    ]
+}
+
+/// Versions with `release_len` release segments and all other components.
+fn version_with_release(
+    release_len: std::ops::RangeInclusive<usize>,
+) -> impl Strategy<Value = Version> {
+    (
+        prop_oneof![3 => Just(0u64), 1 => 1..3u64],
+        prop::collection::vec(number(), release_len),
+        prop::option::weighted(0.3, prerelease()),
+        prop::option::weighted(0.3, number()),
+        prop::option::weighted(0.3, number()),
+        prop::collection::vec(local_segment(), 0..3),
+    )
+        .prop_map(|(epoch, release, pre, post, dev, local)| {
+            Version::new(release)
+                .with_epoch(epoch)
+                .with_pre(pre)
+                .with_post(post)
+                .with_dev(dev)
+                .with_local(local)
+        })
+}
+
+fn version() -> impl Strategy<Value = Version> {
+    version_with_release(1..=4)
+}
+
+/// Specifiers of any operator except `===`, whose string comparison has no version semantics.
+fn specifier() -> impl Strategy<Value = VersionSpecifier> {
+    specifier_of(version_with_release(1..=3))
+}
+
+fn specifier_of(
+    versions: impl Strategy<Value = Version>,
+) -> impl Strategy<Value = VersionSpecifier> {
+    (
+        prop_oneof![
+            Just(Operator::Equal),
+            Just(Operator::EqualStar),
+            Just(Operator::NotEqual),
+            Just(Operator::NotEqualStar),
+            Just(Operator::TildeEqual),
+            Just(Operator::LessThan),
+            Just(Operator::LessThanEqual),
+            Just(Operator::GreaterThan),
+            Just(Operator::GreaterThanEqual),
+        ],
+        versions,
+    )
+        .prop_filter_map("not a valid specifier", |(operator, version)| {
+            // Only `==` and `!=` allow local versions, and the star operators only allow a
+            // release.
+            let version = match operator {
+                Operator::Equal | Operator::NotEqual => version,
+                Operator::EqualStar | Operator::NotEqualStar => {
+                    Version::new(version.release()).with_epoch(version.epoch())
+                }
+                _ => version.without_local(),
+            };
+            VersionSpecifier::from_version(operator, version).ok()
+        })
+}
+
+fn specifiers() -> impl Strategy<Value = VersionSpecifiers> {
+    prop::collection::vec(specifier(), 0..4).prop_map(VersionSpecifiers::from_iter)
+}
+
+/// Specifiers whose versions consist of only an epoch and a release.
+#[cfg(feature = "version-ranges")]
+fn release_specifiers() -> impl Strategy<Value = VersionSpecifiers> {
+    let versions = version_with_release(1..=3)
+        .prop_map(|version| Version::new(version.release()).with_epoch(version.epoch()));
+    prop::collection::vec(specifier_of(versions), 0..4).prop_map(VersionSpecifiers::from_iter)
+}
+
+proptest! {
+    #[test]
+    fn ord_is_a_total_order(a in version(), b in version(), c in version()) {
+        prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        prop_assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        prop_assert_eq!(a.cmp(&a), Ordering::Equal);
+        if a <= b && b <= c {
+            prop_assert!(a <= c);
+        }
+    }
+
+    #[test]
+    fn explain_cmp_agrees_with_ord(a in version(), b in version()) {
+        let (ordering, reason) = a.explain_cmp(&b);
+        prop_assert_eq!(ordering, a.cmp(&b));
+        prop_assert_eq!(ordering == Ordering::Equal, reason == ComparisonReason::Equal);
+    }
+
+    #[test]
+    fn eq_is_consistent_with_ord_and_hash(a in version(), b in version()) {
+        prop_assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+        if a == b {
+            let state = std::collections::hash_map::RandomState::new();
+            prop_assert_eq!(state.hash_one(&a), state.hash_one(&b));
+        }
+    }
+
+    #[test]
+    fn sort_key_string_preserves_order(a in version(), b in version()) {
+        prop_assert_eq!(a.sort_key_string().cmp(&b.sort_key_string()), a.cmp(&b));
+    }
+
+    #[test]
+    fn bytes_preserve_order(a in version(), b in version()) {
+        prop_assert_eq!(a.to_bytes().cmp(&b.to_bytes()), a.cmp(&b));
+        prop_assert_eq!(Version::from_bytes(&a.to_bytes()).unwrap(), a);
+    }
+
+    #[test]
+    fn successors_are_greater(version in version()) {
+        for (bump, successor) in version.successors() {
+            prop_assert!(successor > version, "{:?} {}", bump, successor);
+        }
+    }
+
+    #[test]
+    fn version_display_round_trips(version in version()) {
+        let reparsed = Version::from_str(&version.to_string()).unwrap();
+        prop_assert_eq!(&reparsed, &version);
+        prop_assert_eq!(reparsed.to_string(), version.to_string());
+    }
+
+    #[test]
+    fn parsing_is_idempotent(input in "[vV]?[0-9]{1,3}([._-][0-9]{1,3}){0,2}([._-]?(a|b|c|rc|alpha|beta|pre|post|dev|r)[._-]?[0-9]{0,2}){0,3}(\\+[a-zA-Z0-9]{1,3}([._-][a-zA-Z0-9]{1,3}){0,2})?") {
+        if let Ok(version) = Version::from_str(&input) {
+            let reparsed = Version::from_str(&version.to_string()).unwrap();
+            prop_assert_eq!(reparsed.to_string(), version.to_string());
+            prop_assert_eq!(reparsed, version);
+        }
+    }
+
+    #[test]
+    fn is_valid_version_agrees_with_parsing(input in "[vV]?[0-9]{1,3}(!)?([._-][0-9]{1,3}){0,5}(\\.\\*)?([._-]?(a|b|rc|post|dev|x)[._-]?[0-9]{0,2}){0,3}(\\+[a-zA-Z0-9]{0,3}([._-][a-zA-Z0-9]{0,3}){0,2})?") {
+        prop_assert_eq!(is_valid_version(&input), Version::from_str(&input).is_ok());
+    }
+
+    #[test]
+    fn is_valid_specifier_agrees_with_parsing(input in " ?(==|!=|~=|<|<=|>|>=|===|=>)? ?[0-9]{1,2}(\\.[0-9]{1,2}){0,2}(\\.\\*)?(rc1|\\.post1)?(\\+[a-z0-9]{0,2})? ?") {
+        prop_assert_eq!(is_valid_specifier(&input), VersionSpecifier::from_str(&input).is_ok());
+    }
+
+    #[test]
+    fn warnings_iff_not_normalized(input in "[vV]?0?[0-9]{1,2}([._-]0?[0-9]{1,2}){0,2}([._-]?(a|rc|alpha|post|dev)[._-]?[0-9]{0,2}){0,2}(\\+[a-zA-Z0-9]{1,3})?") {
+        if let Ok((version, warnings)) = Version::from_str_with_warnings(&input) {
+            prop_assert_eq!(warnings.is_empty(), version.to_string() == input);
+        }
+    }
+
+    #[test]
+    fn compiled_specifiers_agree(specifiers in specifiers(), version in version()) {
+        let compiled = CompiledSpecifiers::new(&specifiers);
+        prop_assert_eq!(compiled.contains(&version), specifiers.contains(&version));
+    }
+
+    #[test]
+    fn bounding_range_contains_matches(specifiers in specifiers(), version in version()) {
+        if specifiers.contains(&version) {
+            let bounds = specifiers.bounding_range();
+            prop_assert!(bounds.as_ref().is_some_and(|bounds| bounds.contains(&version)), "{:?}", bounds);
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_contains(specifiers in specifiers(), version in version()) {
+        let mut simplified = specifiers.clone();
+        simplified.simplify();
+        prop_assert_eq!(simplified.contains(&version), specifiers.contains(&version));
+    }
+
+    #[test]
+    fn minimize_preserves_available(specifiers in specifiers(), available in prop::collection::vec(version(), 0..8)) {
+        let minimized = specifiers.minimize(&available);
+        prop_assert!(minimized.len() <= specifiers.len());
+        for version in &available {
+            prop_assert_eq!(minimized.contains(version), specifiers.contains(version));
+        }
+    }
+
+    #[test]
+    fn specifiers_eq_ignores_order(specifiers in specifiers()) {
+        let reversed: VersionSpecifiers = specifiers.iter().rev().cloned().collect();
+        prop_assert_eq!(&reversed, &specifiers);
+        let state = std::collections::hash_map::RandomState::new();
+        prop_assert_eq!(state.hash_one(&reversed), state.hash_one(&specifiers));
+        prop_assert_eq!(reversed.to_string(), specifiers.to_string());
+    }
+
+    #[test]
+    fn specifiers_display_round_trips(specifiers in specifiers()) {
+        let reparsed = VersionSpecifiers::from_str(&specifiers.to_string()).unwrap();
+        prop_assert_eq!(reparsed, specifiers);
+    }
+}
+
+#[cfg(feature = "version-ranges")]
+proptest! {
+    /// `contains` agrees with the interval decomposition from [`version_ranges`].
+    ///
+    /// The specifiers are release-only, since `contains` follows packaging in excluding pre- and
+    /// post-releases of the same release for `<V` and `>V`, while the ranges only exclude those of
+    /// `V` itself. Candidates have no local version, which specifiers without a local version
+    /// ignore but singleton ranges don't.
+    #[test]
+    fn contains_matches_ranges(specifiers in release_specifiers(), version in version()) {
+        let version = version.without_local();
+        let range = version_ranges::Ranges::<Version>::from(specifiers.clone());
+        prop_assert_eq!(specifiers.contains(&version), range.contains(&version));
+    }
+}
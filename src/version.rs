@@ -1,13 +1,15 @@
 use once_cell::sync::Lazy;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
-    borrow::Borrow,
-    cmp::Ordering,
+    borrow::{Borrow, Cow},
+    cmp::{Ordering, Reverse},
     hash::{Hash, Hasher},
     str::FromStr,
     sync::Arc,
 };
 
+use crate::{VersionSpecifier, VersionSpecifiers};
+
 /// One of `~=` `==` `!=` `<=` `>=` `<` `>` `===`
 #[derive(Eq, Ord, PartialEq, PartialOrd, Debug, Hash, Clone, Copy)]
 #[cfg_attr(
@@ -46,6 +48,42 @@ pub enum Operator {
 }
 
 impl Operator {
+    /// All operators, in the order of their declaration.
+    pub const ALL: [Operator; 10] = [
+        Operator::Equal,
+        Operator::EqualStar,
+        Operator::ExactEqual,
+        Operator::NotEqual,
+        Operator::NotEqualStar,
+        Operator::TildeEqual,
+        Operator::LessThan,
+        Operator::LessThanEqual,
+        Operator::GreaterThan,
+        Operator::GreaterThanEqual,
+    ];
+
+    /// The operator as it is written in a specifier, e.g. `>=`.
+    ///
+    /// Like the `Display` implementation, this returns `==` and `!=` for
+    /// [`Operator::EqualStar`] and [`Operator::NotEqualStar`], since the star
+    /// is written after the version.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Equal => "==",
+            // Beware, this doesn't return the star
+            Self::EqualStar => "==",
+            #[allow(deprecated)]
+            Self::ExactEqual => "===",
+            Self::NotEqual => "!=",
+            Self::NotEqualStar => "!=",
+            Self::TildeEqual => "~=",
+            Self::LessThan => "<",
+            Self::LessThanEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanEqual => ">=",
+        }
+    }
+
     /// Negates this operator, if a negation exists, so that it has the
     /// opposite meaning.
     ///
@@ -81,8 +119,15 @@ impl Operator {
     /// NOT permitted in this version specifier." phrasing in the version
     /// specifiers [spec].
     ///
+    /// ```rust
+    /// use pep440_rs::Operator;
+    ///
+    /// assert!(Operator::Equal.allows_local());
+    /// assert!(!Operator::GreaterThanEqual.allows_local());
+    /// ```
+    ///
     /// [spec]: https://packaging.python.org/en/latest/specifications/version-specifiers/
-    pub(crate) fn is_local_compatible(self) -> bool {
+    pub fn allows_local(self) -> bool {
         !matches!(
             self,
             Self::GreaterThan
@@ -95,6 +140,28 @@ impl Operator {
         )
     }
 
+    /// Returns true if this operator can be used with a version ending in `.*`, i.e., it is `==`
+    /// or `!=`, or already their star variant.
+    ///
+    /// ```rust
+    /// use pep440_rs::Operator;
+    ///
+    /// assert!(Operator::NotEqual.allows_star());
+    /// assert!(!Operator::GreaterThanEqual.allows_star());
+    /// ```
+    pub fn allows_star(self) -> bool {
+        matches!(
+            self,
+            Self::Equal | Self::NotEqual | Self::EqualStar | Self::NotEqualStar
+        )
+    }
+
+    /// Returns true if the version of this operator needs at least two release numbers, which is
+    /// the case for `~=`: `~=1` is invalid, since there is no prefix to stay compatible with.
+    pub fn requires_two_release_segments(self) -> bool {
+        self == Self::TildeEqual
+    }
+
     /// Returns the wildcard version of this operator, if appropriate.
     ///
     /// This returns `None` when this operator doesn't have an analogous
@@ -116,10 +183,13 @@ impl Operator {
 impl FromStr for Operator {
     type Err = OperatorParseError;
 
-    /// Notably, this does not know about star versions, it just assumes the base operator
+    /// `==` and `!=` are the plain operators, while `==*` and `!=*` are the
+    /// star operators that are written as `==1.2.*` and `!=1.2.*` in a
+    /// specifier.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let operator = match s {
             "==" => Self::Equal,
+            "==*" => Self::EqualStar,
             "===" => {
                 #[cfg(feature = "tracing")]
                 {
@@ -129,6 +199,7 @@ impl FromStr for Operator {
                 Self::ExactEqual
             }
             "!=" => Self::NotEqual,
+            "!=*" => Self::NotEqualStar,
             "~=" => Self::TildeEqual,
             "<" => Self::LessThan,
             "<=" => Self::LessThanEqual,
@@ -144,25 +215,28 @@ impl FromStr for Operator {
     }
 }
 
+/// Parse with [`Operator::from_str`].
+impl TryFrom<&str> for Operator {
+    type Error = OperatorParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+/// Parse with [`Operator::from_str`].
+impl TryFrom<String> for Operator {
+    type Error = OperatorParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
 impl std::fmt::Display for Operator {
     /// Note the `EqualStar` is also `==`.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let operator = match self {
-            Self::Equal => "==",
-            // Beware, this doesn't print the star
-            Self::EqualStar => "==",
-            #[allow(deprecated)]
-            Self::ExactEqual => "===",
-            Self::NotEqual => "!=",
-            Self::NotEqualStar => "!=",
-            Self::TildeEqual => "~=",
-            Self::LessThan => "<",
-            Self::LessThanEqual => "<=",
-            Self::GreaterThan => ">",
-            Self::GreaterThanEqual => ">=",
-        };
-
-        write!(f, "{operator}")
+        f.pad(self.as_str())
     }
 }
 
@@ -302,12 +376,212 @@ impl Version {
         .with_release(release_numbers)
     }
 
+    /// Create a version from all of its parts at once, without checking them, for data that was
+    /// already validated, e.g. versions read back from a cache.
+    ///
+    /// The release and the local segments are moved into the version instead of being copied
+    /// number by number. The caller must ensure that the release isn't empty; unlike
+    /// [`Version::new`], an empty release doesn't panic here, but the version will display,
+    /// compare and hash incorrectly.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{LocalSegment, Prerelease, PrereleaseKind, Version};
+    ///
+    /// let version = Version::new_unchecked(
+    ///     0,
+    ///     vec![1, 2, 3],
+    ///     Some(Prerelease { kind: PrereleaseKind::Rc, number: 1 }),
+    ///     None,
+    ///     None,
+    ///     vec![LocalSegment::String("cpu".to_string())],
+    /// );
+    /// assert_eq!(version, Version::from_str("1.2.3rc1+cpu").unwrap());
+    /// ```
+    pub fn new_unchecked(
+        epoch: u64,
+        release: Vec<u64>,
+        pre: Option<Prerelease>,
+        post: Option<u64>,
+        dev: Option<u64>,
+        local: Vec<LocalSegment>,
+    ) -> Self {
+        // Keep the compact representation for common versions, which compares faster
+        if local.is_empty() && release.len() <= 4 {
+            let mut version = Self {
+                inner: Arc::new(VersionInner::Small {
+                    small: VersionSmall::new(),
+                }),
+            };
+            version.clear_release();
+            for n in release {
+                version.push_release(n);
+            }
+            return version
+                .with_epoch(epoch)
+                .with_pre(pre)
+                .with_post(post)
+                .with_dev(dev);
+        }
+        Self {
+            inner: Arc::new(VersionInner::Full {
+                full: VersionFull {
+                    epoch,
+                    release,
+                    pre,
+                    post,
+                    dev,
+                    local,
+                    min: None,
+                    max: None,
+                },
+            }),
+        }
+    }
+
     /// Whether this is an alpha/beta/rc or dev version
     #[inline]
     pub fn any_prerelease(&self) -> bool {
         self.is_pre() || self.is_dev()
     }
 
+    /// Whether this is a pre-release or dev release leading up to the given final release, i.e.,
+    /// it has the same epoch and release, but comes before it.
+    ///
+    /// Upgrade tools use this to treat `2.0rc1` as already being on `2.0`. Returns `false` if
+    /// `release` isn't a final release itself. Local versions are ignored.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let release = Version::from_str("2.0").unwrap();
+    /// assert!(Version::from_str("2.0rc1").unwrap().is_prerelease_of(&release));
+    /// assert!(Version::from_str("2.0.0.dev3").unwrap().is_prerelease_of(&release));
+    /// assert!(!Version::from_str("2.0").unwrap().is_prerelease_of(&release));
+    /// assert!(!Version::from_str("2.1rc1").unwrap().is_prerelease_of(&release));
+    /// // A dev release of a post-release comes after the release
+    /// assert!(!Version::from_str("2.0.post1.dev1").unwrap().is_prerelease_of(&release));
+    /// ```
+    pub fn is_prerelease_of(&self, release: &Version) -> bool {
+        release.is_stable()
+            && !release.is_post()
+            && self.any_prerelease()
+            && self.epoch() == release.epoch()
+            && self.release().cmp_padded(&release.release()) == Ordering::Equal
+            && self.cmp_public(release) == Ordering::Less
+    }
+
+    /// The next version for the given kind of release, or `None` if that kind of release can't
+    /// follow this version, e.g. an alpha after a beta.
+    ///
+    /// Local versions are dropped. The release keeps its length if it is long enough for the
+    /// incremented number, so `1.2.3` becomes `1.3.0` and `1.2` becomes `1.3` for
+    /// [`Bump::Minor`].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Bump, Version};
+    ///
+    /// let version = Version::from_str("1.2b3").unwrap();
+    /// let bump = |bump| version.bump(bump).map(|version| version.to_string());
+    /// assert_eq!(bump(Bump::Patch).as_deref(), Some("1.2.1"));
+    /// assert_eq!(bump(Bump::Beta).as_deref(), Some("1.2b4"));
+    /// assert_eq!(bump(Bump::Rc).as_deref(), Some("1.2rc1"));
+    /// assert_eq!(bump(Bump::Final).as_deref(), Some("1.2"));
+    /// assert_eq!(bump(Bump::Alpha), None);
+    ///
+    /// // The dev releases of a pre-release lead up to it
+    /// let version = Version::from_str("1.2b3.dev1").unwrap();
+    /// assert_eq!(version.bump(Bump::Beta).unwrap().to_string(), "1.2b3");
+    /// ```
+    pub fn bump(&self, bump: Bump) -> Option<Version> {
+        let release_with = |index: usize| {
+            let mut release = self.release().to_vec();
+            release.resize(release.len().max(index + 1), 0);
+            release[index] = release[index].checked_add(1)?;
+            release[index + 1..].fill(0);
+            Some(Version::new(release).with_epoch(self.epoch()))
+        };
+        let public = self.clone().without_local();
+        match bump {
+            Bump::Major => release_with(0),
+            Bump::Minor => release_with(1),
+            Bump::Patch => release_with(2),
+            Bump::Alpha | Bump::Beta | Bump::Rc => {
+                let kind = match bump {
+                    Bump::Alpha => PrereleaseKind::Alpha,
+                    Bump::Beta => PrereleaseKind::Beta,
+                    _ => PrereleaseKind::Rc,
+                };
+                // A dev release leads up to the version without the dev release, e.g.
+                // `1.2b3.dev1` to `1.2b3` and `1.2.dev1` to `1.2a1`
+                let leads_up = self.is_dev() && !self.is_post();
+                let number = match self.pre() {
+                    Some(pre) => match pre.kind.cmp(&kind) {
+                        Ordering::Less => 1,
+                        Ordering::Equal if leads_up => pre.number,
+                        Ordering::Equal => pre.number.checked_add(1)?,
+                        Ordering::Greater => return None,
+                    },
+                    None if leads_up => 1,
+                    None => return None,
+                };
+                Some(
+                    Version::new(self.release().iter())
+                        .with_epoch(self.epoch())
+                        .with_pre(Some(Prerelease { kind, number })),
+                )
+            }
+            Bump::Final if self.is_pre() => {
+                Some(Version::new(self.release().iter()).with_epoch(self.epoch()))
+            }
+            Bump::Final if self.is_dev() => Some(public.with_dev(None)),
+            Bump::Final => None,
+            Bump::Post if self.is_dev() => None,
+            Bump::Post => {
+                let post = self.post().map_or(Some(1), |post| post.checked_add(1))?;
+                Some(public.with_post(Some(post)))
+            }
+            Bump::Dev => {
+                let dev = self.dev()?.checked_add(1)?;
+                Some(public.with_dev(Some(dev)))
+            }
+        }
+    }
+
+    /// The plausible next versions with how they follow this version, in ascending order, see
+    /// [`Version::bump`].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Bump, Version};
+    ///
+    /// let successors: Vec<_> = Version::from_str("1.2.3")
+    ///     .unwrap()
+    ///     .successors()
+    ///     .into_iter()
+    ///     .map(|(bump, version)| (bump, version.to_string()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     successors,
+    ///     [
+    ///         (Bump::Post, "1.2.3.post1".to_string()),
+    ///         (Bump::Patch, "1.2.4".to_string()),
+    ///         (Bump::Minor, "1.3.0".to_string()),
+    ///         (Bump::Major, "2.0.0".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn successors(&self) -> Vec<(Bump, Version)> {
+        let mut successors: Vec<(Bump, Version)> = Bump::ALL
+            .into_iter()
+            .filter_map(|bump| Some((bump, self.bump(bump)?)))
+            .collect();
+        successors.sort_by(|(_, a), (_, b)| a.cmp(b));
+        successors
+    }
+
     /// Whether this is a stable version (i.e., _not_ an alpha/beta/rc or dev version)
     #[inline]
     pub fn is_stable(&self) -> bool {
@@ -332,6 +606,40 @@ impl Version {
         self.post().is_some()
     }
 
+    /// The release channel of this version, for policies such as "only stable releases" or
+    /// "also allow betas".
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Channel, Version};
+    ///
+    /// assert_eq!(Version::from_str("2.0b1").unwrap().channel(), Channel::Beta);
+    /// assert_eq!(Version::from_str("2.0b1.dev1").unwrap().channel(), Channel::Dev);
+    /// // Allow betas and everything more stable
+    /// assert!(Version::from_str("2.0rc1").unwrap().channel() >= Channel::Beta);
+    /// ```
+    pub fn channel(&self) -> Channel {
+        if self.is_dev() {
+            return Channel::Dev;
+        }
+        match self.pre() {
+            Some(Prerelease {
+                kind: PrereleaseKind::Alpha,
+                ..
+            }) => Channel::Alpha,
+            Some(Prerelease {
+                kind: PrereleaseKind::Beta,
+                ..
+            }) => Channel::Beta,
+            Some(Prerelease {
+                kind: PrereleaseKind::Rc,
+                ..
+            }) => Channel::Rc,
+            None if self.is_post() => Channel::Post,
+            None => Channel::Stable,
+        }
+    }
+
     /// Whether this is a local version (e.g. `1.2.3+localsuffixesareweird`)
     ///
     /// When true, it is guaranteed that the slice returned by
@@ -341,6 +649,26 @@ impl Version {
         !self.local().is_empty()
     }
 
+    /// Whether this version matches the specifier, the same as [`VersionSpecifier::contains`].
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifier};
+    ///
+    /// let version = Version::from_str("1.19").unwrap();
+    /// assert!(version.satisfies(&VersionSpecifier::from_str(">=1.16").unwrap()));
+    /// ```
+    #[inline]
+    pub fn satisfies(&self, specifier: &VersionSpecifier) -> bool {
+        specifier.contains(self)
+    }
+
+    /// Whether this version matches all specifiers, the same as [`VersionSpecifiers::contains`].
+    #[inline]
+    pub fn satisfies_all(&self, specifiers: &VersionSpecifiers) -> bool {
+        specifiers.contains(self)
+    }
+
     /// Returns the epoch of this version.
     #[inline]
     pub fn epoch(&self) -> u64 {
@@ -352,11 +680,12 @@ impl Version {
 
     /// Returns the release number part of the version.
     #[inline]
-    pub fn release(&self) -> &[u64] {
-        match *self.inner {
+    pub fn release(&self) -> Release<'_> {
+        let numbers = match *self.inner {
             VersionInner::Small { ref small } => small.release(),
             VersionInner::Full { ref full, .. } => &full.release,
-        }
+        };
+        Release { numbers }
     }
 
     /// Returns the pre-release part of this version, if it exists.
@@ -395,6 +724,32 @@ impl Version {
         }
     }
 
+    /// Returns the local version label without the `+`, e.g. `cu118.post1` for
+    /// `2.0.0+cu118.post1`, or `None` if there are no local segments.
+    ///
+    /// Use [`Version::local`] for the individual segments.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let version = Version::from_str("2.0.0+CU118-Post1").unwrap();
+    /// assert_eq!(version.local_str().as_deref(), Some("cu118.post1"));
+    /// assert_eq!(Version::from_str("2.0.0").unwrap().local_str(), None);
+    /// ```
+    pub fn local_str(&self) -> Option<String> {
+        use std::fmt::Write;
+
+        let mut segments = self.local().iter();
+        let first = segments.next()?;
+        let mut local = first.to_string();
+        for segment in segments {
+            local.push('.');
+            write!(local, "{segment}").unwrap();
+        }
+        Some(local)
+    }
+
     /// Returns the min-release part of this version, if it exists.
     ///
     /// The "min" component is internal-only, and does not exist in PEP 440.
@@ -603,6 +958,153 @@ impl Version {
         self
     }
 
+    /// Destructure the version into its components.
+    ///
+    /// The internal-only min and max components are dropped.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{LocalSegment, Version};
+    ///
+    /// let mut parts = Version::from_str("1.2.3+ubuntu.1").unwrap().into_parts();
+    /// parts.local = vec![LocalSegment::String("debian".to_string())];
+    /// let version = Version::from_parts(parts).unwrap();
+    /// assert_eq!(version.to_string(), "1.2.3+debian");
+    /// ```
+    pub fn into_parts(self) -> VersionParts {
+        let mut parts = VersionParts {
+            epoch: self.epoch(),
+            release: self.release().to_vec(),
+            pre: self.pre(),
+            post: self.post(),
+            dev: self.dev(),
+            local: Vec::new(),
+        };
+        // Avoid cloning the local segments if we own the only reference
+        parts.local = match Arc::try_unwrap(self.inner) {
+            Ok(VersionInner::Full { full }) => full.local,
+            Ok(VersionInner::Small { .. }) => Vec::new(),
+            Err(inner) => match *inner {
+                VersionInner::Full { ref full } => full.local.clone(),
+                VersionInner::Small { .. } => Vec::new(),
+            },
+        };
+        parts
+    }
+
+    /// Build a version from its components, checking that they can occur in a parsed version.
+    ///
+    /// The release must not be empty, and string segments of the local version must be
+    /// non-empty lowercase ASCII alphanumerics that aren't all digits.
+    pub fn from_parts(parts: VersionParts) -> Result<Self, VersionPartsError> {
+        if parts.release.is_empty() {
+            return Err(PartsErrorKind::EmptyRelease.into());
+        }
+        for segment in &parts.local {
+            if let LocalSegment::String(segment) = segment {
+                if segment.is_empty()
+                    || segment.bytes().all(|b| b.is_ascii_digit())
+                    || !segment
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+                {
+                    return Err(PartsErrorKind::InvalidLocalSegment {
+                        segment: segment.clone(),
+                    }
+                    .into());
+                }
+            }
+        }
+        Ok(Self::new(parts.release)
+            .with_epoch(parts.epoch)
+            .with_pre(parts.pre)
+            .with_post(parts.post)
+            .with_dev(parts.dev)
+            .with_local(parts.local))
+    }
+
+    /// Compare only the release numbers, padding the shorter release with zeros, and ignore the
+    /// epoch, pre-, post-, dev and local versions.
+    ///
+    /// This is the comparison for interpreter versions or `python_version` markers, where
+    /// `3.12.0rc1` is already `3.12`. It is not a total order on versions: Versions that compare
+    /// as equal here may differ.
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let version = |version| Version::from_str(version).unwrap();
+    /// assert_eq!(version("3.12.0rc1").cmp_release(&version("3.12")), Ordering::Equal);
+    /// assert_eq!(version("3.9").cmp_release(&version("3.12.post1")), Ordering::Less);
+    /// assert_eq!(version("1!3.12").cmp_release(&version("3.12+local")), Ordering::Equal);
+    /// ```
+    pub fn cmp_release(&self, other: &Self) -> Ordering {
+        self.release().cmp_padded(&other.release())
+    }
+
+    /// Compare two versions like [`Ord::cmp`], and also return which component decided the
+    /// ordering.
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use std::str::FromStr;
+    /// use pep440_rs::{ComparisonReason, Version};
+    ///
+    /// let rc = Version::from_str("1.0rc1").unwrap();
+    /// let final_release = Version::from_str("1.0").unwrap();
+    /// assert_eq!(
+    ///     rc.explain_cmp(&final_release),
+    ///     (Ordering::Less, ComparisonReason::Stage)
+    /// );
+    /// assert_eq!(
+    ///     ComparisonReason::Stage.to_string(),
+    ///     "a dev-release sorts before pre-releases, which sort before the final release, \
+    ///      which sorts before post-releases"
+    /// );
+    /// ```
+    pub fn explain_cmp(&self, other: &Self) -> (Ordering, ComparisonReason) {
+        let ordering = self.epoch().cmp(&other.epoch());
+        if ordering != Ordering::Equal {
+            return (ordering, ComparisonReason::Epoch);
+        }
+
+        // Shorter releases are padded with zeros
+        let len = self.release().len().max(other.release().len());
+        for index in 0..len {
+            let this = self.release().get(index).copied().unwrap_or_default();
+            let other = other.release().get(index).copied().unwrap_or_default();
+            if this != other {
+                return (this.cmp(&other), ComparisonReason::Release { index });
+            }
+        }
+
+        let (this_stage, this_pre, this_post, this_dev, this_local) = sortable_tuple(self);
+        let (other_stage, other_pre, other_post, other_dev, other_local) = sortable_tuple(other);
+        // Stages 2 to 4 are alpha, beta and rc
+        let both_pre = (2..=4).contains(&this_stage) && (2..=4).contains(&other_stage);
+        let reason = if this_stage != other_stage && !both_pre {
+            ComparisonReason::Stage
+        } else if this_stage != other_stage || this_pre != other_pre {
+            ComparisonReason::Prerelease
+        } else if this_post != other_post {
+            ComparisonReason::Post
+        } else if this_dev != other_dev {
+            ComparisonReason::Dev
+        } else if this_local != other_local {
+            let index = this_local
+                .iter()
+                .zip(other_local)
+                .position(|(this, other)| this != other)
+                .unwrap_or(this_local.len().min(other_local.len()));
+            ComparisonReason::Local { index }
+        } else {
+            ComparisonReason::Equal
+        };
+        (self.cmp(other), reason)
+    }
+
     /// Convert this version to a "full" representation in-place and return a
     /// mutable borrow to the full type.
     fn make_full(&mut self) -> &mut VersionFull {
@@ -627,6 +1129,127 @@ impl Version {
         }
     }
 
+    /// A string whose byte-wise order is the order of the versions, for sorting versions in
+    /// databases and object stores that can only compare strings.
+    ///
+    /// Equal versions, such as `1.0` and `1.0.0`, have the same key. The format is stable:
+    ///
+    /// * Each number is written as a letter for its number of digits (`a` for 1 through `t` for
+    ///   20) followed by its digits, e.g. `b12` for `12`.
+    /// * The epoch, then the release without trailing zeros with each number prefixed by `.`,
+    ///   terminated by `-`.
+    /// * The stage: `0` for the internal minimum version, `1` for dev releases, `2`, `3` and `4`
+    ///   for alpha, beta and release candidates, `5` for final and `6` for post-releases.
+    /// * The pre-release number (`a0` if there is none), the post-release number prefixed by `s`
+    ///   (or `n` if there is none) and the dev release number, which is the largest number for
+    ///   pre- and post-releases without a dev release and `a0` for final releases.
+    /// * The local version segments, each prefixed by `.`, strings as `a<string>-` and numbers
+    ///   as `n<number>`, terminated by `-`.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::Version;
+    ///
+    /// let versions = ["1.0a1", "1.0", "1.0.post1", "1.10", "1.9"];
+    /// let mut keys: Vec<String> = versions
+    ///     .iter()
+    ///     .map(|version| Version::from_str(version).unwrap().sort_key_string())
+    ///     .collect();
+    /// keys.sort();
+    /// assert_eq!(keys[0], "a0.a1-2a1nt18446744073709551615-");
+    /// assert_eq!(keys[4], "a0.a1.b10-5a0na0-");
+    /// ```
+    pub fn sort_key_string(&self) -> String {
+        fn push_number(key: &mut String, number: u64) {
+            let digits = number.to_string();
+            key.push(char::from(b'a' + (digits.len() - 1) as u8));
+            key.push_str(&digits);
+        }
+
+        let mut key = String::new();
+        push_number(&mut key, self.epoch());
+        let release = self.release();
+        let len = release
+            .iter()
+            .rposition(|number| *number != 0)
+            .map_or(0, |idx| idx + 1);
+        for number in &release[..len] {
+            key.push('.');
+            push_number(&mut key, *number);
+        }
+        key.push('-');
+
+        let (stage, pre, post, dev, local) = sortable_tuple(self);
+        key.push(char::from(b'0' + stage as u8));
+        push_number(&mut key, pre);
+        if let Some(post) = post {
+            key.push('s');
+            push_number(&mut key, post);
+        } else {
+            key.push('n');
+        }
+        push_number(&mut key, dev);
+        for segment in local {
+            key.push('.');
+            match segment {
+                LocalSegment::String(string) => {
+                    key.push('a');
+                    key.push_str(string);
+                    key.push('-');
+                }
+                LocalSegment::Number(number) => {
+                    key.push('n');
+                    push_number(&mut key, *number);
+                }
+            }
+        }
+        key.push('-');
+        key
+    }
+
+    /// Compare the public versions, ignoring the local version segments.
+    ///
+    /// This is the same as comparing the versions after [`Version::without_local`], but without
+    /// cloning them.
+    #[inline]
+    pub(crate) fn cmp_public(&self, other: &Self) -> Ordering {
+        match (&*self.inner, &*other.inner) {
+            // Small versions never have a local version
+            (VersionInner::Small { small: small1 }, VersionInner::Small { small: small2 }) => {
+                small1.repr.cmp(&small2.repr)
+            }
+            _ => self.cmp_epoch_and_release(other).then_with(|| {
+                let (stage, pre, post, dev, _) = sortable_tuple(self);
+                let (other_stage, other_pre, other_post, other_dev, _) = sortable_tuple(other);
+                (stage, pre, post, dev).cmp(&(other_stage, other_pre, other_post, other_dev))
+            }),
+        }
+    }
+
+    /// Compare the epochs and, if they are equal, the releases.
+    #[inline]
+    fn cmp_epoch_and_release(&self, other: &Self) -> Ordering {
+        self.epoch()
+            .cmp(&other.epoch())
+            .then_with(|| self.release().cmp_padded(&other.release()))
+    }
+
+    /// Whether the version has no pre-, post-, dev, local, min or max component.
+    #[inline]
+    fn is_plain_release(&self) -> bool {
+        match *self.inner {
+            VersionInner::Small { ref small } => small.suffix_kind() == VersionSmall::SUFFIX_NONE,
+            VersionInner::Full { ref full } => {
+                full.pre.is_none()
+                    && full.post.is_none()
+                    && full.dev.is_none()
+                    && full.local.is_empty()
+                    && full.min.is_none()
+                    && full.max.is_none()
+            }
+        }
+    }
+
     /// Performs a "slow" but complete comparison between two versions.
     ///
     /// This comparison is done using only the public API of a `Version`, and
@@ -636,6 +1259,11 @@ impl Version {
     #[cold]
     #[inline(never)]
     fn cmp_slow(&self, other: &Self) -> Ordering {
+        // Most versions are final releases, which the epoch and the release order completely
+        if self.is_plain_release() && other.is_plain_release() {
+            return self.cmp_epoch_and_release(other);
+        }
+
         match self.epoch().cmp(&other.epoch()) {
             Ordering::Less => {
                 return Ordering::Less;
@@ -646,7 +1274,7 @@ impl Version {
             }
         }
 
-        match compare_release(self.release(), other.release()) {
+        match self.release().cmp_padded(&other.release()) {
             Ordering::Less => {
                 return Ordering::Less;
             }
@@ -685,49 +1313,62 @@ impl Serialize for Version {
 /// Shows normalized version
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let epoch = if self.epoch() == 0 {
-            String::new()
-        } else {
-            format!("{}!", self.epoch())
-        };
-        let release = self
-            .release()
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<String>>()
-            .join(".");
-        let pre = self
-            .pre()
-            .as_ref()
-            .map(|Prerelease { kind, number }| format!("{kind}{number}"))
-            .unwrap_or_default();
-        let post = self
-            .post()
-            .map(|post| format!(".post{post}"))
-            .unwrap_or_default();
-        let dev = self
-            .dev()
-            .map(|dev| format!(".dev{dev}"))
-            .unwrap_or_default();
-        let local = if self.local().is_empty() {
-            String::new()
-        } else {
-            format!(
-                "+{}",
-                self.local()
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<String>>()
-                    .join(".")
-            )
-        };
-        write!(f, "{epoch}{release}{pre}{post}{dev}{local}")
+        // Honor width, fill and alignment, e.g. `{:>12}`, for aligned output
+        if f.width().is_some() || f.precision().is_some() {
+            let mut normalized = String::new();
+            self.write_normalized(&mut normalized)?;
+            return f.pad(&normalized);
+        }
+        self.write_normalized(f)
+    }
+}
+
+impl Version {
+    /// Write the normalized version without padding.
+    fn write_normalized(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        if self.epoch() != 0 {
+            write!(f, "{}!", self.epoch())?;
+        }
+        for (idx, number) in self.release().iter().enumerate() {
+            if idx > 0 {
+                f.write_char('.')?;
+            }
+            write!(f, "{number}")?;
+        }
+        if let Some(pre) = self.pre() {
+            write!(f, "{pre}")?;
+        }
+        if let Some(post) = self.post() {
+            write!(f, ".post{post}")?;
+        }
+        if let Some(dev) = self.dev() {
+            write!(f, ".dev{dev}")?;
+        }
+        for (idx, segment) in self.local().iter().enumerate() {
+            f.write_char(if idx == 0 { '+' } else { '.' })?;
+            write!(f, "{segment}")?;
+        }
+        Ok(())
     }
 }
 
+/// Shows the normalized version, e.g. `Version("1.0a1")`, or all components with `{:#?}`.
 impl std::fmt::Debug for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "\"{self}\"")
+        if f.alternate() {
+            return f
+                .debug_struct("Version")
+                .field("epoch", &self.epoch())
+                .field("release", &self.release())
+                .field("pre", &self.pre())
+                .field("post", &self.post())
+                .field("dev", &self.dev())
+                .field("local", &self.local())
+                .field("min", &self.min())
+                .field("max", &self.max())
+                .finish();
+        }
+        f.debug_tuple("Version").field(&self.to_string()).finish()
     }
 }
 
@@ -778,6 +1419,80 @@ impl Ord for Version {
     }
 }
 
+/// A final release with the given release numbers, e.g. `Version::from([3, 12])` is `3.12`.
+///
+/// # Panics
+///
+/// When the array is empty.
+impl<const N: usize> From<[u64; N]> for Version {
+    fn from(release: [u64; N]) -> Self {
+        Self::new(release)
+    }
+}
+
+/// A final release `major.minor`, e.g. `Version::from((3, 12))` is `3.12`.
+impl From<(u64, u64)> for Version {
+    fn from((major, minor): (u64, u64)) -> Self {
+        Self::new([major, minor])
+    }
+}
+
+/// A final release `major.minor.patch`, e.g. `Version::from((3, 12, 1))` is `3.12.1`.
+impl From<(u64, u64, u64)> for Version {
+    fn from((major, minor, patch): (u64, u64, u64)) -> Self {
+        Self::new([major, minor, patch])
+    }
+}
+
+/// A final release with the given release numbers, failing if there are none.
+impl TryFrom<&[u64]> for Version {
+    type Error = VersionPartsError;
+
+    fn try_from(release: &[u64]) -> Result<Self, Self::Error> {
+        if release.is_empty() {
+            return Err(PartsErrorKind::EmptyRelease.into());
+        }
+        Ok(Self::new(release))
+    }
+}
+
+/// A final release with the given release numbers, failing if there are none.
+impl TryFrom<Vec<u64>> for Version {
+    type Error = VersionPartsError;
+
+    fn try_from(release: Vec<u64>) -> Result<Self, Self::Error> {
+        Self::try_from(release.as_slice())
+    }
+}
+
+impl Version {
+    /// Parse a version like [`Version::from_str`], but also return the non-fatal issues with how
+    /// it is written, such as a `v` prefix or leading zeros.
+    ///
+    /// The warnings are empty if and only if the input is already normalized.
+    ///
+    /// ```rust
+    /// use pep440_rs::{ParseWarning, Version};
+    ///
+    /// let (version, warnings) = Version::from_str_with_warnings("v1.01-2").unwrap();
+    /// assert_eq!(version.to_string(), "1.1.post2");
+    /// assert_eq!(
+    ///     warnings,
+    ///     [
+    ///         ParseWarning::VPrefix,
+    ///         ParseWarning::LeadingZeros { number: "01".to_string() },
+    ///         ParseWarning::ImplicitPostRelease,
+    ///         ParseWarning::NonCanonical { normalized: "1.1.post2".to_string() },
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_str_with_warnings(
+        version: &str,
+    ) -> Result<(Self, Vec<ParseWarning>), VersionParseError> {
+        Parser::with_warnings(version.as_bytes()).parse_with_warnings()
+    }
+}
+
 impl FromStr for Version {
     type Err = VersionParseError;
 
@@ -789,6 +1504,24 @@ impl FromStr for Version {
     }
 }
 
+/// Parse with [`Version::from_str`].
+impl TryFrom<&str> for Version {
+    type Error = VersionParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+/// Parse with [`Version::from_str`].
+impl TryFrom<String> for Version {
+    type Error = VersionParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
 /// A "small" representation of a version.
 ///
 /// This representation is used for a (very common) subset of versions: the
@@ -1263,6 +1996,64 @@ struct VersionFull {
     max: Option<u64>,
 }
 
+/// The component that decided how two versions compare, see [`Version::explain_cmp`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ComparisonReason {
+    /// The versions are equal.
+    Equal,
+    /// The epochs differ, e.g. `1!1.0 > 2.0`.
+    Epoch,
+    /// The release numbers at the (zero-based) index differ, e.g. index 1 in `1.10 > 1.9`.
+    ///
+    /// Shorter releases are padded with zeros, so `1.0 == 1.0.0`.
+    Release {
+        /// The index of the first differing release number.
+        index: usize,
+    },
+    /// One version is a dev-release, pre-release, final release or post-release and the other one
+    /// is a different kind, e.g. `1.0rc1 < 1.0`.
+    Stage,
+    /// Both versions are pre-releases, with a different kind or number, e.g. `1.0a2 < 1.0b1`.
+    Prerelease,
+    /// The post-releases differ, e.g. `1.0.post1 < 1.0.post2`.
+    Post,
+    /// The dev-releases differ, e.g. `1.0a1.dev1 < 1.0a1`.
+    Dev,
+    /// The local versions differ at the (zero-based) segment index, e.g. index 1 in
+    /// `1.0+ubuntu.1 < 1.0+ubuntu.2`.
+    ///
+    /// If one local version is a prefix of the other, this is the length of the shorter one.
+    Local {
+        /// The index of the first differing local segment.
+        index: usize,
+    },
+}
+
+impl std::fmt::Display for ComparisonReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Equal => write!(f, "the versions are equal"),
+            Self::Epoch => write!(f, "the epochs differ"),
+            Self::Release { index } => write!(f, "the release numbers at index {index} differ"),
+            Self::Stage => write!(
+                f,
+                "a dev-release sorts before pre-releases, which sort before the final release, \
+                 which sorts before post-releases"
+            ),
+            Self::Prerelease => write!(
+                f,
+                "the pre-releases differ, with alpha before beta before release candidate"
+            ),
+            Self::Post => write!(f, "the post-releases differ"),
+            Self::Dev => write!(
+                f,
+                "the dev-releases differ, with dev-releases before the release they belong to"
+            ),
+            Self::Local { index } => write!(f, "the local versions differ at segment {index}"),
+        }
+    }
+}
+
 /// A version number pattern.
 ///
 /// A version pattern appears in a
@@ -1327,6 +2118,15 @@ impl VersionPattern {
     }
 }
 
+impl VersionPattern {
+    /// Parse a version pattern, also returning the non-fatal issues with how it is written.
+    pub(crate) fn from_str_with_warnings(
+        pattern: &str,
+    ) -> Result<(Self, Vec<ParseWarning>), VersionPatternParseError> {
+        Parser::with_warnings(pattern.as_bytes()).parse_pattern_with_warnings()
+    }
+}
+
 impl FromStr for VersionPattern {
     type Err = VersionPatternParseError;
 
@@ -1335,19 +2135,228 @@ impl FromStr for VersionPattern {
     }
 }
 
-/// An optional pre-release modifier and number applied to a version.
-#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Ord, PartialOrd)]
-#[cfg_attr(
-    feature = "rkyv",
-    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize,)
-)]
-#[cfg_attr(feature = "rkyv", rkyv(derive(Debug, Eq, PartialEq, PartialOrd, Ord)))]
-pub struct Prerelease {
-    /// The kind of pre-release.
-    pub kind: PrereleaseKind,
-    /// The number associated with the pre-release.
-    pub number: u64,
-}
+/// Shows the normalized version, followed by `.*` for a wildcard pattern.
+impl std::fmt::Display for VersionPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.wildcard {
+            write!(f, "{}.*", self.version)
+        } else {
+            write!(f, "{}", self.version)
+        }
+    }
+}
+
+/// The release numbers of a [`Version`], e.g. `1.2.3` in `1!1.2.3rc1`, see [`Version::release`].
+///
+/// PEP 440 compares releases of different lengths as if the shorter one was padded with zeros, so
+/// `1.2` and `1.2.0` are the same release. [`Release::cmp_padded`] and [`Release::starts_with`]
+/// implement this padding, while `==` on a `Release` and the slice it dereferences to compare the
+/// numbers as written.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::Version;
+///
+/// let version = Version::from_str("1.2rc1").unwrap();
+/// assert_eq!(version.release(), [1, 2]);
+/// assert_eq!(version.release().to_string(), "1.2");
+/// assert!(version.release().starts_with(&[1, 2, 0]));
+/// assert!(!version.release().starts_with(&[1, 2, 1]));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Release<'a> {
+    numbers: &'a [u64],
+}
+
+impl<'a> Release<'a> {
+    /// The release numbers as written, e.g. `[1, 2]` for `1.2`.
+    pub fn as_slice(&self) -> &'a [u64] {
+        self.numbers
+    }
+
+    /// Compare to other release numbers, padding the shorter one with zeros, e.g. `1.1.0` ==
+    /// `1.1` and `1.16` < `1.19`.
+    pub fn cmp_padded(&self, other: &[u64]) -> Ordering {
+        compare_release(self.numbers, other)
+    }
+
+    /// Whether the release starts with the prefix, padding the release with zeros if it is
+    /// shorter than the prefix.
+    ///
+    /// This is the prefix match of `==1.2.*`: Both `1.2.3` and `1.2` start with `1.2`, and `1`
+    /// starts with `1.0` but not with `1.2`.
+    pub fn starts_with(&self, prefix: &[u64]) -> bool {
+        prefix
+            .iter()
+            .enumerate()
+            .all(|(idx, number)| self.numbers.get(idx).copied().unwrap_or(0) == *number)
+    }
+
+    /// The first `len` release numbers, or all of them if there are fewer.
+    pub fn truncate(&self, len: usize) -> Release<'a> {
+        Release {
+            numbers: &self.numbers[..len.min(self.numbers.len())],
+        }
+    }
+
+    /// The release with the last number incremented, e.g. `1.3` for `1.2`, which is the
+    /// exclusive upper bound of `==1.2.*`.
+    ///
+    /// Returns `None` for an empty release or if the last number would overflow, in which case
+    /// there is no upper bound.
+    pub(crate) fn increment_last(&self) -> Option<Vec<u64>> {
+        let (last, prefix) = self.numbers.split_last()?;
+        let last = last.checked_add(1)?;
+        Some(prefix.iter().copied().chain([last]).collect())
+    }
+}
+
+impl std::ops::Deref for Release<'_> {
+    type Target = [u64];
+
+    fn deref(&self) -> &[u64] {
+        self.numbers
+    }
+}
+
+impl<'a> From<&'a [u64]> for Release<'a> {
+    fn from(numbers: &'a [u64]) -> Self {
+        Self { numbers }
+    }
+}
+
+impl<'a> IntoIterator for Release<'a> {
+    type Item = &'a u64;
+    type IntoIter = std::slice::Iter<'a, u64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.numbers.iter()
+    }
+}
+
+impl PartialEq<[u64]> for Release<'_> {
+    fn eq(&self, other: &[u64]) -> bool {
+        self.numbers == other
+    }
+}
+
+impl<const N: usize> PartialEq<[u64; N]> for Release<'_> {
+    fn eq(&self, other: &[u64; N]) -> bool {
+        self.numbers == other
+    }
+}
+
+impl std::fmt::Debug for Release<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.numbers, f)
+    }
+}
+
+/// Shows the release numbers separated by dots, e.g. `1.2.3`.
+impl std::fmt::Display for Release<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut numbers = self.numbers.iter();
+        if let Some(first) = numbers.next() {
+            write!(f, "{first}")?;
+        }
+        for number in numbers {
+            write!(f, ".{number}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The components of a [`Version`] as plain data, see [`Version::into_parts`] and
+/// [`Version::from_parts`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct VersionParts {
+    /// The epoch, e.g. `1` in `1!2.0`, usually `0`.
+    pub epoch: u64,
+    /// The release numbers, e.g. `[1, 2, 3]` in `1.2.3`. Must not be empty.
+    pub release: Vec<u64>,
+    /// The pre-release, e.g. `rc1` in `1.0rc1`.
+    pub pre: Option<Prerelease>,
+    /// The post-release number, e.g. `1` in `1.0.post1`.
+    pub post: Option<u64>,
+    /// The dev-release number, e.g. `1` in `1.0.dev1`.
+    pub dev: Option<u64>,
+    /// The local version segments, e.g. `ubuntu` and `1` in `1.0+ubuntu.1`.
+    pub local: Vec<LocalSegment>,
+}
+
+/// An optional pre-release modifier and number applied to a version.
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize,)
+)]
+#[cfg_attr(feature = "rkyv", rkyv(derive(Debug, Eq, PartialEq, PartialOrd, Ord)))]
+pub struct Prerelease {
+    /// The kind of pre-release.
+    pub kind: PrereleaseKind,
+    /// The number associated with the pre-release.
+    pub number: u64,
+}
+
+/// The release channel of a version, see [`Version::channel`].
+///
+/// Channels are ordered from the least to the most stable, so `channel >= Channel::Stable` selects
+/// final releases and their post-releases.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Channel {
+    /// A dev release, including dev releases of pre- and post-releases, e.g. `1.0.dev1` or
+    /// `1.0rc1.dev1`.
+    Dev,
+    /// An alpha release, e.g. `1.0a1` or `1.0a1.post1`.
+    Alpha,
+    /// A beta release, e.g. `1.0b1`.
+    Beta,
+    /// A release candidate, e.g. `1.0rc1`.
+    Rc,
+    /// A final release, e.g. `1.0` or `1.0+local`.
+    Stable,
+    /// A post-release of a final release, e.g. `1.0.post1`.
+    Post,
+}
+
+/// A kind of release that can follow a version, see [`Version::bump`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Bump {
+    /// Increment the first release number, e.g. `1.2.3` to `2.0.0`.
+    Major,
+    /// Increment the second release number, e.g. `1.2.3` to `1.3.0`.
+    Minor,
+    /// Increment the third release number, e.g. `1.2.3` to `1.2.4`.
+    Patch,
+    /// The next alpha of the same release, e.g. `1.0a1` to `1.0a2`.
+    Alpha,
+    /// The next beta of the same release, e.g. `1.0a2` to `1.0b1` or `1.0b1` to `1.0b2`.
+    Beta,
+    /// The next release candidate of the same release, e.g. `1.0b2` to `1.0rc1`.
+    Rc,
+    /// The release that a pre-release or dev release leads up to, e.g. `1.0rc1` to `1.0` or
+    /// `1.0.post1.dev1` to `1.0.post1`.
+    Final,
+    /// The next post-release, e.g. `1.0` to `1.0.post1` or `1.0.post1` to `1.0.post2`.
+    Post,
+    /// The next dev release, e.g. `1.0.dev1` to `1.0.dev2`.
+    Dev,
+}
+
+impl Bump {
+    /// All kinds of bumps.
+    pub const ALL: [Bump; 9] = [
+        Bump::Major,
+        Bump::Minor,
+        Bump::Patch,
+        Bump::Alpha,
+        Bump::Beta,
+        Bump::Rc,
+        Bump::Final,
+        Bump::Post,
+        Bump::Dev,
+    ];
+}
 
 /// Optional pre-release modifier (alpha, beta or release candidate) appended to version
 ///
@@ -1367,6 +2376,33 @@ pub enum PrereleaseKind {
     Rc,
 }
 
+impl PrereleaseKind {
+    /// All kinds of pre-releases, in ascending order.
+    pub const ALL: [PrereleaseKind; 3] = [
+        PrereleaseKind::Alpha,
+        PrereleaseKind::Beta,
+        PrereleaseKind::Rc,
+    ];
+
+    /// The spellings that normalize to this kind, starting with the normalized one.
+    ///
+    /// The parser accepts them in any case, e.g. `1.0Alpha1` is `1.0a1`.
+    ///
+    /// ```rust
+    /// use pep440_rs::PrereleaseKind;
+    ///
+    /// assert_eq!(PrereleaseKind::Beta.aliases(), ["b", "beta"]);
+    /// assert_eq!(PrereleaseKind::Rc.aliases(), ["rc", "c", "pre", "preview"]);
+    /// ```
+    pub fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::Alpha => &["a", "alpha"],
+            Self::Beta => &["b", "beta"],
+            Self::Rc => &["rc", "c", "pre", "preview"],
+        }
+    }
+}
+
 impl std::fmt::Display for PrereleaseKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1383,6 +2419,33 @@ impl std::fmt::Display for Prerelease {
     }
 }
 
+/// Parses a pre-release with the same spellings that are accepted in a version, e.g. `rc1`,
+/// `-preview.2` or `alpha`, which are `rc1`, `rc2` and `a0`.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{Prerelease, PrereleaseKind};
+///
+/// let prerelease = Prerelease::from_str("beta2").unwrap();
+/// assert_eq!(prerelease, Prerelease { kind: PrereleaseKind::Beta, number: 2 });
+/// assert_eq!(prerelease.to_string(), "b2");
+/// ```
+impl FromStr for Prerelease {
+    type Err = VersionParseError;
+
+    fn from_str(prerelease: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(prerelease.as_bytes());
+        let invalid = || ErrorKind::InvalidPrerelease {
+            prerelease: prerelease.to_string(),
+        };
+        parser.parse_pre().map_err(|_| invalid())?;
+        match parser.pre {
+            Some(pre) if parser.is_done() => Ok(pre),
+            _ => Err(invalid().into()),
+        }
+    }
+}
+
 /// A part of the [local version identifier](<https://peps.python.org/pep-0440/#local-version-identifiers>)
 ///
 /// Local versions are a mess:
@@ -1469,6 +2532,11 @@ struct Parser<'a> {
     ///
     /// This is only valid when a version pattern is being parsed.
     wildcard: bool,
+    /// The non-fatal issues found so far, if they are collected at all.
+    warnings: Option<Vec<ParseWarning>>,
+    /// Whether to only check the syntax, without collecting more than four release numbers or
+    /// the local segments, so that checking a valid version doesn't allocate.
+    validate_only: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -1477,6 +2545,10 @@ impl<'a> Parser<'a> {
     #[allow(clippy::byte_char_slices)]
     const SEPARATOR: ByteSet = ByteSet::new(&[b'.', b'_', b'-']);
 
+    /// The spellings of pre-releases, see [`PrereleaseKind::aliases`].
+    const PRERELEASE_SPELLINGS: &'static [&'static str] =
+        &["alpha", "beta", "preview", "pre", "rc", "a", "b", "c"];
+
     /// Create a new `Parser` for parsing the version in the given byte string.
     fn new(version: &'a [u8]) -> Parser<'a> {
         Parser {
@@ -1489,6 +2561,34 @@ impl<'a> Parser<'a> {
             dev: None,
             local: vec![],
             wildcard: false,
+            warnings: None,
+            validate_only: false,
+        }
+    }
+
+    /// Create a new `Parser` that also collects [`ParseWarning`]s.
+    fn with_warnings(version: &'a [u8]) -> Parser<'a> {
+        Parser {
+            warnings: Some(Vec::new()),
+            ..Parser::new(version)
+        }
+    }
+
+    /// Create a new `Parser` that only checks the syntax, see [`Parser::check_pattern`].
+    fn validating(version: &'a [u8]) -> Parser<'a> {
+        Parser {
+            validate_only: true,
+            ..Parser::new(version)
+        }
+    }
+
+    /// Record a warning if warnings are collected.
+    fn warn(&mut self, warning: ParseWarning) {
+        if let Some(warnings) = &mut self.warnings {
+            // Report each problem once, e.g. for multiple non-normalized separators
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
         }
     }
 
@@ -1496,12 +2596,17 @@ impl<'a> Parser<'a> {
     ///
     /// If a version pattern is found, then an error is returned.
     fn parse(self) -> Result<Version, VersionParseError> {
-        match self.parse_pattern() {
-            Ok(vpat) => {
+        self.parse_with_warnings().map(|(version, _)| version)
+    }
+
+    /// Parse a verbatim version, also returning the collected warnings.
+    fn parse_with_warnings(self) -> Result<(Version, Vec<ParseWarning>), VersionParseError> {
+        match self.parse_pattern_with_warnings() {
+            Ok((vpat, warnings)) => {
                 if vpat.is_wildcard() {
                     Err(ErrorKind::Wildcard.into())
                 } else {
-                    Ok(vpat.into_version())
+                    Ok((vpat.into_version(), warnings))
                 }
             }
             // If we get an error when parsing a version pattern, then
@@ -1518,28 +2623,78 @@ impl<'a> Parser<'a> {
     }
 
     /// Parse a version pattern, which may be a verbatim version.
-    fn parse_pattern(mut self) -> Result<VersionPattern, VersionPatternParseError> {
-        if let Some(vpat) = self.parse_fast() {
-            return Ok(vpat);
+    fn parse_pattern(self) -> Result<VersionPattern, VersionPatternParseError> {
+        self.parse_pattern_with_warnings().map(|(vpat, _)| vpat)
+    }
+
+    /// Parse a version pattern, also returning the collected warnings.
+    fn parse_pattern_with_warnings(
+        mut self,
+    ) -> Result<(VersionPattern, Vec<ParseWarning>), VersionPatternParseError> {
+        // The fast path only accepts normalized versions, but doesn't check for leading zeros
+        if self.warnings.is_none() {
+            if let Some(vpat) = self.parse_fast() {
+                return Ok((vpat, Vec::new()));
+            }
+        }
+        if !self
+            .bump_while(|byte| byte.is_ascii_whitespace())
+            .is_empty()
+        {
+            self.warn(ParseWarning::Whitespace);
+        }
+        if self.warnings.is_some() && self.v.iter().any(u8::is_ascii_uppercase) {
+            self.warn(ParseWarning::Uppercase);
+        }
+        if self.bump_if("v") {
+            self.warn(ParseWarning::VPrefix);
         }
-        self.bump_while(|byte| byte.is_ascii_whitespace());
-        self.bump_if("v");
         self.parse_epoch_and_initial_release()?;
         self.parse_rest_of_release()?;
         if self.parse_wildcard()? {
-            return Ok(self.into_pattern());
+            return Ok(self.into_pattern_with_warnings());
         }
         self.parse_pre()?;
         self.parse_post()?;
         self.parse_dev()?;
         self.parse_local()?;
-        self.bump_while(|byte| byte.is_ascii_whitespace());
+        if !self
+            .bump_while(|byte| byte.is_ascii_whitespace())
+            .is_empty()
+        {
+            self.warn(ParseWarning::Whitespace);
+        }
         if !self.is_done() {
             let version = String::from_utf8_lossy(&self.v[..self.i]).into_owned();
             let remaining = String::from_utf8_lossy(&self.v[self.i..]).into_owned();
             return Err(ErrorKind::UnexpectedEnd { version, remaining }.into());
         }
-        Ok(self.into_pattern())
+        Ok(self.into_pattern_with_warnings())
+    }
+
+    /// Check whether the input is a valid version pattern without building it, returning what
+    /// decides whether it can be used with an operator.
+    fn check_pattern(mut self) -> Option<PatternShape> {
+        self.bump_while(|byte| byte.is_ascii_whitespace());
+        self.bump_if("v");
+        self.parse_epoch_and_initial_release().ok()?;
+        self.parse_rest_of_release().ok()?;
+        if !self.parse_wildcard().ok()? {
+            self.parse_pre().ok()?;
+            self.parse_post().ok()?;
+            self.parse_dev().ok()?;
+            self.parse_local().ok()?;
+            self.bump_while(|byte| byte.is_ascii_whitespace());
+            if !self.is_done() {
+                return None;
+            }
+        }
+        Some(PatternShape {
+            wildcard: self.wildcard,
+            release_len: self.release.len(),
+            // Only the local version may contain a `+`
+            local: self.v.contains(&b'+'),
+        })
     }
 
     /// Attempts to do a "fast parse" of a version.
@@ -1617,6 +2772,9 @@ impl<'a> Parser<'a> {
     fn parse_epoch_and_initial_release(&mut self) -> Result<(), VersionPatternParseError> {
         let first_number = self.parse_number()?.ok_or(ErrorKind::NoLeadingNumber)?;
         let first_release_number = if self.bump_if("!") {
+            if first_number == 0 {
+                self.warn(ParseWarning::ZeroEpoch);
+            }
             self.epoch = first_number;
             self.parse_number()?
                 .ok_or(ErrorKind::NoLeadingReleaseNumber)?
@@ -1643,7 +2801,10 @@ impl<'a> Parser<'a> {
                 self.unbump();
                 break;
             };
-            self.release.push(n);
+            // When validating, the length only matters for `~=`, which needs at least two
+            if !self.validate_only || self.release.len() < 4 {
+                self.release.push(n);
+            }
         }
         Ok(())
     }
@@ -1681,8 +2842,7 @@ impl<'a> Parser<'a> {
         // Note also that the order of the strings themselves matters. If 'pre'
         // were before 'preview' for example, then 'preview' would never match
         // since the strings are matched in order.
-        const SPELLINGS: StringSet =
-            StringSet::new(&["alpha", "beta", "preview", "pre", "rc", "a", "b", "c"]);
+        const SPELLINGS: StringSet = StringSet::new(Parser::PRERELEASE_SPELLINGS);
         const MAP: &[PrereleaseKind] = &[
             PrereleaseKind::Alpha,
             PrereleaseKind::Beta,
@@ -1695,7 +2855,7 @@ impl<'a> Parser<'a> {
         ];
 
         let oldpos = self.i;
-        self.bump_if_byte_set(&Parser::SEPARATOR);
+        let before = self.bump_if_byte_set(&Parser::SEPARATOR);
         let Some(spelling) = self.bump_if_string_set(&SPELLINGS) else {
             // We might see a separator (or not) and then something
             // that isn't a pre-release. At this stage, we can't tell
@@ -1705,10 +2865,14 @@ impl<'a> Parser<'a> {
             return Ok(());
         };
         let kind = MAP[spelling];
-        self.bump_if_byte_set(&Parser::SEPARATOR);
+        let after = self.bump_if_byte_set(&Parser::SEPARATOR);
+        if before.is_some() || after.is_some() {
+            self.warn(ParseWarning::Separator);
+        }
+        self.warn_spelling(SPELLINGS.strings[spelling], kind.aliases()[0]);
         // Under the normalization rules, a pre-release without an
         // explicit number defaults to `0`.
-        let number = self.parse_number()?.unwrap_or(0);
+        let number = self.parse_number_or_implicit_zero()?;
         self.pre = Some(Prerelease { kind, number });
         Ok(())
     }
@@ -1725,22 +2889,27 @@ impl<'a> Parser<'a> {
         if self.bump_if("-") {
             if let Some(n) = self.parse_number()? {
                 self.post = Some(n);
+                self.warn(ParseWarning::ImplicitPostRelease);
                 return Ok(());
             }
             self.reset(oldpos);
         }
-        self.bump_if_byte_set(&Parser::SEPARATOR);
-        if self.bump_if_string_set(&SPELLINGS).is_none() {
+        let before = self.bump_if_byte_set(&Parser::SEPARATOR);
+        let Some(spelling) = self.bump_if_string_set(&SPELLINGS) else {
             // As with pre-releases, if we don't see post|rev|r here, we can't
             // yet determine whether the version as a whole is invalid since
             // post-releases are optional.
             self.reset(oldpos);
             return Ok(());
+        };
+        let after = self.bump_if_byte_set(&Parser::SEPARATOR);
+        if before != Some(b'.') || after.is_some() {
+            self.warn(ParseWarning::Separator);
         }
-        self.bump_if_byte_set(&Parser::SEPARATOR);
+        self.warn_spelling(SPELLINGS.strings[spelling], "post");
         // Under the normalization rules, a post-release without an
         // explicit number defaults to `0`.
-        self.post = Some(self.parse_number()?.unwrap_or(0));
+        self.post = Some(self.parse_number_or_implicit_zero()?);
         Ok(())
     }
 
@@ -1751,7 +2920,7 @@ impl<'a> Parser<'a> {
     /// byte immediately following the post-release.
     fn parse_dev(&mut self) -> Result<(), VersionPatternParseError> {
         let oldpos = self.i;
-        self.bump_if_byte_set(&Parser::SEPARATOR);
+        let before = self.bump_if_byte_set(&Parser::SEPARATOR);
         if !self.bump_if("dev") {
             // As with pre-releases, if we don't see dev here, we can't
             // yet determine whether the version as a whole is invalid
@@ -1759,10 +2928,13 @@ impl<'a> Parser<'a> {
             self.reset(oldpos);
             return Ok(());
         }
-        self.bump_if_byte_set(&Parser::SEPARATOR);
+        let after = self.bump_if_byte_set(&Parser::SEPARATOR);
+        if before != Some(b'.') || after.is_some() {
+            self.warn(ParseWarning::Separator);
+        }
         // Under the normalization rules, a post-release without an
         // explicit number defaults to `0`.
-        self.dev = Some(self.parse_number()?.unwrap_or(0));
+        self.dev = Some(self.parse_number_or_implicit_zero()?);
         Ok(())
     }
 
@@ -1783,16 +2955,21 @@ impl<'a> Parser<'a> {
             if first.is_empty() {
                 return Err(ErrorKind::LocalEmpty { precursor }.into());
             }
-            self.local.push(if let Ok(number) = parse_u64(first) {
-                LocalSegment::Number(number)
-            } else {
-                let string = String::from_utf8(first.to_ascii_lowercase())
-                    .expect("ASCII alphanumerics are always valid UTF-8");
-                LocalSegment::String(string)
-            });
+            if !self.validate_only {
+                self.local.push(if let Ok(number) = parse_u64(first) {
+                    LocalSegment::Number(number)
+                } else {
+                    let string = String::from_utf8(first.to_ascii_lowercase())
+                        .expect("ASCII alphanumerics are always valid UTF-8");
+                    LocalSegment::String(string)
+                });
+            }
             let Some(byte) = self.bump_if_byte_set(&Parser::SEPARATOR) else {
                 break;
             };
+            if byte != b'.' {
+                self.warn(ParseWarning::Separator);
+            }
             precursor = char::from(byte);
         }
         Ok(())
@@ -1810,9 +2987,52 @@ impl<'a> Parser<'a> {
         if digits.is_empty() {
             return Ok(None);
         }
+        if self.warnings.is_some() && digits.len() > 1 && digits[0] == b'0' {
+            self.warn(ParseWarning::LeadingZeros {
+                number: String::from_utf8_lossy(digits).into_owned(),
+            });
+        }
         Ok(Some(parse_u64(digits)?))
     }
 
+    /// Like [`Parser::parse_number`], but defaults to `0` for a missing number, as for the
+    /// pre-release, post-release and dev release.
+    fn parse_number_or_implicit_zero(&mut self) -> Result<u64, VersionPatternParseError> {
+        let number = self.parse_number()?;
+        if number.is_none() {
+            self.warn(ParseWarning::ImplicitNumber);
+        }
+        Ok(number.unwrap_or(0))
+    }
+
+    /// Record a warning if a pre-release or post-release isn't spelled in the normalized form.
+    fn warn_spelling(&mut self, spelling: &str, normalized: &str) {
+        if self.warnings.is_some() && spelling != normalized {
+            self.warn(ParseWarning::Spelling {
+                spelling: spelling.to_string(),
+                normalized: normalized.to_string(),
+            });
+        }
+    }
+
+    /// Like [`Parser::into_pattern`], but also returns the collected warnings,
+    /// adding [`ParseWarning::NonCanonical`] if the input isn't normalized.
+    fn into_pattern_with_warnings(mut self) -> (VersionPattern, Vec<ParseWarning>) {
+        let input = self.v;
+        let Some(mut warnings) = self.warnings.take() else {
+            return (self.into_pattern(), Vec::new());
+        };
+        let vpat = self.into_pattern();
+        let mut normalized = vpat.version.to_string();
+        if vpat.wildcard {
+            normalized.push_str(".*");
+        }
+        if normalized.as_bytes() != input {
+            warnings.push(ParseWarning::NonCanonical { normalized });
+        }
+        (vpat, warnings)
+    }
+
     /// Turns whatever state has been gathered into a `VersionPattern`.
     ///
     /// # Panics
@@ -1825,12 +3045,18 @@ impl<'a> Parser<'a> {
             self.release.len() > 0,
             "version with no release numbers is invalid"
         );
-        let version = Version::new(self.release.as_slice())
-            .with_epoch(self.epoch)
-            .with_pre(self.pre)
-            .with_post(self.post)
-            .with_dev(self.dev)
-            .with_local(self.local);
+        let version = match self.release {
+            ReleaseNumbers::Inline { numbers, len } => Version::new(&numbers[..len])
+                .with_epoch(self.epoch)
+                .with_pre(self.pre)
+                .with_post(self.post)
+                .with_dev(self.dev)
+                .with_local(self.local),
+            // Move the long release into the version instead of copying it number by number
+            ReleaseNumbers::Vec(release) => Version::new_unchecked(
+                self.epoch, release, self.pre, self.post, self.dev, self.local,
+            ),
+        };
         VersionPattern {
             version,
             wildcard: self.wildcard,
@@ -1960,9 +3186,10 @@ impl ReleaseNumbers {
             } => {
                 assert!(*len <= 4);
                 if *len == 4 {
-                    let mut numbers = numbers.to_vec();
-                    numbers.push(n);
-                    *self = Self::Vec(numbers.clone());
+                    let mut vec = Vec::with_capacity(8);
+                    vec.extend_from_slice(numbers);
+                    vec.push(n);
+                    *self = Self::Vec(vec);
                 } else {
                     numbers[*len] = n;
                     *len += 1;
@@ -2093,6 +3320,94 @@ impl std::fmt::Debug for ByteSet {
     }
 }
 
+/// A non-fatal issue with how a version or a specifier is written, see
+/// [`Version::from_str_with_warnings`].
+///
+/// Except for [`ParseWarning::ArbitraryEquality`], these are all spellings that the normalization
+/// rules accept, but that differ from the normalized form.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParseWarning {
+    /// The version differs from its normalized form, e.g. `1.0-alpha1` instead of `1.0a1`.
+    ///
+    /// This is reported in addition to the more specific warnings.
+    NonCanonical {
+        /// The normalized version.
+        normalized: String,
+    },
+    /// The version starts with `v`, e.g. `v1.0`.
+    VPrefix,
+    /// A number has leading zeros, e.g. `01` in `1.01`.
+    LeadingZeros {
+        /// The number as written.
+        number: String,
+    },
+    /// The post-release uses the implicit `-N` form, e.g. `1.0-1` instead of `1.0.post1`.
+    ImplicitPostRelease,
+    /// The version has leading or trailing whitespace, e.g. ` 1.0`.
+    Whitespace,
+    /// The version contains uppercase letters, e.g. `1.0RC1` or `1.0+Ubuntu`.
+    Uppercase,
+    /// The version has an explicit zero epoch, e.g. `0!1.0`.
+    ZeroEpoch,
+    /// A pre-release, post-release, dev release or local version uses a separator that isn't
+    /// normalized, e.g. `1.0-a1`, `1.0_post1`, `1.0dev1` or `1.0+ubuntu-1`.
+    Separator,
+    /// A pre-release or post-release uses an alternative spelling, e.g. `alpha` instead of `a`.
+    Spelling {
+        /// The spelling as written, in lowercase.
+        spelling: String,
+        /// The normalized spelling.
+        normalized: String,
+    },
+    /// A pre-release, post-release or dev release doesn't have a number, e.g. `1.0a` instead of
+    /// `1.0a0`.
+    ImplicitNumber,
+    /// The specifier uses arbitrary equality (`===`), which is discouraged.
+    ArbitraryEquality,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::NonCanonical { normalized } => {
+                write!(
+                    f,
+                    "Version is not normalized, the normalized form is `{normalized}`"
+                )
+            }
+            ParseWarning::VPrefix => write!(f, "Version starts with a `v`"),
+            ParseWarning::LeadingZeros { number } => {
+                write!(f, "Number `{number}` has leading zeros")
+            }
+            ParseWarning::ImplicitPostRelease => {
+                write!(
+                    f,
+                    "Post-release uses the implicit `-N` form instead of `.postN`"
+                )
+            }
+            ParseWarning::Whitespace => write!(f, "Version has leading or trailing whitespace"),
+            ParseWarning::Uppercase => write!(f, "Version contains uppercase letters"),
+            ParseWarning::ZeroEpoch => write!(f, "Version has an explicit zero epoch (`0!`)"),
+            ParseWarning::Separator => write!(f, "Version uses non-normalized separators"),
+            ParseWarning::Spelling {
+                spelling,
+                normalized,
+            } => {
+                write!(f, "`{spelling}` is spelled `{normalized}` when normalized")
+            }
+            ParseWarning::ImplicitNumber => {
+                write!(
+                    f,
+                    "Pre-release, post-release or dev release without a number, which is `0`"
+                )
+            }
+            ParseWarning::ArbitraryEquality => {
+                write!(f, "Using arbitrary equality (`===`) is discouraged")
+            }
+        }
+    }
+}
+
 /// An error that occurs when parsing a [`Version`] string fails.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VersionParseError {
@@ -2160,6 +3475,19 @@ impl std::fmt::Display for VersionParseError {
                      which is not part of a valid version",
                 )
             }
+            ErrorKind::NotReleaseOnly { ref version } => {
+                write!(
+                    f,
+                    "expected a version with only an epoch and up to three release numbers, \
+                     but found `{version}`",
+                )
+            }
+            ErrorKind::InvalidPrerelease { ref prerelease } => {
+                write!(
+                    f,
+                    "expected a pre-release such as `a1`, `b2` or `rc3`, but found `{prerelease}`",
+                )
+            }
         }
     }
 }
@@ -2201,6 +3529,68 @@ pub(crate) enum ErrorKind {
         /// The bytes that were remaining and not parsed.
         remaining: String,
     },
+    /// Occurs when parsing a standalone [`Prerelease`] that isn't one.
+    InvalidPrerelease {
+        /// The string that was parsed.
+        prerelease: String,
+    },
+    /// Occurs when a [`crate::ReleaseOnlyVersion`] is expected, but the version has more
+    /// components.
+    NotReleaseOnly {
+        /// The normalized version.
+        version: String,
+    },
+}
+
+impl VersionParseError {
+    /// What went wrong, for handling the error programmatically.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionErrorKind};
+    ///
+    /// let err = Version::from_str("1.0+").unwrap_err();
+    /// assert_eq!(err.kind(), VersionErrorKind::InvalidLocal);
+    /// ```
+    pub fn kind(&self) -> VersionErrorKind {
+        match *self.kind {
+            ErrorKind::Wildcard => VersionErrorKind::Wildcard,
+            ErrorKind::InvalidDigit { .. } => VersionErrorKind::InvalidDigit,
+            ErrorKind::NumberTooBig { .. } => VersionErrorKind::NumberTooBig,
+            ErrorKind::NoLeadingNumber => VersionErrorKind::NoLeadingNumber,
+            ErrorKind::NoLeadingReleaseNumber => VersionErrorKind::NoLeadingReleaseNumber,
+            ErrorKind::LocalEmpty { .. } => VersionErrorKind::InvalidLocal,
+            ErrorKind::UnexpectedEnd { .. } => VersionErrorKind::UnexpectedEnd,
+            ErrorKind::InvalidPrerelease { .. } => VersionErrorKind::InvalidPrerelease,
+            ErrorKind::NotReleaseOnly { .. } => VersionErrorKind::NotReleaseOnly,
+        }
+    }
+}
+
+/// What went wrong when parsing a version or version pattern, see [`VersionParseError::kind`]
+/// and [`VersionPatternParseError::kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VersionErrorKind {
+    /// A wildcard (`.*`) in a version that isn't a pattern, e.g. `1.0.*`.
+    Wildcard,
+    /// A wildcard that isn't at the end of the pattern, e.g. `1.*.0`.
+    WildcardNotTrailing,
+    /// A digit was expected, e.g. after the `!` of an epoch.
+    InvalidDigit,
+    /// A number doesn't fit into a `u64`.
+    NumberTooBig,
+    /// The version doesn't start with a number, e.g. `a1`.
+    NoLeadingNumber,
+    /// There is no release number after the epoch, e.g. `1!`.
+    NoLeadingReleaseNumber,
+    /// A local version segment is empty, e.g. `1.0+` or `1.0+abc.`.
+    InvalidLocal,
+    /// There is input after the version, e.g. `1.0-foo`.
+    UnexpectedEnd,
+    /// A standalone pre-release is invalid, e.g. `dev1`.
+    InvalidPrerelease,
+    /// A release-only version has other components, e.g. `1.0rc1`.
+    NotReleaseOnly,
 }
 
 impl From<ErrorKind> for VersionParseError {
@@ -2219,6 +3609,16 @@ pub struct VersionPatternParseError {
 
 impl std::error::Error for VersionPatternParseError {}
 
+impl VersionPatternParseError {
+    /// What went wrong, for handling the error programmatically.
+    pub fn kind(&self) -> VersionErrorKind {
+        match *self.kind {
+            PatternErrorKind::Version(ref err) => err.kind(),
+            PatternErrorKind::WildcardNotTrailing => VersionErrorKind::WildcardNotTrailing,
+        }
+    }
+}
+
 impl std::fmt::Display for VersionPatternParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self.kind {
@@ -2259,6 +3659,255 @@ impl From<VersionParseError> for VersionPatternParseError {
     }
 }
 
+/// An error that occurs when building a [`Version`] with [`Version::from_parts`] or from a list of
+/// release numbers fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionPartsError {
+    kind: Box<PartsErrorKind>,
+}
+
+impl std::error::Error for VersionPartsError {}
+
+impl std::fmt::Display for VersionPartsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self.kind {
+            PartsErrorKind::EmptyRelease => {
+                write!(f, "a version must have at least one release number")
+            }
+            PartsErrorKind::InvalidLocalSegment { ref segment } => write!(
+                f,
+                "local version segment {segment:?} must be lowercase ASCII alphanumerics \
+                 and must not be all digits"
+            ),
+        }
+    }
+}
+
+/// The kind of error that occurs when building a `Version` from its parts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum PartsErrorKind {
+    EmptyRelease,
+    InvalidLocalSegment { segment: String },
+}
+
+impl From<PartsErrorKind> for VersionPartsError {
+    fn from(kind: PartsErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+/// The normalized form of a version as computed by
+/// [`packaging.utils.canonicalize_version`](https://packaging.pypa.io/en/stable/utils.html#packaging.utils.canonicalize_version),
+/// which index and filename tooling uses to compare versions as strings.
+///
+/// Without `strip_trailing_zero`, this is the same as [`Version`]'s `Display`. With
+/// `strip_trailing_zero`, which is the default in packaging, trailing zeros are removed from the
+/// release, keeping at least one number, e.g. `1.0.0rc1` becomes `1rc1` and `0.0` becomes `0`.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{canonicalize_version, Version};
+///
+/// let version = Version::from_str("1.10.0-RC.1+Ubuntu").unwrap();
+/// assert_eq!(canonicalize_version(&version, true), "1.10rc1+ubuntu");
+/// assert_eq!(canonicalize_version(&version, false), "1.10.0rc1+ubuntu");
+/// ```
+pub fn canonicalize_version(version: &Version, strip_trailing_zero: bool) -> String {
+    if !strip_trailing_zero {
+        return version.to_string();
+    }
+    let release = version.release();
+    let len = release
+        .iter()
+        .rposition(|number| *number != 0)
+        .map_or(1, |idx| idx + 1);
+    if len == release.len() {
+        return version.to_string();
+    }
+    version
+        .clone()
+        .with_release(release.truncate(len))
+        .to_string()
+}
+
+/// Normalize many version strings, reporting for each whether and why it changed.
+///
+/// This is meant for jobs that normalize large numbers of records, such as the versions of a
+/// package index: Versions that are already normalized are returned borrowed, and the common
+/// case of a plain release such as `1.2.3` skips the parser.
+///
+/// ```rust
+/// use pep440_rs::{canonicalize_versions, ParseWarning};
+///
+/// let results: Vec<_> = canonicalize_versions(["1.2.3", "1.0-ALPHA"])
+///     .map(Result::unwrap)
+///     .collect();
+/// assert!(!results[0].is_changed());
+/// assert_eq!(results[1].canonical(), "1.0a0");
+/// assert_eq!(
+///     results[1].changes(),
+///     [
+///         ParseWarning::Uppercase,
+///         ParseWarning::Separator,
+///         ParseWarning::Spelling { spelling: "alpha".to_string(), normalized: "a".to_string() },
+///         ParseWarning::ImplicitNumber,
+///     ]
+/// );
+/// ```
+pub fn canonicalize_versions<'a>(
+    versions: impl IntoIterator<Item = &'a str>,
+) -> impl Iterator<Item = Result<CanonicalVersion<'a>, VersionParseError>> {
+    versions.into_iter().map(CanonicalVersion::new)
+}
+
+/// A normalized version string from [`canonicalize_versions`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CanonicalVersion<'a> {
+    canonical: Cow<'a, str>,
+    changes: Vec<ParseWarning>,
+}
+
+impl<'a> CanonicalVersion<'a> {
+    fn new(version: &'a str) -> Result<Self, VersionParseError> {
+        if is_normalized_release(version.as_bytes()) {
+            return Ok(Self {
+                canonical: Cow::Borrowed(version),
+                changes: Vec::new(),
+            });
+        }
+        let (_, mut changes) = Version::from_str_with_warnings(version)?;
+        // `NonCanonical` comes last, after the more specific warnings
+        let canonical = match changes.last_mut() {
+            Some(ParseWarning::NonCanonical { normalized }) => {
+                let normalized = std::mem::take(normalized);
+                changes.pop();
+                Cow::Owned(normalized)
+            }
+            _ => Cow::Borrowed(version),
+        };
+        Ok(Self { canonical, changes })
+    }
+
+    /// The normalized version.
+    pub fn canonical(&self) -> &str {
+        &self.canonical
+    }
+
+    /// The normalized version, borrowed from the input if it was already normalized.
+    pub fn into_canonical(self) -> Cow<'a, str> {
+        self.canonical
+    }
+
+    /// Whether the input differs from the normalized version.
+    pub fn is_changed(&self) -> bool {
+        matches!(self.canonical, Cow::Owned(_))
+    }
+
+    /// Why the input differs from the normalized version, empty if it doesn't.
+    pub fn changes(&self) -> &[ParseWarning] {
+        &self.changes
+    }
+}
+
+/// Whether the version is a normalized release without other components, e.g. `1.2.3`, but not
+/// `1.02` or `1.2a1`.
+fn is_normalized_release(version: &[u8]) -> bool {
+    !version.is_empty()
+        && version.split(|byte| *byte == b'.').all(|number| {
+            !number.is_empty()
+                // Longer numbers may not fit into a `u64`
+                && number.len() < 20
+                && number.iter().all(u8::is_ascii_digit)
+                && (number[0] != b'0' || number.len() == 1)
+        })
+}
+
+/// Whether the string is a valid version, like [`Version::from_str`] succeeding, but without
+/// building the version.
+///
+/// Checking a valid version doesn't allocate, so this is cheap enough for validating large
+/// numbers of versions, e.g. on upload to an index.
+///
+/// ```rust
+/// use pep440_rs::is_valid_version;
+///
+/// assert!(is_valid_version("1.0.post1"));
+/// assert!(is_valid_version("v1.0-Alpha1+Ubuntu.1"));
+/// assert!(!is_valid_version("1.0.*"));
+/// assert!(!is_valid_version("1.0-foo"));
+/// ```
+pub fn is_valid_version(version: &str) -> bool {
+    check_version_pattern(version).is_some_and(|shape| !shape.wildcard)
+}
+
+/// What decides whether a valid version pattern can be used with an operator, see
+/// [`check_version_pattern`].
+pub(crate) struct PatternShape {
+    /// Whether the pattern ends with `.*`.
+    pub(crate) wildcard: bool,
+    /// The number of release numbers, but at most four.
+    pub(crate) release_len: usize,
+    /// Whether there is a local version.
+    pub(crate) local: bool,
+}
+
+/// Check that the string is a valid version pattern without building it.
+pub(crate) fn check_version_pattern(version: &str) -> Option<PatternShape> {
+    Parser::validating(version.as_bytes()).check_pattern()
+}
+
+/// The candidate closest to the target version, e.g. for a "no version matches `2.3.1`, did you
+/// mean `2.3.2`?" message.
+///
+/// Candidates are ranked by their distance to the target: First by the difference in epoch, then
+/// by the first release segment that differs, where a difference in an earlier segment is farther
+/// away, and by how much that segment differs, and finally by the difference in release stage
+/// (dev, pre, final or post). Between equally close candidates, the higher version is chosen.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{suggest_nearest, Version};
+///
+/// let candidates: Vec<Version> = ["1.0", "2.3.0", "2.3.2", "2.4.0", "3.0"]
+///     .into_iter()
+///     .map(|version| Version::from_str(version).unwrap())
+///     .collect();
+/// let target = Version::from_str("2.3.1").unwrap();
+/// assert_eq!(
+///     suggest_nearest(&target, &candidates),
+///     Some(&Version::from_str("2.3.2").unwrap())
+/// );
+/// ```
+pub fn suggest_nearest<'a>(target: &Version, candidates: &'a [Version]) -> Option<&'a Version> {
+    candidates.iter().min_by(|a, b| {
+        nearest_distance(target, a)
+            .cmp(&nearest_distance(target, b))
+            .then_with(|| b.cmp(a))
+    })
+}
+
+/// The distance of a candidate for [`suggest_nearest`], smaller is closer.
+fn nearest_distance(target: &Version, candidate: &Version) -> (u64, Reverse<usize>, u64, u64) {
+    let epoch = target.epoch().abs_diff(candidate.epoch());
+    let len = target.release().len().max(candidate.release().len());
+    let padded = |release: &[u64], idx: usize| release.get(idx).copied().unwrap_or(0);
+    let (first_difference, release) = (0..len)
+        .map(|idx| {
+            (
+                idx,
+                padded(&target.release(), idx).abs_diff(padded(&candidate.release(), idx)),
+            )
+        })
+        .find(|(_, difference)| *difference != 0)
+        .unwrap_or((usize::MAX, 0));
+    let stage = sortable_tuple(target)
+        .0
+        .abs_diff(sortable_tuple(candidate).0);
+    (epoch, Reverse(first_difference), release, stage)
+}
+
 /// Compare the release parts of two versions, e.g. `4.3.1` > `4.2`, `1.1.0` ==
 /// `1.1` and `1.16` < `1.19`
 pub(crate) fn compare_release(this: &[u64], other: &[u64]) -> Ordering {
@@ -2267,23 +3916,15 @@ pub(crate) fn compare_release(this: &[u64], other: &[u64]) -> Ordering {
     }
     // "When comparing release segments with different numbers of components, the shorter segment
     // is padded out with additional zeros as necessary"
-    for (this, other) in this.iter().chain(std::iter::repeat(&0)).zip(
-        other
+    fn padded(release: &[u64], len: usize) -> impl Iterator<Item = u64> + '_ {
+        release
             .iter()
-            .chain(std::iter::repeat(&0))
-            .take(this.len().max(other.len())),
-    ) {
-        match this.cmp(other) {
-            Ordering::Less => {
-                return Ordering::Less;
-            }
-            Ordering::Equal => {}
-            Ordering::Greater => {
-                return Ordering::Greater;
-            }
-        }
+            .copied()
+            .chain(std::iter::repeat(0))
+            .take(len)
     }
-    Ordering::Equal
+    let len = this.len().max(other.len());
+    padded(this, len).cmp(padded(other, len))
 }
 
 /// Compare the parts attached after the release, given equal release
@@ -2301,7 +3942,7 @@ pub(crate) fn compare_release(this: &[u64], other: &[u64]) -> Ordering {
 /// implementation
 ///
 /// [pep440-suffix-ordering]: https://peps.python.org/pep-0440/#summary-of-permitted-suffixes-and-relative-ordering
-fn sortable_tuple(version: &Version) -> (u64, u64, Option<u64>, u64, &[LocalSegment]) {
+pub(crate) fn sortable_tuple(version: &Version) -> (u64, u64, Option<u64>, u64, &[LocalSegment]) {
     // If the version is a "max" version, use a post version larger than any possible post version.
     let post = if version.max().is_some() {
         Some(u64::MAX)
@@ -2313,36 +3954,21 @@ fn sortable_tuple(version: &Version) -> (u64, u64, Option<u64>, u64, &[LocalSegm
         (_pre, post, _dev, Some(n)) => (0, 0, post, n, version.local()),
         // dev release
         (None, None, Some(n), None) => (1, 0, None, n, version.local()),
-        // alpha release
-        (
-            Some(Prerelease {
-                kind: PrereleaseKind::Alpha,
-                number: n,
-            }),
-            post,
-            dev,
-            None,
-        ) => (2, n, post, dev.unwrap_or(u64::MAX), version.local()),
-        // beta release
-        (
-            Some(Prerelease {
-                kind: PrereleaseKind::Beta,
-                number: n,
-            }),
-            post,
-            dev,
-            None,
-        ) => (3, n, post, dev.unwrap_or(u64::MAX), version.local()),
-        // alpha release
-        (
-            Some(Prerelease {
-                kind: PrereleaseKind::Rc,
-                number: n,
-            }),
-            post,
-            dev,
-            None,
-        ) => (4, n, post, dev.unwrap_or(u64::MAX), version.local()),
+        // alpha, beta or release candidate
+        (Some(Prerelease { kind, number }), post, dev, None) => {
+            let stage = match kind {
+                PrereleaseKind::Alpha => 2,
+                PrereleaseKind::Beta => 3,
+                PrereleaseKind::Rc => 4,
+            };
+            (
+                stage,
+                number,
+                post,
+                dev.unwrap_or(u64::MAX),
+                version.local(),
+            )
+        }
         // final release
         (None, None, None, None) => (5, 0, None, 0, version.local()),
         // post release
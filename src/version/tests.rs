@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
 use crate::VersionSpecifier;
@@ -1309,6 +1310,333 @@ fn parse_number_u64() {
     );
 }
 
+#[test]
+fn parts_round_trip() {
+    for version in ["1.0", "1!2.3.4rc5.post6.dev7+ubuntu.8", "1.2.3.4.5.6+a1"] {
+        let version = Version::from_str(version).unwrap();
+        let parts = version.clone().into_parts();
+        assert_eq!(
+            Version::from_parts(parts).unwrap().to_string(),
+            version.to_string()
+        );
+    }
+
+    let parts = Version::from_str("1.0+ubuntu").unwrap().into_parts();
+    assert_eq!(
+        parts,
+        VersionParts {
+            epoch: 0,
+            release: vec![1, 0],
+            pre: None,
+            post: None,
+            dev: None,
+            local: vec![LocalSegment::String("ubuntu".to_string())],
+        }
+    );
+}
+
+#[test]
+fn parts_invalid() {
+    let parts = VersionParts {
+        epoch: 0,
+        release: vec![],
+        pre: None,
+        post: None,
+        dev: None,
+        local: vec![],
+    };
+    assert_eq!(
+        Version::from_parts(parts.clone()).unwrap_err().to_string(),
+        "a version must have at least one release number"
+    );
+    for segment in ["", "Ubuntu", "12", "a-b"] {
+        let parts = VersionParts {
+            release: vec![1],
+            local: vec![LocalSegment::String(segment.to_string())],
+            ..parts.clone()
+        };
+        assert!(Version::from_parts(parts).is_err(), "{segment}");
+    }
+}
+
+#[test]
+fn from_release_numbers() {
+    assert_eq!(Version::from([3, 12]).to_string(), "3.12");
+    assert_eq!(Version::from([1]), Version::new([1]));
+    assert_eq!(Version::from((3, 12)), Version::from_str("3.12").unwrap());
+    assert_eq!(
+        Version::from((3, 12, 1)),
+        Version::from_str("3.12.1").unwrap()
+    );
+    assert_eq!(
+        Version::try_from([1, 2, 3].as_slice()).unwrap(),
+        Version::from((1, 2, 3))
+    );
+    assert_eq!(
+        Version::try_from(vec![2024, 1]).unwrap().to_string(),
+        "2024.1"
+    );
+    assert!(Version::try_from(Vec::new()).is_err());
+    assert!(Version::try_from([].as_slice()).is_err());
+}
+
+#[test]
+fn explain_cmp() {
+    let cases = [
+        ("1.0", "1.0.0", Ordering::Equal, ComparisonReason::Equal),
+        ("1!1.0", "2.0", Ordering::Greater, ComparisonReason::Epoch),
+        (
+            "1.9",
+            "1.10",
+            Ordering::Less,
+            ComparisonReason::Release { index: 1 },
+        ),
+        (
+            "1.0",
+            "1.0.0.1",
+            Ordering::Less,
+            ComparisonReason::Release { index: 3 },
+        ),
+        ("1.0rc1", "1.0", Ordering::Less, ComparisonReason::Stage),
+        ("1.0.dev1", "1.0a1", Ordering::Less, ComparisonReason::Stage),
+        (
+            "1.0.post1",
+            "1.0",
+            Ordering::Greater,
+            ComparisonReason::Stage,
+        ),
+        (
+            "1.0a2",
+            "1.0b1",
+            Ordering::Less,
+            ComparisonReason::Prerelease,
+        ),
+        (
+            "1.0rc2",
+            "1.0rc10",
+            Ordering::Less,
+            ComparisonReason::Prerelease,
+        ),
+        (
+            "1.0a1.post1",
+            "1.0a1",
+            Ordering::Greater,
+            ComparisonReason::Post,
+        ),
+        ("1.0a1.dev1", "1.0a1", Ordering::Less, ComparisonReason::Dev),
+        (
+            "1.0+ubuntu.1",
+            "1.0+ubuntu.2",
+            Ordering::Less,
+            ComparisonReason::Local { index: 1 },
+        ),
+        (
+            "1.0+ubuntu",
+            "1.0+ubuntu.1",
+            Ordering::Less,
+            ComparisonReason::Local { index: 1 },
+        ),
+        (
+            "1.0",
+            "1.0+local",
+            Ordering::Less,
+            ComparisonReason::Local { index: 0 },
+        ),
+    ];
+    for (a, b, ordering, reason) in cases {
+        let a = Version::from_str(a).unwrap();
+        let b = Version::from_str(b).unwrap();
+        assert_eq!(a.explain_cmp(&b), (ordering, reason), "{a} {b}");
+        assert_eq!(b.explain_cmp(&a), (ordering.reverse(), reason), "{b} {a}");
+    }
+}
+
+#[test]
+fn release() {
+    let version = Version::from_str("1!1.2.0rc1").unwrap();
+    let release = version.release();
+    assert_eq!(release, [1, 2, 0]);
+    assert_eq!(release.to_string(), "1.2.0");
+    assert_eq!(format!("{release:?}"), "[1, 2, 0]");
+    assert_eq!(release.truncate(2), [1, 2]);
+    assert_eq!(release.truncate(5), [1, 2, 0]);
+
+    assert_eq!(release.cmp_padded(&[1, 2]), Ordering::Equal);
+    assert_eq!(release.cmp_padded(&[1, 2, 0, 0, 1]), Ordering::Less);
+    assert_eq!(release.cmp_padded(&[1, 1, 9]), Ordering::Greater);
+
+    assert!(release.starts_with(&[1]));
+    assert!(release.starts_with(&[1, 2, 0, 0]));
+    assert!(!release.starts_with(&[1, 2, 1]));
+    assert!(!release.starts_with(&[2]));
+    let short = Version::from_str("1").unwrap();
+    assert!(short.release().starts_with(&[1, 0]));
+    assert!(!short.release().starts_with(&[1, 2]));
+}
+
+#[test]
+fn prerelease_from_str() {
+    let cases = [
+        ("a1", PrereleaseKind::Alpha, 1),
+        ("alpha", PrereleaseKind::Alpha, 0),
+        ("B2", PrereleaseKind::Beta, 2),
+        ("-preview.3", PrereleaseKind::Rc, 3),
+        ("c4", PrereleaseKind::Rc, 4),
+        ("rc_05", PrereleaseKind::Rc, 5),
+    ];
+    for (input, kind, number) in cases {
+        assert_eq!(
+            Prerelease::from_str(input).unwrap(),
+            Prerelease { kind, number },
+            "{input}"
+        );
+    }
+    for input in ["", "1", "rc1.post1", "dev1", "a1a"] {
+        assert_eq!(
+            Prerelease::from_str(input).unwrap_err(),
+            ErrorKind::InvalidPrerelease {
+                prerelease: input.to_string()
+            }
+            .into(),
+            "{input}"
+        );
+    }
+}
+
+/// The aliases are exactly the spellings the parser accepts.
+#[test]
+fn prerelease_aliases() {
+    let mut aliases = Vec::new();
+    for kind in PrereleaseKind::ALL {
+        assert_eq!(kind.aliases()[0], kind.to_string());
+        for alias in kind.aliases() {
+            let version = Version::from_str(&format!("1.0{}2", alias.to_uppercase())).unwrap();
+            assert_eq!(
+                version.pre(),
+                Some(Prerelease { kind, number: 2 }),
+                "{alias}"
+            );
+            aliases.push(*alias);
+        }
+    }
+    aliases.sort_unstable();
+    let mut spellings = Parser::PRERELEASE_SPELLINGS.to_vec();
+    spellings.sort_unstable();
+    assert_eq!(aliases, spellings);
+}
+
+/// <https://github.com/pypa/packaging/blob/24.1/tests/test_utils.py#L44-L74>
+#[test]
+fn canonicalize() {
+    let cases = [
+        ("1.4.0", "1.4", "1.4.0"),
+        ("1.40.0", "1.40", "1.40.0"),
+        ("1.4.0.0.00.000.0000", "1.4", "1.4.0.0.0.0.0"),
+        ("1.0", "1", "1.0"),
+        ("0.0", "0", "0.0"),
+        ("1.0a0", "1a0", "1.0a0"),
+        ("1.0rc0", "1rc0", "1.0rc0"),
+        ("100.0b0", "100b0", "100.0b0"),
+        ("1.0.dev0", "1.dev0", "1.0.dev0"),
+        ("1.0.post0", "1.post0", "1.0.post0"),
+        ("1.0+abc", "1+abc", "1.0+abc"),
+        ("1!0.0.post0", "1!0.post0", "1!0.0.post0"),
+        ("2.1.0-rc.1", "2.1rc1", "2.1.0rc1"),
+    ];
+    for (input, stripped, unstripped) in cases {
+        let version = Version::from_str(input).unwrap();
+        assert_eq!(canonicalize_version(&version, true), stripped, "{input}");
+        assert_eq!(canonicalize_version(&version, false), unstripped, "{input}");
+    }
+}
+
+#[test]
+fn operator_reflection() {
+    assert!(Operator::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    for operator in Operator::ALL {
+        assert_eq!(operator.as_str(), operator.to_string());
+        let star = if operator.is_star() { "*" } else { "" };
+        assert_eq!(
+            Operator::from_str(&format!("{}{star}", operator.as_str())).unwrap(),
+            operator
+        );
+    }
+    assert!(Operator::from_str("~=*").is_err());
+    assert!(Operator::from_str(">=*").is_err());
+}
+
+#[test]
+fn operator_validation() {
+    for operator in Operator::ALL
+        .into_iter()
+        .filter(|operator| !operator.is_star())
+    {
+        let parses =
+            |version: &str| VersionSpecifier::from_str(&format!("{operator}{version}")).is_ok();
+        assert_eq!(operator.allows_local(), parses("1.0+local"), "{operator}");
+        assert_eq!(operator.allows_star(), parses("1.0.*"), "{operator}");
+        assert_eq!(
+            operator.requires_two_release_segments(),
+            !parses("1"),
+            "{operator}"
+        );
+    }
+    assert!(Operator::EqualStar.allows_star());
+    assert!(!Operator::EqualStar.allows_local());
+}
+
+#[test]
+fn channel() {
+    let cases = [
+        ("1.0.dev1", Channel::Dev),
+        ("1.0a1.dev1", Channel::Dev),
+        ("1.0.post1.dev1", Channel::Dev),
+        ("1.0a1", Channel::Alpha),
+        ("1.0a1.post1", Channel::Alpha),
+        ("1.0b2", Channel::Beta),
+        ("1.0rc1", Channel::Rc),
+        ("1.0", Channel::Stable),
+        ("1.0+local", Channel::Stable),
+        ("1.0.post1", Channel::Post),
+    ];
+    for (version, channel) in cases {
+        assert_eq!(
+            Version::from_str(version).unwrap().channel(),
+            channel,
+            "{version}"
+        );
+    }
+}
+
+#[test]
+fn suggest_nearest_version() {
+    let cases = [
+        (
+            "2.3.1",
+            &["1.0", "2.3.0", "2.3.2", "2.4.0", "3.0"][..],
+            Some("2.3.2"),
+        ),
+        ("2.3.1", &["2.2.9", "2.4", "2.3.5"], Some("2.3.5")),
+        ("2.3", &["2.3.0", "2.3.1"], Some("2.3.0")),
+        ("2.3.1rc1", &["2.3.1a1", "2.3.1", "2.3.2"], Some("2.3.1")),
+        ("2.3.1", &["2.3.1a1", "2.3.1.post1"], Some("2.3.1.post1")),
+        ("1!1.0", &["1.0", "1!2.0"], Some("1!2.0")),
+        ("1.0", &[], None),
+    ];
+    for (target, candidates, expected) in cases {
+        let target = Version::from_str(target).unwrap();
+        let candidates: Vec<Version> = candidates
+            .iter()
+            .map(|candidate| Version::from_str(candidate).unwrap())
+            .collect();
+        assert_eq!(
+            suggest_nearest(&target, &candidates).map(ToString::to_string),
+            expected.map(ToString::to_string),
+            "{target}"
+        );
+    }
+}
+
 /// Wraps a `Version` and provides a more "bloated" debug but standard
 /// representation.
 ///
@@ -1341,3 +1669,296 @@ impl Version {
         VersionBloatedDebug(self)
     }
 }
+
+#[test]
+fn parse_warnings() {
+    let non_canonical = |normalized: &str| ParseWarning::NonCanonical {
+        normalized: normalized.to_string(),
+    };
+    let leading_zeros = |number: &str| ParseWarning::LeadingZeros {
+        number: number.to_string(),
+    };
+    let spelling = |spelling: &str, normalized: &str| ParseWarning::Spelling {
+        spelling: spelling.to_string(),
+        normalized: normalized.to_string(),
+    };
+    let cases = [
+        ("1.0", vec![]),
+        ("1!2.0rc1.post3.dev4+abc.5", vec![]),
+        ("1.0.0.0", vec![]),
+        (
+            "V1.0",
+            vec![
+                ParseWarning::Uppercase,
+                ParseWarning::VPrefix,
+                non_canonical("1.0"),
+            ],
+        ),
+        (
+            "2024.01.05",
+            vec![
+                leading_zeros("01"),
+                leading_zeros("05"),
+                non_canonical("2024.1.5"),
+            ],
+        ),
+        (
+            "1.0-1",
+            vec![
+                ParseWarning::ImplicitPostRelease,
+                non_canonical("1.0.post1"),
+            ],
+        ),
+        (
+            "1.0-post1",
+            vec![ParseWarning::Separator, non_canonical("1.0.post1")],
+        ),
+        (
+            "1.0.alpha1",
+            vec![
+                ParseWarning::Separator,
+                spelling("alpha", "a"),
+                non_canonical("1.0a1"),
+            ],
+        ),
+        (
+            "1.0c1.r",
+            vec![
+                spelling("c", "rc"),
+                spelling("r", "post"),
+                ParseWarning::ImplicitNumber,
+                non_canonical("1.0rc1.post0"),
+            ],
+        ),
+        (
+            "1.0a-dev+ubuntu_1-2",
+            vec![
+                ParseWarning::Separator,
+                ParseWarning::ImplicitNumber,
+                non_canonical("1.0a0.dev0+ubuntu.1.2"),
+            ],
+        ),
+        ("0!1.0", vec![ParseWarning::ZeroEpoch, non_canonical("1.0")]),
+        (
+            " 1.0 ",
+            vec![ParseWarning::Whitespace, non_canonical("1.0")],
+        ),
+        (
+            "1.0+ABC",
+            vec![ParseWarning::Uppercase, non_canonical("1.0+abc")],
+        ),
+    ];
+    for (input, expected) in cases {
+        let (version, warnings) = Version::from_str_with_warnings(input).unwrap();
+        assert_eq!(version, Version::from_str(input).unwrap(), "{input}");
+        assert_eq!(warnings, expected, "{input}");
+    }
+    assert_eq!(
+        Version::from_str_with_warnings("1.0.*").unwrap_err(),
+        Version::from_str("1.0.*").unwrap_err()
+    );
+}
+
+#[test]
+fn is_prerelease_of() {
+    let release = Version::from_str("1!2.0+local").unwrap();
+    for (version, expected) in [
+        ("1!2.0a1", true),
+        ("1!2.0.0b2.dev1", true),
+        ("1!2.0rc1.post1", true),
+        ("1!2.0.dev0+abc", true),
+        ("2.0rc1", false),
+        ("1!2.0", false),
+        ("1!2.0.post1", false),
+        ("1!2.0.post1.dev1", false),
+        ("1!2.0.1rc1", false),
+        ("1!1.9rc1", false),
+    ] {
+        let version = Version::from_str(version).unwrap();
+        assert_eq!(version.is_prerelease_of(&release), expected, "{version}");
+    }
+    let prerelease = Version::from_str("2.0rc2").unwrap();
+    assert!(!Version::from_str("2.0rc1")
+        .unwrap()
+        .is_prerelease_of(&prerelease));
+}
+
+#[test]
+fn bump() {
+    let cases = [
+        ("1", Bump::Minor, Some("1.1")),
+        ("1.2", Bump::Patch, Some("1.2.1")),
+        ("1.2.3.4", Bump::Minor, Some("1.3.0.0")),
+        ("1!1.2+local", Bump::Major, Some("1!2.0")),
+        ("1.0a2", Bump::Alpha, Some("1.0a3")),
+        ("1.0a2", Bump::Rc, Some("1.0rc1")),
+        ("1.0rc1", Bump::Beta, None),
+        ("1.0", Bump::Alpha, None),
+        // The dev releases of a pre-release or final release lead up to it
+        ("1.2b3.dev1", Bump::Beta, Some("1.2b3")),
+        ("1.0a1.dev1", Bump::Alpha, Some("1.0a1")),
+        ("1.0a1.dev1", Bump::Beta, Some("1.0b1")),
+        ("1.2.dev1", Bump::Alpha, Some("1.2a1")),
+        ("1.2.dev1+local", Bump::Rc, Some("1.2rc1")),
+        ("1.2b3.post1.dev1", Bump::Beta, Some("1.2b4")),
+        ("1.2.post1.dev1", Bump::Alpha, None),
+        ("1.0rc1.dev2", Bump::Final, Some("1.0")),
+        ("1.0.post1.dev2+local", Bump::Final, Some("1.0.post1")),
+        ("1.0", Bump::Final, None),
+        ("1.0.post1", Bump::Post, Some("1.0.post2")),
+        ("1.0.dev1", Bump::Post, None),
+        ("1.0.dev1", Bump::Dev, Some("1.0.dev2")),
+        ("1.0", Bump::Dev, None),
+        ("18446744073709551615", Bump::Major, None),
+    ];
+    for (version, bump, expected) in cases {
+        let bumped = Version::from_str(version).unwrap().bump(bump);
+        assert_eq!(
+            bumped.as_ref().map(ToString::to_string).as_deref(),
+            expected,
+            "{version} {bump:?}"
+        );
+    }
+}
+
+#[test]
+fn successors() {
+    let successors: Vec<(Bump, String)> = Version::from_str("2.0b1.dev3")
+        .unwrap()
+        .successors()
+        .into_iter()
+        .map(|(bump, version)| (bump, version.to_string()))
+        .collect();
+    assert_eq!(
+        successors,
+        [
+            (Bump::Dev, "2.0b1.dev4".to_string()),
+            (Bump::Beta, "2.0b1".to_string()),
+            (Bump::Rc, "2.0rc1".to_string()),
+            (Bump::Final, "2.0".to_string()),
+            (Bump::Patch, "2.0.1".to_string()),
+            (Bump::Minor, "2.1".to_string()),
+            (Bump::Major, "3.0".to_string()),
+        ]
+    );
+    let successors: Vec<String> = Version::from_str("1.2.dev1")
+        .unwrap()
+        .successors()
+        .into_iter()
+        .map(|(_, version)| version.to_string())
+        .collect();
+    assert_eq!(
+        successors,
+        ["1.2.dev2", "1.2a1", "1.2b1", "1.2rc1", "1.2", "1.2.1", "1.3", "2.0"]
+    );
+    for (_, successor) in Version::from_str("1.0a1.post2").unwrap().successors() {
+        assert!(successor > Version::from_str("1.0a1.post2").unwrap());
+    }
+}
+
+#[test]
+fn new_unchecked() {
+    for version in [
+        "1.0",
+        "2!1.2.3rc4.post5.dev6",
+        "1.2.3.4.5.6",
+        "1.0+ubuntu.1",
+        "1.2.18446744073709551615",
+    ] {
+        let version = Version::from_str(version).unwrap();
+        let unchecked = Version::new_unchecked(
+            version.epoch(),
+            version.release().to_vec(),
+            version.pre(),
+            version.post(),
+            version.dev(),
+            version.local().to_vec(),
+        );
+        assert_eq!(unchecked, version);
+        assert_eq!(unchecked.to_string(), version.to_string());
+    }
+}
+
+#[test]
+fn local_str() {
+    for (version, local) in [
+        ("1.0", None),
+        ("1.0+cpu", Some("cpu")),
+        ("2.0.0+cu118.post1", Some("cu118.post1")),
+        ("1.0+Ubuntu-1_2", Some("ubuntu.1.2")),
+        ("1.0+007", Some("7")),
+    ] {
+        let version = Version::from_str(version).unwrap();
+        assert_eq!(version.local_str().as_deref(), local, "{version}");
+    }
+}
+
+#[test]
+fn canonicalize_versions() {
+    let inputs = [
+        "1.2.3",
+        "0",
+        "2024.01",
+        "1.0.POST1",
+        "99999999999999999999",
+        "1.0-foo",
+    ];
+    let results: Vec<_> = super::canonicalize_versions(inputs).collect();
+    let unchanged = results[0].as_ref().unwrap();
+    assert!(!unchanged.is_changed());
+    assert!(matches!(
+        unchanged.clone().into_canonical(),
+        Cow::Borrowed("1.2.3")
+    ));
+    assert!(!results[1].as_ref().unwrap().is_changed());
+
+    let leading_zeros = results[2].as_ref().unwrap();
+    assert_eq!(leading_zeros.canonical(), "2024.1");
+    assert_eq!(
+        leading_zeros.changes(),
+        [ParseWarning::LeadingZeros {
+            number: "01".to_string()
+        }]
+    );
+    let uppercase = results[3].as_ref().unwrap();
+    assert_eq!(uppercase.canonical(), "1.0.post1");
+    assert_eq!(uppercase.changes(), [ParseWarning::Uppercase]);
+
+    assert!(results[4].is_err());
+    assert!(results[5].is_err());
+}
+
+#[test]
+fn error_kind() {
+    let kind = |s: &str| Version::from_str(s).unwrap_err().kind();
+    assert_eq!(kind("1.0.*"), VersionErrorKind::Wildcard);
+    assert_eq!(kind("1!"), VersionErrorKind::NoLeadingReleaseNumber);
+    assert_eq!(kind("a1"), VersionErrorKind::NoLeadingNumber);
+    assert_eq!(kind("1.0+"), VersionErrorKind::InvalidLocal);
+    assert_eq!(kind("1.0-foo"), VersionErrorKind::UnexpectedEnd);
+    assert_eq!(
+        kind("1.18446744073709551616"),
+        VersionErrorKind::NumberTooBig
+    );
+
+    let kind = |s: &str| VersionPattern::from_str(s).unwrap_err().kind();
+    assert_eq!(kind("1.*.0"), VersionErrorKind::WildcardNotTrailing);
+    assert_eq!(kind("1.0+"), VersionErrorKind::InvalidLocal);
+}
+
+#[test]
+fn version_pattern_display() {
+    for (input, expected) in [
+        ("1.2.3", "1.2.3"),
+        ("1.2.*", "1.2.*"),
+        ("2!1.0.*", "2!1.0.*"),
+        ("1.0+Local", "1.0+local"),
+    ] {
+        let pattern = VersionPattern::from_str(input).unwrap();
+        assert_eq!(pattern.to_string(), expected);
+        assert_eq!(
+            VersionPattern::from_str(&pattern.to_string()).unwrap(),
+            pattern
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Conversions between [`Version`] and CPython's `sys.version_info`.
+
+use std::str::FromStr;
+
+use crate::{Prerelease, PrereleaseKind, Version};
+
+/// The fields of CPython's [`sys.version_info`](https://docs.python.org/3/library/sys.html#sys.version_info),
+/// e.g. `(3, 13, 0, 'candidate', 2)` for Python 3.13.0rc2.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{PythonVersionInfo, ReleaseLevel, Version};
+///
+/// let info = PythonVersionInfo {
+///     major: 3,
+///     minor: 13,
+///     micro: 0,
+///     releaselevel: ReleaseLevel::Candidate,
+///     serial: 2,
+/// };
+/// assert_eq!(Version::from(info), Version::from_str("3.13.0rc2").unwrap());
+/// assert_eq!(PythonVersionInfo::try_from(&Version::from_str("3.13rc2").unwrap()), Ok(info));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PythonVersionInfo {
+    /// The major version, e.g. `3`.
+    pub major: u64,
+    /// The minor version, e.g. `13`.
+    pub minor: u64,
+    /// The micro version, e.g. `0`.
+    pub micro: u64,
+    /// Whether this is an alpha, beta, release candidate or final release.
+    pub releaselevel: ReleaseLevel,
+    /// The pre-release number, `0` for final releases.
+    pub serial: u64,
+}
+
+/// The `releaselevel` of `sys.version_info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReleaseLevel {
+    /// `alpha`, a PEP 440 `a` pre-release.
+    Alpha,
+    /// `beta`, a PEP 440 `b` pre-release.
+    Beta,
+    /// `candidate`, a PEP 440 `rc` pre-release.
+    Candidate,
+    /// `final`, a release without pre-release.
+    Final,
+}
+
+impl ReleaseLevel {
+    /// The name used by `sys.version_info`, e.g. `candidate`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Alpha => "alpha",
+            Self::Beta => "beta",
+            Self::Candidate => "candidate",
+            Self::Final => "final",
+        }
+    }
+}
+
+impl FromStr for ReleaseLevel {
+    type Err = VersionInfoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alpha" => Ok(Self::Alpha),
+            "beta" => Ok(Self::Beta),
+            "candidate" => Ok(Self::Candidate),
+            "final" => Ok(Self::Final),
+            _ => Err(VersionInfoErrorKind::InvalidReleaseLevel(s.to_string()).into()),
+        }
+    }
+}
+
+impl std::fmt::Display for ReleaseLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Shows the tuple like Python does, e.g. `(3, 13, 0, 'candidate', 2)`.
+impl std::fmt::Display for PythonVersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}, {}, {}, '{}', {})",
+            self.major, self.minor, self.micro, self.releaselevel, self.serial
+        )
+    }
+}
+
+/// The final release or the pre-release `major.minor.micro`. For final releases, the serial is
+/// ignored.
+impl From<PythonVersionInfo> for Version {
+    fn from(info: PythonVersionInfo) -> Self {
+        let kind = match info.releaselevel {
+            ReleaseLevel::Alpha => Some(PrereleaseKind::Alpha),
+            ReleaseLevel::Beta => Some(PrereleaseKind::Beta),
+            ReleaseLevel::Candidate => Some(PrereleaseKind::Rc),
+            ReleaseLevel::Final => None,
+        };
+        Version::new([info.major, info.minor, info.micro]).with_pre(kind.map(|kind| Prerelease {
+            kind,
+            number: info.serial,
+        }))
+    }
+}
+
+/// Fails for versions that `sys.version_info` can't represent: Those with an epoch, more than
+/// three release numbers, or a post, dev or local version.
+impl TryFrom<&Version> for PythonVersionInfo {
+    type Error = VersionInfoError;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        let release = version.release();
+        if version.epoch() != 0
+            || release.len() > 3
+            || version.is_post()
+            || version.is_dev()
+            || version.is_local()
+        {
+            return Err(VersionInfoErrorKind::Unrepresentable(version.clone()).into());
+        }
+        let (releaselevel, serial) = match version.pre() {
+            None => (ReleaseLevel::Final, 0),
+            Some(Prerelease { kind, number }) => {
+                let level = match kind {
+                    PrereleaseKind::Alpha => ReleaseLevel::Alpha,
+                    PrereleaseKind::Beta => ReleaseLevel::Beta,
+                    PrereleaseKind::Rc => ReleaseLevel::Candidate,
+                };
+                (level, number)
+            }
+        };
+        let number = |idx: usize| release.get(idx).copied().unwrap_or(0);
+        Ok(Self {
+            major: number(0),
+            minor: number(1),
+            micro: number(2),
+            releaselevel,
+            serial,
+        })
+    }
+}
+
+impl TryFrom<Version> for PythonVersionInfo {
+    type Error = VersionInfoError;
+
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        Self::try_from(&version)
+    }
+}
+
+/// A version that can't be converted to `sys.version_info`, or an unknown release level.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionInfoError {
+    kind: Box<VersionInfoErrorKind>,
+}
+
+impl std::error::Error for VersionInfoError {}
+
+impl std::fmt::Display for VersionInfoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self.kind {
+            VersionInfoErrorKind::Unrepresentable(ref version) => write!(
+                f,
+                "`{version}` can't be represented as `sys.version_info`, \
+                 which doesn't support epochs, more than three release numbers, \
+                 post, dev or local versions"
+            ),
+            VersionInfoErrorKind::InvalidReleaseLevel(ref level) => write!(
+                f,
+                "expected one of `alpha`, `beta`, `candidate` or `final`, but found `{level}`"
+            ),
+        }
+    }
+}
+
+/// The kind of error that occurs when converting to `sys.version_info`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum VersionInfoErrorKind {
+    Unrepresentable(Version),
+    InvalidReleaseLevel(String),
+}
+
+impl From<VersionInfoErrorKind> for VersionInfoError {
+    fn from(kind: VersionInfoErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
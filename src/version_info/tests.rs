@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use super::*;
+
+#[test]
+fn round_trip() {
+    let cases = [
+        ("3.12.1", (3, 12, 1, ReleaseLevel::Final, 0)),
+        ("3.13.0a1", (3, 13, 0, ReleaseLevel::Alpha, 1)),
+        ("3.13.0b4", (3, 13, 0, ReleaseLevel::Beta, 4)),
+        ("3.13.0rc2", (3, 13, 0, ReleaseLevel::Candidate, 2)),
+    ];
+    for (version, (major, minor, micro, releaselevel, serial)) in cases {
+        let version = Version::from_str(version).unwrap();
+        let info = PythonVersionInfo {
+            major,
+            minor,
+            micro,
+            releaselevel,
+            serial,
+        };
+        assert_eq!(Version::from(info), version);
+        assert_eq!(PythonVersionInfo::try_from(&version).unwrap(), info);
+    }
+}
+
+#[test]
+fn short_release() {
+    let info = PythonVersionInfo::try_from(Version::from_str("3").unwrap()).unwrap();
+    assert_eq!(info.to_string(), "(3, 0, 0, 'final', 0)");
+}
+
+#[test]
+fn unrepresentable() {
+    for version in [
+        "1!3.12",
+        "3.12.1.1",
+        "3.12.post1",
+        "3.13.dev0",
+        "3.12+local",
+    ] {
+        let err = PythonVersionInfo::try_from(Version::from_str(version).unwrap()).unwrap_err();
+        assert_eq!(
+            err,
+            VersionInfoErrorKind::Unrepresentable(Version::from_str(version).unwrap()).into()
+        );
+    }
+}
+
+#[test]
+fn release_level() {
+    for level in [
+        ReleaseLevel::Alpha,
+        ReleaseLevel::Beta,
+        ReleaseLevel::Candidate,
+        ReleaseLevel::Final,
+    ] {
+        assert_eq!(ReleaseLevel::from_str(level.as_str()).unwrap(), level);
+    }
+    assert_eq!(
+        ReleaseLevel::from_str("rc").unwrap_err().to_string(),
+        "expected one of `alpha`, `beta`, `candidate` or `final`, but found `rc`"
+    );
+}
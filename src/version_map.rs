@@ -0,0 +1,133 @@
+//! A sorted map keyed by version that can be queried with specifiers.
+
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+
+use crate::{Version, VersionSpecifiers};
+
+/// A map from versions to values, sorted by version.
+///
+/// Equal versions with different spellings, such as `1.0` and `1.0.0`, are the same key:
+/// Inserting `1.0.0` after `1.0` replaces the value, but keeps the key `1.0`.
+///
+/// [`VersionMap::range`] only visits the entries between the lowest and the highest version
+/// the specifiers allow, so querying a map with many versions for a narrow constraint is cheap.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{Version, VersionMap, VersionSpecifiers};
+///
+/// let mut files = VersionMap::new();
+/// for version in ["1.0", "1.1", "1.2", "2.0"] {
+///     files.insert(Version::from_str(version).unwrap(), format!("foo-{version}.tar.gz"));
+/// }
+/// let specifiers = VersionSpecifiers::from_str(">=1.1, <2, !=1.2").unwrap();
+/// let matching: Vec<&String> = files.range(&specifiers).map(|(_, file)| file).collect();
+/// assert_eq!(matching, ["foo-1.1.tar.gz"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VersionMap<T> {
+    map: BTreeMap<Version, T>,
+}
+
+impl<T> Default for VersionMap<T> {
+    fn default() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> VersionMap<T> {
+    /// An empty map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a value, returning the previous value for an equal version.
+    pub fn insert(&mut self, version: Version, value: T) -> Option<T> {
+        self.map.insert(version, value)
+    }
+
+    /// The value for an equal version.
+    pub fn get(&self, version: &Version) -> Option<&T> {
+        self.map.get(version)
+    }
+
+    /// The key and the value for an equal version.
+    pub fn get_key_value(&self, version: &Version) -> Option<(&Version, &T)> {
+        self.map.get_key_value(version)
+    }
+
+    /// Remove and return the value for an equal version.
+    pub fn remove(&mut self, version: &Version) -> Option<T> {
+        self.map.remove(version)
+    }
+
+    /// The number of versions.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if there are no versions.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The entry with the highest version.
+    pub fn last(&self) -> Option<(&Version, &T)> {
+        self.map.last_key_value()
+    }
+
+    /// All entries in ascending version order.
+    pub fn iter(&self) -> btree_map::Iter<'_, Version, T> {
+        self.map.iter()
+    }
+
+    /// The entries whose version matches the specifiers, in ascending version order.
+    pub fn range<'a>(
+        &'a self,
+        specifiers: &'a VersionSpecifiers,
+    ) -> impl DoubleEndedIterator<Item = (&'a Version, &'a T)> + 'a {
+        specifiers
+            .bounding_range()
+            .into_iter()
+            .flat_map(|bounds| self.map.range(bounds))
+            .filter(|(version, _)| specifiers.contains(version))
+    }
+}
+
+impl<T> FromIterator<(Version, T)> for VersionMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Version, T)>>(iter: I) -> Self {
+        Self {
+            map: BTreeMap::from_iter(iter),
+        }
+    }
+}
+
+impl<T> Extend<(Version, T)> for VersionMap<T> {
+    fn extend<I: IntoIterator<Item = (Version, T)>>(&mut self, iter: I) {
+        self.map.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for VersionMap<T> {
+    type Item = (Version, T);
+    type IntoIter = btree_map::IntoIter<Version, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a VersionMap<T> {
+    type Item = (&'a Version, &'a T);
+    type IntoIter = btree_map::Iter<'a, Version, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests;
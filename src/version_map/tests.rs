@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn map(versions: &[&str]) -> VersionMap<usize> {
+    versions
+        .iter()
+        .enumerate()
+        .map(|(idx, version)| (Version::from_str(version).unwrap(), idx))
+        .collect()
+}
+
+#[test]
+fn equal_spellings() {
+    let mut map = map(&["1.0", "2.0"]);
+    assert_eq!(map.insert(Version::from_str("1.0.0").unwrap(), 5), Some(0));
+    assert_eq!(map.len(), 2);
+    let (key, value) = map.get_key_value(&Version::from_str("1").unwrap()).unwrap();
+    assert_eq!((key.to_string(), *value), ("1.0".to_string(), 5));
+}
+
+#[test]
+fn range_matches_contains() {
+    let versions = [
+        "0.9",
+        "1.0.dev0",
+        "1.0a1",
+        "1.0",
+        "1.0+local",
+        "1.0.post1",
+        "1.1",
+        "1.2.0",
+        "1.2.1",
+        "1.2.1+local",
+        "1.3rc1",
+        "1.3",
+        "2.0",
+        "1!0.1",
+    ];
+    let map = map(&versions);
+    let specifiers = [
+        "",
+        ">=1.0",
+        ">1.0",
+        "<=1.0",
+        "<1.3",
+        "==1.0",
+        "==1.0+local",
+        "===1.0",
+        "==1.2.*",
+        "!=1.2.*",
+        "~=1.2.0",
+        ">=1.0, <2, !=1.1",
+        ">=2, <1",
+        ">1.0, <1.0.post1",
+    ];
+    for specifiers in specifiers {
+        let specifiers = VersionSpecifiers::from_str(specifiers).unwrap();
+        let expected: Vec<String> = map
+            .iter()
+            .filter(|(version, _)| specifiers.contains(version))
+            .map(|(version, _)| version.to_string())
+            .collect();
+        let actual: Vec<String> = map
+            .range(&specifiers)
+            .map(|(version, _)| version.to_string())
+            .collect();
+        assert_eq!(actual, expected, "{specifiers}");
+    }
+}
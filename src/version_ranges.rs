@@ -20,20 +20,20 @@ impl From<VersionSpecifier> for Ranges<Version> {
     /// Convert the [`VersionSpecifier`] to a PubGrub-compatible version range, using PEP 440
     /// semantics.
     fn from(specifier: VersionSpecifier) -> Self {
-        let VersionSpecifier { operator, version } = specifier;
+        let VersionSpecifier {
+            operator, version, ..
+        } = specifier;
         match operator {
             Operator::Equal => Ranges::singleton(version),
             Operator::ExactEqual => Ranges::singleton(version),
             Operator::NotEqual => Ranges::singleton(version).complement(),
             Operator::TildeEqual => {
-                let [rest @ .., last, _] = version.release() else {
-                    unreachable!("~= must have at least two segments");
-                };
-                let upper = Version::new(rest.iter().chain([&(last + 1)]))
-                    .with_epoch(version.epoch())
-                    .with_dev(Some(0));
-
-                Ranges::from_range_bounds(version..upper)
+                let upper = compatible_upper(&version).map(|upper| {
+                    Version::new(upper)
+                        .with_epoch(version.epoch())
+                        .with_dev(Some(0))
+                });
+                range_from(version, upper)
             }
             Operator::LessThan => {
                 if version.any_prerelease() {
@@ -61,42 +61,50 @@ impl From<VersionSpecifier> for Ranges<Version> {
             Operator::GreaterThanEqual => Ranges::higher_than(version),
             Operator::EqualStar => {
                 let low = version.with_dev(Some(0));
-                let mut high = low.clone();
-                if let Some(post) = high.post() {
-                    high = high.with_post(Some(post + 1));
-                } else if let Some(pre) = high.pre() {
-                    high = high.with_pre(Some(Prerelease {
-                        kind: pre.kind,
-                        number: pre.number + 1,
-                    }));
-                } else {
-                    let mut release = high.release().to_vec();
-                    *release.last_mut().unwrap() += 1;
-                    high = high.with_release(release);
-                }
-                Ranges::from_range_bounds(low..high)
+                let high = star_upper(&low);
+                range_from(low, high)
             }
             Operator::NotEqualStar => {
                 let low = version.with_dev(Some(0));
-                let mut high = low.clone();
-                if let Some(post) = high.post() {
-                    high = high.with_post(Some(post + 1));
-                } else if let Some(pre) = high.pre() {
-                    high = high.with_pre(Some(Prerelease {
-                        kind: pre.kind,
-                        number: pre.number + 1,
-                    }));
-                } else {
-                    let mut release = high.release().to_vec();
-                    *release.last_mut().unwrap() += 1;
-                    high = high.with_release(release);
-                }
-                Ranges::from_range_bounds(low..high).complement()
+                let high = star_upper(&low);
+                range_from(low, high).complement()
             }
         }
     }
 }
 
+impl VersionSpecifiers {
+    /// Whether both specifiers allow the same versions, even if they are written differently,
+    /// e.g. `~=1.4.5` and `>=1.4.5, ==1.4.*`, or `>=1.0, >=0.5` and `>=1`.
+    ///
+    /// The versions are compared by their [`Ranges`] with PEP 440 semantics. Specifiers with `===`
+    /// are only semantically equal if they are equal, since `===` compares strings.
+    pub fn semantically_equals(&self, other: &Self) -> bool {
+        let arbitrary = |specifiers: &Self| {
+            specifiers
+                .iter()
+                .any(|specifier| *specifier.operator() == Operator::ExactEqual)
+        };
+        if arbitrary(self) || arbitrary(other) {
+            return self == other;
+        }
+        Ranges::from(self.clone()) == Ranges::from(other.clone())
+    }
+}
+
+impl VersionSpecifier {
+    /// Whether both specifiers allow the same versions, even if they are written differently,
+    /// e.g. `==1.2.*` and `~=1.2.0.dev0`.
+    ///
+    /// See [`VersionSpecifiers::semantically_equals`].
+    pub fn semantically_equals(&self, other: &Self) -> bool {
+        if self.operator == Operator::ExactEqual || other.operator == Operator::ExactEqual {
+            return self == other;
+        }
+        Ranges::from(self.clone()) == Ranges::from(other.clone())
+    }
+}
+
 /// Convert the [`VersionSpecifiers`] to a PubGrub-compatible version range, using release-only
 /// semantics.
 ///
@@ -128,7 +136,9 @@ pub fn release_specifiers_to_ranges(specifiers: VersionSpecifiers) -> Ranges<Ver
 ///
 /// See: <https://github.com/pypa/pip/blob/a432c7f4170b9ef798a15f035f5dfdb4cc939f35/src/pip/_internal/resolution/resolvelib/candidates.py#L540>
 pub fn release_specifier_to_range(specifier: VersionSpecifier) -> Ranges<Version> {
-    let VersionSpecifier { operator, version } = specifier;
+    let VersionSpecifier {
+        operator, version, ..
+    } = specifier;
     match operator {
         Operator::Equal => {
             let version = version.only_release();
@@ -143,12 +153,9 @@ pub fn release_specifier_to_range(specifier: VersionSpecifier) -> Ranges<Version
             Ranges::singleton(version).complement()
         }
         Operator::TildeEqual => {
-            let [rest @ .., last, _] = version.release() else {
-                unreachable!("~= must have at least two segments");
-            };
-            let upper = Version::new(rest.iter().chain([&(last + 1)]));
+            let upper = compatible_upper(&version).map(Version::new);
             let version = version.only_release();
-            Ranges::from_range_bounds(version..upper)
+            range_from(version, upper)
         }
         Operator::LessThan => {
             let version = version.only_release();
@@ -168,25 +175,49 @@ pub fn release_specifier_to_range(specifier: VersionSpecifier) -> Ranges<Version
         }
         Operator::EqualStar => {
             let low = version.only_release();
-            let high = {
-                let mut high = low.clone();
-                let mut release = high.release().to_vec();
-                *release.last_mut().unwrap() += 1;
-                high = high.with_release(release);
-                high
-            };
-            Ranges::from_range_bounds(low..high)
+            let high = low.release().increment_last().map(Version::new);
+            range_from(low, high)
         }
         Operator::NotEqualStar => {
             let low = version.only_release();
-            let high = {
-                let mut high = low.clone();
-                let mut release = high.release().to_vec();
-                *release.last_mut().unwrap() += 1;
-                high = high.with_release(release);
-                high
-            };
-            Ranges::from_range_bounds(low..high).complement()
+            let high = low.release().increment_last().map(Version::new);
+            range_from(low, high).complement()
         }
     }
 }
+
+/// The exclusive upper bound of `==V.*` for `low`, which is `V` with a dev version of `0`.
+///
+/// Returns `None` if there is no upper bound, because the incremented number would overflow.
+fn star_upper(low: &Version) -> Option<Version> {
+    if let Some(post) = low.post() {
+        Some(low.clone().with_post(Some(post.checked_add(1)?)))
+    } else if let Some(pre) = low.pre() {
+        Some(low.clone().with_pre(Some(Prerelease {
+            kind: pre.kind,
+            number: pre.number.checked_add(1)?,
+        })))
+    } else {
+        let release = low.release().increment_last()?;
+        Some(low.clone().with_release(release))
+    }
+}
+
+/// The release of the exclusive upper bound of `~=V`, e.g. `2` for `~=1.4`.
+///
+/// Returns `None` if the last number of the prefix would overflow or, for a `~=` with a single
+/// segment that bypassed the constructor, if there is no prefix.
+fn compatible_upper(version: &Version) -> Option<Vec<u64>> {
+    let release = version.release();
+    release
+        .truncate(release.len().saturating_sub(1))
+        .increment_last()
+}
+
+/// The versions from `low` up to `high`, or all versions from `low` if there is no `high`.
+fn range_from(low: Version, high: Option<Version>) -> Ranges<Version> {
+    match high {
+        Some(high) => Ranges::from_range_bounds(low..high),
+        None => Ranges::higher_than(low),
+    }
+}
@@ -1,9 +1,13 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::ops::Bound;
+use std::ops::{Bound, Range};
 use std::str::FromStr;
+use std::sync::Arc;
 
+use crate::version::check_version_pattern;
 use crate::{
-    version, Operator, OperatorParseError, Version, VersionPattern, VersionPatternParseError,
+    Operator, OperatorParseError, ParseWarning, Release, Version, VersionErrorKind, VersionPattern,
+    VersionPatternParseError,
 };
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 #[cfg(feature = "tracing")]
@@ -14,6 +18,11 @@ use tracing::warn;
 /// Python requirements can contain multiple version specifier so we need to store them in a list,
 /// such as `>1.2,<2.0` being `[">1.2", "<2.0"]`.
 ///
+/// The specifiers are sorted by version and then by operator, so the order they were written in
+/// doesn't matter: `>=1.0,<2.0` and `<2.0,>=1.0` are equal and have the same hash, which makes
+/// them usable as cache keys. This compares the clauses, not the matching versions, which
+/// `semantically_equals` with the `version-ranges` feature does.
+///
 /// ```rust
 /// # use std::str::FromStr;
 /// # use pep440_rs::{VersionSpecifiers, Version, Operator};
@@ -24,7 +33,7 @@ use tracing::warn;
 /// // VersionSpecifiers derefs into a list of specifiers
 /// assert_eq!(version_specifiers.iter().position(|specifier| *specifier.operator() == Operator::LessThan), Some(1));
 /// ```
-#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Clone, Hash)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
@@ -46,21 +55,331 @@ impl VersionSpecifiers {
         Self(Vec::new())
     }
 
+    /// Matches all versions, the same as [`VersionSpecifiers::empty`] and parsing an empty or
+    /// whitespace-only string.
+    pub fn any() -> Self {
+        Self::empty()
+    }
+
+    /// A single specifier pinning exactly the given version, see [`VersionSpecifier::pin`].
+    pub fn pinned(version: &Version, pin: Pin) -> Self {
+        Self(vec![VersionSpecifier::pin(version, pin)])
+    }
+
     /// Whether all specifiers match the given version.
     pub fn contains(&self, version: &Version) -> bool {
         self.iter().all(|specifier| specifier.contains(version))
     }
 
+    /// Whether all specifiers match the given version, with `===` comparing the candidate as
+    /// written, see [`VersionSpecifier::contains_verbatim`].
+    pub fn contains_verbatim(&self, version: &Version, verbatim: &str) -> bool {
+        self.iter()
+            .all(|specifier| specifier.contains_verbatim(version, verbatim))
+    }
+
+    /// For each specifier, return whether the version satisfies it, and if not, why.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{MismatchReason, Version, VersionSpecifiers};
+    ///
+    /// let specifiers = VersionSpecifiers::from_str(">=1.16, !=1.19, <2.0").unwrap();
+    /// let version = Version::from_str("1.19").unwrap();
+    /// let reasons: Vec<_> = specifiers
+    ///     .explain(&version)
+    ///     .into_iter()
+    ///     .map(|(specifier, reason)| (specifier.to_string(), reason))
+    ///     .collect();
+    /// assert_eq!(
+    ///     reasons,
+    ///     [
+    ///         (">=1.16".to_string(), None),
+    ///         ("!=1.19".to_string(), Some(MismatchReason::Excluded)),
+    ///         ("<2.0".to_string(), None),
+    ///     ]
+    /// );
+    /// ```
+    pub fn explain(&self, version: &Version) -> Vec<(&VersionSpecifier, Option<MismatchReason>)> {
+        self.iter()
+            .map(|specifier| (specifier, specifier.explain(version)))
+            .collect()
+    }
+
+    /// The smallest interval containing all versions that match all specifiers, or `None` if
+    /// the intervals of the specifiers don't overlap.
+    ///
+    /// The interval can be passed to [`BTreeSet::range`](std::collections::BTreeSet::range)
+    /// and [`BTreeMap::range`](std::collections::BTreeMap::range), which panic for empty
+    /// intervals. Like for [`VersionSpecifier::bounding_range`], the results still need to be
+    /// filtered with [`VersionSpecifiers::contains`].
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifiers};
+    ///
+    /// let versions: BTreeSet<Version> = ["1.0", "1.1", "1.2", "1.3", "2.0"]
+    ///     .into_iter()
+    ///     .map(|version| Version::from_str(version).unwrap())
+    ///     .collect();
+    /// let specifiers = VersionSpecifiers::from_str(">=1.1, !=1.2, <2").unwrap();
+    /// let bounds = specifiers.bounding_range().unwrap();
+    /// let matching: Vec<String> = versions
+    ///     .range(bounds)
+    ///     .filter(|version| specifiers.contains(version))
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(matching, ["1.1", "1.3"]);
+    ///
+    /// // No version can match both
+    /// assert_eq!(VersionSpecifiers::from_str(">=2, <1").unwrap().bounding_range(), None);
+    /// ```
+    pub fn bounding_range(&self) -> Option<(Bound<Version>, Bound<Version>)> {
+        let mut lower = Bound::Unbounded;
+        let mut upper = Bound::Unbounded;
+        for specifier in self.iter() {
+            let (specifier_lower, specifier_upper) = specifier.bounding_range();
+            if compare_lower_bounds(&specifier_lower, &lower) == Ordering::Greater {
+                lower = specifier_lower;
+            }
+            if compare_upper_bounds(&specifier_upper, &upper) == Ordering::Less {
+                upper = specifier_upper;
+            }
+        }
+        let is_empty = match (&lower, &upper) {
+            (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+            (Bound::Included(lower), Bound::Excluded(upper))
+            | (Bound::Excluded(lower), Bound::Included(upper))
+            | (Bound::Excluded(lower), Bound::Excluded(upper)) => lower >= upper,
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+        };
+        if is_empty {
+            None
+        } else {
+            Some((lower, upper))
+        }
+    }
+
+    /// Classify how tightly the specifiers constrain the version, for linting requirements
+    /// that are unpinned or have no upper bound.
+    ///
+    /// The specifiers that set the tightest bounds are attached, so `>=1.0, >=1.2, <2` is
+    /// [`Boundedness::Bounded`] by `>=1.2` and `<2`. `!=` doesn't count as a bound.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Boundedness, VersionSpecifiers};
+    ///
+    /// let specifiers = VersionSpecifiers::from_str(">=1.2, !=1.5").unwrap();
+    /// let Boundedness::LowerOnly(lower) = specifiers.boundedness() else {
+    ///     panic!("expected only a lower bound");
+    /// };
+    /// assert_eq!(lower.to_string(), ">=1.2");
+    /// ```
+    pub fn boundedness(&self) -> Boundedness<'_> {
+        if let Some(pinned) = self.iter().find(|specifier| {
+            matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+        }) {
+            return Boundedness::Pinned(pinned);
+        }
+        let mut lower: Option<(&VersionSpecifier, Bound<Version>)> = None;
+        let mut upper: Option<(&VersionSpecifier, Bound<Version>)> = None;
+        for specifier in self.iter() {
+            let (specifier_lower, specifier_upper) = specifier.bounding_range();
+            if compare_lower_bounds(
+                &specifier_lower,
+                lower.as_ref().map_or(&Bound::Unbounded, |(_, bound)| bound),
+            ) == Ordering::Greater
+            {
+                lower = Some((specifier, specifier_lower));
+            }
+            if compare_upper_bounds(
+                &specifier_upper,
+                upper.as_ref().map_or(&Bound::Unbounded, |(_, bound)| bound),
+            ) == Ordering::Less
+            {
+                upper = Some((specifier, specifier_upper));
+            }
+        }
+        match (lower, upper) {
+            (Some((lower, _)), Some((upper, _))) => Boundedness::Bounded { lower, upper },
+            (Some((lower, _)), None) => Boundedness::LowerOnly(lower),
+            (None, Some((upper, _))) => Boundedness::UpperOnly(upper),
+            (None, None) => Boundedness::Unbounded,
+        }
+    }
+
     /// Returns `true` if there are no specifiers.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Add a specifier, keeping the specifiers sorted by version and then by operator.
+    ///
+    /// The order is the same as when parsing the combined list.
+    pub fn push(&mut self, specifier: VersionSpecifier) {
+        let index = self
+            .0
+            .partition_point(|existing| cmp_clauses(existing, &specifier) != Ordering::Greater);
+        self.0.insert(index, specifier);
+    }
+
+    /// Remove and return the specifier at `index`.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> VersionSpecifier {
+        self.0.remove(index)
+    }
+
+    /// Keep only the specifiers for which `keep` returns `true`.
+    pub fn retain(&mut self, keep: impl FnMut(&VersionSpecifier) -> bool) {
+        self.0.retain(keep);
+    }
+
+    /// Replace the specifier at `index`, returning the old one.
+    ///
+    /// The new specifier is moved to its sorted position, so it may end up at a different index.
+    ///
+    /// # Panics
+    ///
+    /// When `index` is out of bounds.
+    pub fn replace_clause(
+        &mut self,
+        index: usize,
+        specifier: VersionSpecifier,
+    ) -> VersionSpecifier {
+        let old = self.0.remove(index);
+        self.push(specifier);
+        old
+    }
+
+    /// Remove specifiers that don't change which versions match: duplicates, and all `>=` but
+    /// the highest and all `<=` but the lowest.
+    ///
+    /// Use this to clean up after merging specifiers from multiple sources.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::VersionSpecifiers;
+    ///
+    /// let mut specifiers = VersionSpecifiers::from_str(">=1.0, !=1.5, >=1.2, <3, !=1.5").unwrap();
+    /// specifiers.simplify();
+    /// assert_eq!(specifiers.to_string(), ">=1.2, !=1.5, <3");
+    /// ```
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(level = "debug", skip_all, fields(clauses = self.len(), removed))
+    )]
+    pub fn simplify(&mut self) {
+        #[cfg(feature = "instrument")]
+        let clauses = self.len();
+        // `>=` and `<=` compare the public version without exceptions, so the tightest bound
+        // implies all others
+        let lower = self
+            .iter()
+            .filter(|specifier| *specifier.operator() == Operator::GreaterThanEqual)
+            .map(VersionSpecifier::version)
+            .max()
+            .cloned();
+        let upper = self
+            .iter()
+            .filter(|specifier| *specifier.operator() == Operator::LessThanEqual)
+            .map(VersionSpecifier::version)
+            .min()
+            .cloned();
+        let mut seen: Vec<VersionSpecifier> = Vec::with_capacity(self.0.len());
+        self.0.retain(|specifier| {
+            let redundant = match specifier.operator() {
+                Operator::GreaterThanEqual => lower.as_ref() != Some(specifier.version()),
+                Operator::LessThanEqual => upper.as_ref() != Some(specifier.version()),
+                _ => false,
+            };
+            if redundant || seen.contains(specifier) {
+                return false;
+            }
+            seen.push(specifier.clone());
+            true
+        });
+        #[cfg(feature = "instrument")]
+        tracing::Span::current().record("removed", clauses - self.len());
+    }
+
+    /// Drop the specifiers that don't change which of the `available` versions match, e.g. a
+    /// `!=1.3.4` when `1.3.4` was never published, or a `>=1.0` when all published versions are
+    /// newer.
+    ///
+    /// The result matches exactly the same available versions, but may match different
+    /// unpublished versions. When multiple specifiers are only redundant together, the later
+    /// ones are kept.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifiers};
+    ///
+    /// let available: Vec<Version> = ["1.2", "1.3", "1.3.5", "2.0"]
+    ///     .into_iter()
+    ///     .map(|version| Version::from_str(version).unwrap())
+    ///     .collect();
+    /// let specifiers = VersionSpecifiers::from_str(">=1.0, !=1.3.4, !=1.3.5, <2").unwrap();
+    /// assert_eq!(specifiers.minimize(&available).to_string(), "!=1.3.5, <2");
+    /// ```
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(clauses = self.len(), available = available.len(), kept)
+        )
+    )]
+    pub fn minimize(&self, available: &[Version]) -> Self {
+        let matches: Vec<Vec<bool>> = self
+            .iter()
+            .map(|specifier| {
+                available
+                    .iter()
+                    .map(|version| specifier.contains(version))
+                    .collect()
+            })
+            .collect();
+        let matching = |included: &[bool]| -> Vec<bool> {
+            (0..available.len())
+                .map(|idx| {
+                    matches
+                        .iter()
+                        .zip(included)
+                        .all(|(matches, included)| !included || matches[idx])
+                })
+                .collect()
+        };
+
+        let mut included = vec![true; self.0.len()];
+        let expected = matching(&included);
+        for idx in 0..included.len() {
+            included[idx] = false;
+            if matching(&included) != expected {
+                included[idx] = true;
+            }
+        }
+        let minimized = Self(
+            self.iter()
+                .zip(included)
+                .filter(|(_, included)| *included)
+                .map(|(specifier, _)| specifier.clone())
+                .collect(),
+        );
+        #[cfg(feature = "instrument")]
+        tracing::Span::current().record("kept", minimized.len());
+        minimized
+    }
+
     /// Sort the specifiers.
     fn from_unsorted(mut specifiers: Vec<VersionSpecifier>) -> Self {
         // TODO(konsti): This seems better than sorting on insert and not getting the size hint,
         // but i haven't measured it.
-        specifiers.sort_by(|a, b| a.version().cmp(b.version()));
+        specifiers.sort_by(cmp_clauses);
         Self(specifiers)
     }
 
@@ -129,6 +448,24 @@ impl FromStr for VersionSpecifiers {
     }
 }
 
+/// Parse with [`VersionSpecifiers::from_str`].
+impl TryFrom<&str> for VersionSpecifiers {
+    type Error = VersionSpecifiersParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+/// Parse with [`VersionSpecifiers::from_str`].
+impl TryFrom<String> for VersionSpecifiers {
+    type Error = VersionSpecifiersParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
 impl From<VersionSpecifier> for VersionSpecifiers {
     fn from(specifier: VersionSpecifier) -> Self {
         Self(vec![specifier])
@@ -137,6 +474,19 @@ impl From<VersionSpecifier> for VersionSpecifiers {
 
 impl std::fmt::Display for VersionSpecifiers {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Honor width, fill and alignment, e.g. `{:>12}`, for aligned output
+        if f.width().is_some() || f.precision().is_some() {
+            let mut normalized = String::new();
+            self.write_normalized(&mut normalized)?;
+            return f.pad(&normalized);
+        }
+        self.write_normalized(f)
+    }
+}
+
+impl VersionSpecifiers {
+    /// Write the specifiers without padding.
+    fn write_normalized(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
         for (idx, version_specifier) in self.0.iter().enumerate() {
             // Separate version specifiers by comma, but we need one comma less than there are
             // specifiers
@@ -150,6 +500,18 @@ impl std::fmt::Display for VersionSpecifiers {
     }
 }
 
+/// Shows the specifiers, e.g. `VersionSpecifiers(">=1.0, <2.0")`, or each specifier with `{:#?}`.
+impl std::fmt::Debug for VersionSpecifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return f.debug_tuple("VersionSpecifiers").field(&self.0).finish();
+        }
+        f.debug_tuple("VersionSpecifiers")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
 impl Default for VersionSpecifiers {
     fn default() -> Self {
         Self::empty()
@@ -183,6 +545,20 @@ impl Serialize for VersionSpecifiers {
 }
 
 /// Error with span information (unicode width) inside the parsed line
+///
+/// The default `Display` shows the line with the failing part underlined, on multiple lines. The
+/// alternate form (`{:#}`) is a single line with the byte span instead, for structured logs:
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::VersionSpecifiers;
+///
+/// let err = VersionSpecifiers::from_str(">=1.0, 3.0").unwrap_err();
+/// assert_eq!(
+///     format!("{err:#}"),
+///     "error at 6..10: Unexpected end of version specifier, expected operator"
+/// );
+/// ```
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct VersionSpecifiersParseError {
     // Clippy complains about this error type being too big (at time of
@@ -194,8 +570,8 @@ pub struct VersionSpecifiersParseError {
 struct VersionSpecifiersParseErrorInner {
     /// The underlying error that occurred.
     err: VersionSpecifierParseError,
-    /// The string that failed to parse
-    line: String,
+    /// The string that failed to parse, shared so that cloning the error doesn't copy it.
+    line: Arc<str>,
     /// The starting byte offset into the original string where the error
     /// occurred.
     start: usize,
@@ -214,9 +590,16 @@ impl std::fmt::Display for VersionSpecifiersParseError {
             start,
             end,
         } = *self.inner;
+        if f.alternate() {
+            return write!(f, "error at {start}..{end}: {err}");
+        }
         writeln!(f, "Failed to parse version: {err}:")?;
-        writeln!(f, "{line}")?;
-        let indent = line[..start].width();
+        // For long lists of specifiers, only show the part around the error
+        let (window_start, window_end) = error_window(line, start, end);
+        let prefix = if window_start > 0 { "…" } else { "" };
+        let suffix = if window_end < line.len() { "…" } else { "" };
+        writeln!(f, "{prefix}{}{suffix}", &line[window_start..window_end])?;
+        let indent = prefix.width() + line[window_start..start].width();
         let point = line[start..end].width();
         writeln!(f, "{}{}", " ".repeat(indent), "^".repeat(point))?;
         Ok(())
@@ -225,9 +608,32 @@ impl std::fmt::Display for VersionSpecifiersParseError {
 
 impl VersionSpecifiersParseError {
     /// The string that failed to parse
-    pub fn line(&self) -> &String {
+    pub fn line(&self) -> &str {
         &self.inner.line
     }
+
+    /// The byte range of the invalid specifier in [`VersionSpecifiersParseError::line`].
+    pub fn span(&self) -> Range<usize> {
+        self.inner.start..self.inner.end
+    }
+
+    /// The error for the invalid specifier.
+    pub fn specifier_error(&self) -> &VersionSpecifierParseError {
+        &self.inner.err
+    }
+
+    /// What went wrong, for handling the error programmatically.
+    pub fn kind(&self) -> SpecifierErrorKind {
+        self.inner.err.kind()
+    }
+
+    /// Point the error into a larger string, in which the parsed string starts at `offset`.
+    pub(crate) fn within(mut self, line: &str, offset: usize) -> Self {
+        self.inner.line = line.into();
+        self.inner.start += offset;
+        self.inner.end += offset;
+        self
+    }
 }
 
 impl std::error::Error for VersionSpecifiersParseError {}
@@ -243,7 +649,7 @@ impl std::error::Error for VersionSpecifiersParseError {}
 /// let version_specifier = VersionSpecifier::from_str("== 1.*").unwrap();
 /// assert!(version_specifier.contains(&version));
 /// ```
-#[derive(Eq, Ord, PartialEq, PartialOrd, Debug, Clone, Hash)]
+#[derive(Eq, Ord, PartialEq, PartialOrd, Clone, Hash)]
 #[cfg_attr(
     feature = "rkyv",
     derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize)
@@ -254,6 +660,9 @@ pub struct VersionSpecifier {
     pub(crate) operator: Operator,
     /// The whole version part behind the operator
     pub(crate) version: Version,
+    /// For `===`, the version as written, which is compared to the candidate instead of the
+    /// normalized version.
+    pub(crate) verbatim: Option<Box<str>>,
 }
 
 /// <https://github.com/serde-rs/serde/issues/1316#issue-332908452>
@@ -308,15 +717,39 @@ impl VersionSpecifier {
         version: Version,
     ) -> Result<Self, VersionSpecifierBuildError> {
         // "Local version identifiers are NOT permitted in this version specifier."
-        if version.is_local() && !operator.is_local_compatible() {
+        if version.is_local() && !operator.allows_local() {
             return Err(BuildErrorKind::OperatorLocalCombo { operator, version }.into());
         }
 
-        if operator == Operator::TildeEqual && version.release().len() < 2 {
+        if operator.requires_two_release_segments() && version.release().len() < 2 {
             return Err(BuildErrorKind::CompatibleRelease.into());
         }
 
-        Ok(Self { operator, version })
+        // `1.0rc1.*` isn't a valid pattern, the wildcard must follow the release
+        if operator.is_star() && (version.any_prerelease() || version.is_post()) {
+            return Err(BuildErrorKind::StarNotReleaseOnly { operator, version }.into());
+        }
+
+        Ok(Self {
+            operator,
+            version,
+            verbatim: None,
+        })
+    }
+
+    /// Create a version specifier from an operator and a version without checking that they
+    /// can be combined, for specifiers that were already validated, e.g. read back from a cache.
+    ///
+    /// The caller must ensure that only `==`, `!=` and `===` have a local version, that the star
+    /// operators have a version consisting of only an epoch and a release, and that `~=` has at
+    /// least two release numbers. Otherwise, matching gives unspecified results, though it
+    /// doesn't panic. `===` compares the normalized version, since there is no original string.
+    pub fn new_unchecked(operator: Operator, version: Version) -> Self {
+        Self {
+            operator,
+            version,
+            verbatim: None,
+        }
     }
 
     /// `==<version>`
@@ -324,6 +757,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::Equal,
             version,
+            verbatim: None,
         }
     }
 
@@ -332,6 +766,30 @@ impl VersionSpecifier {
         Self {
             operator: Operator::EqualStar,
             version,
+            verbatim: None,
+        }
+    }
+
+    /// Pin exactly the given version, e.g. for writing a lockfile, see [`Pin`] for the options.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Pin, Version, VersionSpecifier};
+    ///
+    /// let version = Version::from_str("2.1.0+cu118").unwrap();
+    /// assert_eq!(VersionSpecifier::pin(&version, Pin::Exact).to_string(), "==2.1.0+cu118");
+    /// assert_eq!(VersionSpecifier::pin(&version, Pin::Public).to_string(), "==2.1.0");
+    /// assert_eq!(VersionSpecifier::pin(&version, Pin::Arbitrary).to_string(), "===2.1.0+cu118");
+    /// ```
+    pub fn pin(version: &Version, pin: Pin) -> Self {
+        match pin {
+            Pin::Exact => Self::equals_version(version.clone()),
+            Pin::Public => Self::equals_version(version.clone().without_local()),
+            Pin::Arbitrary => Self {
+                operator: Operator::ExactEqual,
+                version: version.clone(),
+                verbatim: Some(version.to_string().into()),
+            },
         }
     }
 
@@ -340,6 +798,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::NotEqualStar,
             version,
+            verbatim: None,
         }
     }
 
@@ -348,6 +807,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::NotEqual,
             version,
+            verbatim: None,
         }
     }
 
@@ -356,6 +816,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::GreaterThanEqual,
             version,
+            verbatim: None,
         }
     }
     /// `><version>`
@@ -363,6 +824,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::GreaterThan,
             version,
+            verbatim: None,
         }
     }
 
@@ -371,6 +833,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::LessThanEqual,
             version,
+            verbatim: None,
         }
     }
 
@@ -379,6 +842,7 @@ impl VersionSpecifier {
         Self {
             operator: Operator::LessThan,
             version,
+            verbatim: None,
         }
     }
 
@@ -392,6 +856,14 @@ impl VersionSpecifier {
         &self.version
     }
 
+    /// For `===`, the version as written in the specifier, e.g. `1.0.0-post1` in `===1.0.0-post1`.
+    ///
+    /// Like in packaging, [`VersionSpecifier::contains`] compares the normalized candidate to this
+    /// string, while [`VersionSpecifier::contains_verbatim`] compares the candidate as written.
+    pub fn verbatim(&self) -> Option<&str> {
+        self.verbatim.as_deref()
+    }
+
     /// Get the operator and version parts of this specifier.
     pub fn into_parts(self) -> (Operator, Version) {
         (self.operator, self.version)
@@ -402,6 +874,79 @@ impl VersionSpecifier {
         self.version.any_prerelease()
     }
 
+    /// The smallest interval containing all versions that match the specifier, which can be
+    /// passed to [`BTreeSet::range`](std::collections::BTreeSet::range) and
+    /// [`BTreeMap::range`](std::collections::BTreeMap::range).
+    ///
+    /// The interval may contain versions that don't match, so the results still need to be
+    /// filtered with [`VersionSpecifier::contains`]: `!=` and `!=V.*` can't be expressed as a
+    /// single interval, so they are unbounded, and `>V` and `<V` include the post- and
+    /// pre-releases of `V` that they exclude.
+    ///
+    /// ```rust
+    /// use std::collections::BTreeSet;
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifier};
+    ///
+    /// let versions: BTreeSet<Version> = ["1.0", "1.1", "1.1.5", "1.2", "2.0"]
+    ///     .into_iter()
+    ///     .map(|version| Version::from_str(version).unwrap())
+    ///     .collect();
+    /// let specifier = VersionSpecifier::from_str("==1.1.*").unwrap();
+    /// let matching: Vec<String> = versions
+    ///     .range(specifier.bounding_range())
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(matching, ["1.1", "1.1.5"]);
+    /// ```
+    pub fn bounding_range(&self) -> (Bound<Version>, Bound<Version>) {
+        let version = &self.version;
+        match self.operator {
+            Operator::Equal if version.is_local() => (
+                Bound::Included(version.clone()),
+                Bound::Included(version.clone()),
+            ),
+            Operator::Equal => (
+                Bound::Included(version.clone()),
+                after_local_versions(version),
+            ),
+            Operator::ExactEqual => (
+                Bound::Included(version.clone()),
+                Bound::Included(version.clone()),
+            ),
+            Operator::EqualStar => {
+                let lower = Version::new(version.release())
+                    .with_epoch(version.epoch())
+                    .with_min(Some(0));
+                let upper = match version.release().increment_last() {
+                    Some(upper) => Bound::Excluded(
+                        Version::new(upper)
+                            .with_epoch(version.epoch())
+                            .with_min(Some(0)),
+                    ),
+                    None => Bound::Unbounded,
+                };
+                (Bound::Included(lower), upper)
+            }
+            Operator::NotEqual | Operator::NotEqualStar => (Bound::Unbounded, Bound::Unbounded),
+            Operator::TildeEqual => {
+                let upper = match Self::compatible_prefix(version).increment_last() {
+                    Some(upper) => Bound::Excluded(
+                        Version::new(upper)
+                            .with_epoch(version.epoch())
+                            .with_min(Some(0)),
+                    ),
+                    None => Bound::Unbounded,
+                };
+                (Bound::Included(version.clone()), upper)
+            }
+            Operator::GreaterThan => (Bound::Excluded(version.clone()), Bound::Unbounded),
+            Operator::GreaterThanEqual => (Bound::Included(version.clone()), Bound::Unbounded),
+            Operator::LessThan => (Bound::Unbounded, Bound::Excluded(version.clone())),
+            Operator::LessThanEqual => (Bound::Unbounded, after_local_versions(version)),
+        }
+    }
+
     /// Returns the version specifiers whose union represents the given range.
     ///
     /// This function is not applicable to ranges involving pre-release versions.
@@ -466,26 +1011,45 @@ impl VersionSpecifier {
     /// - <https://peps.python.org/pep-0440/#version-specifiers>
     /// - <https://github.com/pypa/packaging/blob/e184feef1a28a5c574ec41f5c263a3a573861f5a/packaging/specifiers.py#L362-L496>
     pub fn contains(&self, version: &Version) -> bool {
+        self.contains_impl(version, None)
+    }
+
+    /// Whether the given version satisfies the version range, with `===` comparing `verbatim`,
+    /// the candidate as written, e.g. in a filename, instead of its normalized form.
+    ///
+    /// `verbatim` must be the string that `version` was parsed from. All other operators ignore it.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{Version, VersionSpecifier};
+    ///
+    /// let specifier = VersionSpecifier::from_str("===1.0.0-post1").unwrap();
+    /// let version = Version::from_str("1.0.0-post1").unwrap();
+    /// // The normalized candidate is `1.0.0.post1`
+    /// assert!(!specifier.contains(&version));
+    /// assert!(specifier.contains_verbatim(&version, "1.0.0-post1"));
+    /// ```
+    pub fn contains_verbatim(&self, version: &Version, verbatim: &str) -> bool {
+        self.contains_impl(version, Some(verbatim))
+    }
+
+    fn contains_impl(&self, version: &Version, verbatim: Option<&str>) -> bool {
         // "Except where specifically noted below, local version identifiers MUST NOT be permitted
         // in version specifiers, and local version labels MUST be ignored entirely when checking
         // if candidate versions match a given version specifier."
-        let (this, other) = if self.version.local().is_empty() {
-            // self is already without local
-            (self.version.clone(), version.clone().without_local())
+        let ignore_local = self.version.local().is_empty();
+        let (this, other) = (&self.version, version);
+        // How the candidate compares to the version in the specifier
+        let cmp = if ignore_local {
+            other.cmp_public(this)
         } else {
-            (self.version.clone(), version.clone())
+            other.cmp(this)
         };
 
         match self.operator {
-            Operator::Equal => other == this,
+            Operator::Equal => cmp == Ordering::Equal,
             Operator::EqualStar => {
-                this.epoch() == other.epoch()
-                    && self
-                        .version
-                        .release()
-                        .iter()
-                        .zip(other.release())
-                        .all(|(this, other)| this == other)
+                this.epoch() == other.epoch() && other.release().starts_with(&this.release())
             }
             #[allow(deprecated)]
             Operator::ExactEqual => {
@@ -493,52 +1057,92 @@ impl VersionSpecifier {
                 {
                     tracing::warn!("Using arbitrary equality (`===`) is discouraged");
                 }
-                self.version.to_string() == version.to_string()
+                // Like packaging, compare the operand as written to the normalized candidate,
+                // unless we know how the candidate was written
+                let candidate = match verbatim {
+                    Some(verbatim) => Cow::Borrowed(verbatim.trim()),
+                    None => Cow::Owned(version.to_string()),
+                };
+                match self.verbatim.as_deref() {
+                    Some(operand) => operand.eq_ignore_ascii_case(&candidate),
+                    None => self.version.to_string().eq_ignore_ascii_case(&candidate),
+                }
             }
-            Operator::NotEqual => other != this,
+            Operator::NotEqual => cmp != Ordering::Equal,
             Operator::NotEqualStar => {
-                this.epoch() != other.epoch()
-                    || !this
-                        .release()
-                        .iter()
-                        .zip(version.release())
-                        .all(|(this, other)| this == other)
+                this.epoch() != other.epoch() || !other.release().starts_with(&this.release())
             }
             Operator::TildeEqual => {
                 // "For a given release identifier V.N, the compatible release clause is
                 // approximately equivalent to the pair of comparison clauses: `>= V.N, == V.*`"
                 // First, we test that every but the last digit matches.
-                // We know that this must hold true since we checked it in the constructor
-                assert!(this.release().len() > 1);
                 if this.epoch() != other.epoch() {
                     return false;
                 }
 
-                if !this.release()[..this.release().len() - 1]
-                    .iter()
-                    .zip(other.release())
-                    .all(|(this, other)| this == other)
-                {
+                if !other.release().starts_with(&Self::compatible_prefix(this)) {
                     return false;
                 }
 
                 // According to PEP 440, this ignores the pre-release special rules
                 // pypa/packaging disagrees: https://github.com/pypa/packaging/issues/617
-                other >= this
+                cmp != Ordering::Less
+            }
+            Operator::GreaterThan => Self::greater_than(this, other, cmp, ignore_local),
+            Operator::GreaterThanEqual => {
+                Self::greater_than(this, other, cmp, ignore_local) || cmp != Ordering::Less
             }
-            Operator::GreaterThan => Self::greater_than(&this, &other),
-            Operator::GreaterThanEqual => Self::greater_than(&this, &other) || other >= this,
             Operator::LessThan => {
-                Self::less_than(&this, &other)
-                    && !(version::compare_release(this.release(), other.release())
-                        == Ordering::Equal
+                Self::less_than(this, other, cmp)
+                    && !(this.epoch() == other.epoch()
+                        && this.release().cmp_padded(&other.release()) == Ordering::Equal
                         && other.any_prerelease())
             }
-            Operator::LessThanEqual => Self::less_than(&this, &other) || other <= this,
+            Operator::LessThanEqual => {
+                Self::less_than(this, other, cmp) || cmp != Ordering::Greater
+            }
         }
     }
 
-    fn less_than(this: &Version, other: &Version) -> bool {
+    /// If the version doesn't satisfy the specifier, return why.
+    ///
+    /// See [`VersionSpecifiers::explain`].
+    pub fn explain(&self, version: &Version) -> Option<MismatchReason> {
+        if self.contains(version) {
+            return None;
+        }
+        let public = version.clone().without_local();
+        let reason = match self.operator {
+            Operator::Equal if self.version.clone().without_local() == public => {
+                MismatchReason::LocalMismatch
+            }
+            Operator::Equal | Operator::EqualStar | Operator::ExactEqual => {
+                MismatchReason::NotEqual
+            }
+            Operator::NotEqual | Operator::NotEqualStar => MismatchReason::Excluded,
+            Operator::TildeEqual if public < self.version => MismatchReason::BelowLowerBound,
+            Operator::TildeEqual => MismatchReason::AboveUpperBound,
+            Operator::GreaterThan if public > self.version => MismatchReason::ExcludedPostRelease,
+            Operator::GreaterThan | Operator::GreaterThanEqual => MismatchReason::BelowLowerBound,
+            Operator::LessThan if public < self.version => MismatchReason::ExcludedPrerelease,
+            Operator::LessThan | Operator::LessThanEqual => MismatchReason::AboveUpperBound,
+        };
+        Some(reason)
+    }
+
+    /// The release prefix that candidates of `~=V` must start with, i.e., all but the last
+    /// segment of `V`.
+    ///
+    /// The constructors reject `~=` with a single segment, but a specifier that bypassed them,
+    /// e.g. through rkyv, still matches without panicking: `~=1` has an empty prefix and behaves
+    /// like `>=1` within the epoch.
+    fn compatible_prefix(version: &Version) -> Release<'_> {
+        let release = version.release();
+        release.truncate(release.len().saturating_sub(1))
+    }
+
+    /// `cmp` is how `other` compares to `this`, ignoring local versions if `this` has none.
+    fn less_than(this: &Version, other: &Version, cmp: Ordering) -> bool {
         if other.epoch() < this.epoch() {
             return true;
         }
@@ -549,20 +1153,21 @@ impl VersionSpecifier {
         // not match 3.1.dev0, but should match 3.0.dev0).
         if !this.any_prerelease()
             && other.is_pre()
-            && version::compare_release(this.release(), other.release()) == Ordering::Equal
+            && this.release().cmp_padded(&other.release()) == Ordering::Equal
         {
             return false;
         }
 
-        other < this
+        cmp == Ordering::Less
     }
 
-    fn greater_than(this: &Version, other: &Version) -> bool {
+    /// `cmp` is how `other` compares to `this`, ignoring local versions if `ignore_local` is set.
+    fn greater_than(this: &Version, other: &Version, cmp: Ordering, ignore_local: bool) -> bool {
         if other.epoch() > this.epoch() {
             return true;
         }
 
-        if version::compare_release(this.release(), other.release()) == Ordering::Equal {
+        if this.release().cmp_padded(&other.release()) == Ordering::Equal {
             // This special case is here so that, unless the specifier itself
             // includes is a post-release version, that we do not accept
             // post-release versions for the version mentioned in the specifier
@@ -572,12 +1177,12 @@ impl VersionSpecifier {
             }
 
             // We already checked that self doesn't have a local version
-            if other.is_local() {
+            if !ignore_local && other.is_local() {
                 return false;
             }
         }
 
-        other > this
+        cmp == Ordering::Greater
     }
 
     /// Whether this version specifier rejects versions below a lower cutoff.
@@ -602,6 +1207,57 @@ impl FromStr for VersionSpecifier {
 
     /// Parses a version such as `>= 1.19`, `== 1.1.*`,`~=1.0+abc.5` or `<=1!2012.2`
     fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        Self::parse(spec, false).map(|(specifier, _)| specifier)
+    }
+}
+
+/// Parse with [`VersionSpecifier::from_str`].
+impl TryFrom<&str> for VersionSpecifier {
+    type Error = VersionSpecifierParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+/// Parse with [`VersionSpecifier::from_str`].
+impl TryFrom<String> for VersionSpecifier {
+    type Error = VersionSpecifierParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_str(&s)
+    }
+}
+
+impl VersionSpecifier {
+    /// Parse a specifier like [`VersionSpecifier::from_str`], but also return the non-fatal issues
+    /// with how it is written: Those of the version, see [`Version::from_str_with_warnings`], and
+    /// the use of `===`.
+    ///
+    /// ```rust
+    /// use pep440_rs::{ParseWarning, VersionSpecifier};
+    ///
+    /// let (specifier, warnings) = VersionSpecifier::from_str_with_warnings("== v1.0").unwrap();
+    /// assert_eq!(specifier.to_string(), "==1.0");
+    /// assert_eq!(
+    ///     warnings,
+    ///     [
+    ///         ParseWarning::VPrefix,
+    ///         ParseWarning::NonCanonical { normalized: "1.0".to_string() }
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_str_with_warnings(
+        spec: &str,
+    ) -> Result<(Self, Vec<ParseWarning>), VersionSpecifierParseError> {
+        Self::parse(spec, true)
+    }
+
+    /// Parse a specifier, collecting warnings only if `warn` is set.
+    fn parse(
+        spec: &str,
+        warn: bool,
+    ) -> Result<(Self, Vec<ParseWarning>), VersionSpecifierParseError> {
         let mut s = unscanny::Scanner::new(spec);
         s.eat_while(|c: char| c.is_whitespace());
         // operator but we don't know yet if it has a star
@@ -615,19 +1271,46 @@ impl FromStr for VersionSpecifier {
         if version.is_empty() {
             return Err(ParseErrorKind::MissingVersion.into());
         }
-        let vpat = version.parse().map_err(ParseErrorKind::InvalidVersion)?;
-        let version_specifier =
+        let (vpat, mut warnings) = if warn {
+            VersionPattern::from_str_with_warnings(version)
+        } else {
+            version.parse().map(|vpat| (vpat, Vec::new()))
+        }
+        .map_err(ParseErrorKind::InvalidVersion)?;
+        let mut version_specifier =
             Self::from_pattern(operator, vpat).map_err(ParseErrorKind::InvalidSpecifier)?;
+        if version_specifier.operator == Operator::ExactEqual {
+            version_specifier.verbatim = Some(version.into());
+            if warn {
+                warnings.push(ParseWarning::ArbitraryEquality);
+            }
+        }
         s.eat_while(|c: char| c.is_whitespace());
         if !s.done() {
             return Err(ParseErrorKind::InvalidTrailing(s.after().to_string()).into());
         }
-        Ok(version_specifier)
+        Ok((version_specifier, warnings))
     }
 }
 
 impl std::fmt::Display for VersionSpecifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Honor width, fill and alignment, e.g. `{:>12}`, for aligned output
+        if f.width().is_some() || f.precision().is_some() {
+            let mut normalized = String::new();
+            self.write_normalized(&mut normalized)?;
+            return f.pad(&normalized);
+        }
+        self.write_normalized(f)
+    }
+}
+
+impl VersionSpecifier {
+    /// Write the specifier without padding.
+    fn write_normalized(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        if let Some(verbatim) = &self.verbatim {
+            return write!(f, "{}{verbatim}", self.operator);
+        }
         if self.operator == Operator::EqualStar || self.operator == Operator::NotEqualStar {
             return write!(f, "{}{}.*", self.operator, self.version);
         }
@@ -635,6 +1318,100 @@ impl std::fmt::Display for VersionSpecifier {
     }
 }
 
+/// Shows the specifier, e.g. `VersionSpecifier(">=1.0")`, or the operator and the version with
+/// `{:#?}`.
+impl std::fmt::Debug for VersionSpecifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return f
+                .debug_struct("VersionSpecifier")
+                .field("operator", &self.operator)
+                .field("version", &self.version)
+                .finish();
+        }
+        f.debug_tuple("VersionSpecifier")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+/// How [`VersionSpecifier::pin`] pins a version.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Pin {
+    /// `==<version>`, including the local version, e.g. `==2.1.0+cu118`.
+    Exact,
+    /// `==<version>` without the local version, e.g. `==2.1.0` for `2.1.0+cu118`, which matches
+    /// all local variants of the public version.
+    Public,
+    /// `===<version>`, e.g. `===2.1.0+cu118`, which only matches this spelling of the version:
+    /// Unlike `==`, it doesn't pad the release with zeros or ignore a local version.
+    Arbitrary,
+}
+
+/// How tightly [`VersionSpecifiers`] constrain the version, see
+/// [`VersionSpecifiers::boundedness`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Boundedness<'a> {
+    /// An `==` or `===` specifier allows only a single version, e.g. `==1.2.3`.
+    Pinned(&'a VersionSpecifier),
+    /// There is both a lower and an upper bound, e.g. `>=1.2,<2` or `~=1.2`, which may come from
+    /// the same specifier.
+    Bounded {
+        /// The specifier with the highest lower bound.
+        lower: &'a VersionSpecifier,
+        /// The specifier with the lowest upper bound.
+        upper: &'a VersionSpecifier,
+    },
+    /// There is a lower bound, but no upper bound, e.g. `>=1.2`.
+    LowerOnly(&'a VersionSpecifier),
+    /// There is an upper bound, but no lower bound, e.g. `<2`.
+    UpperOnly(&'a VersionSpecifier),
+    /// There are no bounds, e.g. no specifiers or only `!=`.
+    Unbounded,
+}
+
+/// Why a version doesn't satisfy a [`VersionSpecifier`], see [`VersionSpecifiers::explain`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MismatchReason {
+    /// The version is lower than the lower bound of `>=`, `>` or `~=`.
+    BelowLowerBound,
+    /// The version is higher than the upper bound of `<=`, `<` or `~=`.
+    AboveUpperBound,
+    /// `<V` excludes pre-releases of `V` unless `V` is a pre-release itself, e.g. `<2.0` excludes
+    /// `2.0rc1`.
+    ExcludedPrerelease,
+    /// `>V` excludes post-releases of `V` unless `V` is a post-release itself, e.g. `>2.0` excludes
+    /// `2.0.post1`.
+    ExcludedPostRelease,
+    /// The version is excluded by `!=` or `!=V.*`.
+    Excluded,
+    /// The version is not the one required by `==`, `==V.*` or `===`.
+    NotEqual,
+    /// The public version matches `==`, but the local version doesn't, e.g. `==1.0+cpu` and
+    /// `1.0+cu118`.
+    LocalMismatch,
+}
+
+impl std::fmt::Display for MismatchReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BelowLowerBound => write!(f, "the version is below the lower bound"),
+            Self::AboveUpperBound => write!(f, "the version is above the upper bound"),
+            Self::ExcludedPrerelease => write!(
+                f,
+                "`<` excludes pre-releases of the specified version unless it is a pre-release"
+            ),
+            Self::ExcludedPostRelease => write!(
+                f,
+                "`>` excludes post-releases of the specified version unless it is a post-release"
+            ),
+            Self::Excluded => write!(f, "the version is excluded"),
+            Self::NotEqual => write!(f, "the version is not the required version"),
+            Self::LocalMismatch => write!(f, "the local version doesn't match"),
+        }
+    }
+}
+
 /// An error that can occur when constructing a version specifier.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VersionSpecifierBuildError {
@@ -652,12 +1429,7 @@ impl std::fmt::Display for VersionSpecifierBuildError {
                 operator: ref op,
                 ref version,
             } => {
-                let local = version
-                    .local()
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<String>>()
-                    .join(".");
+                let local = version.local_str().unwrap_or_default();
                 write!(
                     f,
                     "Operator {op} is incompatible with versions \
@@ -676,10 +1448,56 @@ impl std::fmt::Display for VersionSpecifierBuildError {
                     "The ~= operator requires at least two segments in the release version"
                 )
             }
+            BuildErrorKind::StarNotReleaseOnly {
+                operator: ref op,
+                ref version,
+            } => {
+                write!(
+                    f,
+                    "Operator {op} requires a version with only an epoch and a release, \
+                     but `{version}` has other components",
+                )
+            }
         }
     }
 }
 
+impl VersionSpecifierBuildError {
+    /// What went wrong, for handling the error programmatically.
+    pub fn kind(&self) -> SpecifierErrorKind {
+        match *self.kind {
+            BuildErrorKind::OperatorLocalCombo { .. } => SpecifierErrorKind::OperatorWithLocal,
+            BuildErrorKind::OperatorWithStar { .. } => SpecifierErrorKind::OperatorWithStar,
+            BuildErrorKind::CompatibleRelease => SpecifierErrorKind::CompatibleReleaseTooShort,
+            BuildErrorKind::StarNotReleaseOnly { .. } => SpecifierErrorKind::StarNotReleaseOnly,
+        }
+    }
+}
+
+/// What went wrong when parsing or building a version specifier, see
+/// [`VersionSpecifierParseError::kind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SpecifierErrorKind {
+    /// The operator is invalid, e.g. `=>`.
+    InvalidOperator,
+    /// The version is invalid.
+    InvalidVersion(VersionErrorKind),
+    /// The operator doesn't allow a local version, e.g. `>=1.0+cpu`.
+    OperatorWithLocal,
+    /// The operator doesn't allow a wildcard, e.g. `>=1.0.*`.
+    OperatorWithStar,
+    /// `~=` is used with a single release segment, e.g. `~=1`.
+    CompatibleReleaseTooShort,
+    /// A wildcard operator is built with a version that isn't only a release, e.g. `==1.0rc1.*`.
+    StarNotReleaseOnly,
+    /// There is no operator, e.g. `1.0`.
+    MissingOperator,
+    /// There is no version after the operator, e.g. `>=`.
+    MissingVersion,
+    /// There is input after the version, e.g. `>=1.0 foo`.
+    InvalidTrailing,
+}
+
 /// The specific kind of error that can occur when building a version specifier
 /// from an operator and version pair.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -702,6 +1520,14 @@ enum BuildErrorKind {
     /// Occurs when the compatible release operator (`~=`) is used with a
     /// version that has fewer than 2 segments in its release version.
     CompatibleRelease,
+    /// Occurs when a wildcard operator is used with a version that has a
+    /// pre-release, post-release or dev release, e.g. `==1.0rc1.*`.
+    StarNotReleaseOnly {
+        /// The operator given.
+        operator: Operator,
+        /// The version given.
+        version: Version,
+    },
 }
 
 impl From<BuildErrorKind> for VersionSpecifierBuildError {
@@ -722,6 +1548,30 @@ pub struct VersionSpecifierParseError {
 
 impl std::error::Error for VersionSpecifierParseError {}
 
+impl VersionSpecifierParseError {
+    /// What went wrong, for handling the error programmatically.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use pep440_rs::{SpecifierErrorKind, VersionSpecifier};
+    ///
+    /// let err = VersionSpecifier::from_str(">=1.0.*").unwrap_err();
+    /// assert_eq!(err.kind(), SpecifierErrorKind::OperatorWithStar);
+    /// ```
+    pub fn kind(&self) -> SpecifierErrorKind {
+        match *self.kind {
+            ParseErrorKind::InvalidOperator(_) => SpecifierErrorKind::InvalidOperator,
+            ParseErrorKind::InvalidVersion(ref err) => {
+                SpecifierErrorKind::InvalidVersion(err.kind())
+            }
+            ParseErrorKind::InvalidSpecifier(ref err) => err.kind(),
+            ParseErrorKind::MissingOperator => SpecifierErrorKind::MissingOperator,
+            ParseErrorKind::MissingVersion => SpecifierErrorKind::MissingVersion,
+            ParseErrorKind::InvalidTrailing(_) => SpecifierErrorKind::InvalidTrailing,
+        }
+    }
+}
+
 impl std::fmt::Display for VersionSpecifierParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         // Note that even though we have nested error types here, since we
@@ -766,12 +1616,177 @@ impl From<ParseErrorKind> for VersionSpecifierParseError {
     }
 }
 
+/// An exclusive upper bound above the version and all its local versions, e.g. `1.0.post0.dev0`
+/// for `1.0`.
+fn after_local_versions(version: &Version) -> Bound<Version> {
+    let version = version.clone().without_local();
+    let next = if let Some(dev) = version.dev() {
+        dev.checked_add(1).map(|dev| version.with_dev(Some(dev)))
+    } else {
+        let post = match version.post() {
+            Some(post) => post.checked_add(1),
+            None => Some(0),
+        };
+        post.map(|post| version.with_post(Some(post)).with_dev(Some(0)))
+    };
+    next.map_or(Bound::Unbounded, Bound::Excluded)
+}
+
+/// The canonical order of the clauses in [`VersionSpecifiers`]: By version, then by operator, then
+/// by how the clause is written.
+///
+/// Sorting by the complete clause makes the order independent of how the clauses were written, so
+/// lists with the same clauses compare and hash equal. Equal versions can be written differently,
+/// e.g. `1.0` and `1.0.0`, so the written form breaks the remaining ties to get the same `Display`.
+fn cmp_clauses(a: &VersionSpecifier, b: &VersionSpecifier) -> Ordering {
+    a.version()
+        .cmp(b.version())
+        .then_with(|| a.cmp(b))
+        .then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+/// Compare two lower bounds by how restrictive they are, with `Unbounded` as the smallest.
+fn compare_lower_bounds(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp(b).then(Ordering::Less),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp(b).then(Ordering::Greater),
+    }
+}
+
+/// Compare two upper bounds by how permissive they are, with `Unbounded` as the largest.
+fn compare_upper_bounds(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp(b).then(Ordering::Greater),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp(b).then(Ordering::Less),
+    }
+}
+
+/// The width up to which the specifiers are shown in full in an error message.
+const ERROR_LINE_WIDTH: usize = 80;
+
+/// The byte range of `line` to show in an error message about `line[start..end]`.
+///
+/// Short lines are shown in full. For longer lines, the range is the error with as much context
+/// on either side as fits into [`ERROR_LINE_WIDTH`], leaving room for `…` markers.
+fn error_window(line: &str, start: usize, end: usize) -> (usize, usize) {
+    use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+    if line.width() <= ERROR_LINE_WIDTH {
+        return (0, line.len());
+    }
+    let mut budget = ERROR_LINE_WIDTH.saturating_sub(line[start..end].width() + 2);
+
+    // Extend to the left by up to half of the budget, to the right by what remains, and then to
+    // the left again if the error is close to the end.
+    let extend_left = |mut window_start: usize, limit: usize| {
+        let mut used = 0;
+        for (idx, c) in line[..window_start].char_indices().rev() {
+            let width = c.width().unwrap_or(0);
+            if used + width > limit {
+                break;
+            }
+            used += width;
+            window_start = idx;
+        }
+        (window_start, used)
+    };
+    let (mut window_start, used) = extend_left(start, budget / 2);
+    budget -= used;
+    let mut window_end = end;
+    for (idx, c) in line[end..].char_indices() {
+        let width = c.width().unwrap_or(0);
+        if width > budget {
+            break;
+        }
+        budget -= width;
+        window_end = end + idx + c.len_utf8();
+    }
+    (window_start, _) = extend_left(window_start, budget);
+    (window_start, window_end)
+}
+
+/// Whether the string is a valid version specifier, like [`VersionSpecifier::from_str`]
+/// succeeding, but without building the specifier.
+///
+/// Checking a valid specifier doesn't allocate, see [`is_valid_version`](crate::is_valid_version).
+///
+/// ```rust
+/// use pep440_rs::is_valid_specifier;
+///
+/// assert!(is_valid_specifier(">= 1.0"));
+/// assert!(is_valid_specifier("!=1.2.*"));
+/// assert!(!is_valid_specifier(">=1.2.*"));
+/// assert!(!is_valid_specifier("~=1"));
+/// ```
+pub fn is_valid_specifier(specifier: &str) -> bool {
+    let mut s = unscanny::Scanner::new(specifier);
+    s.eat_while(|c: char| c.is_whitespace());
+    let Ok(operator) = Operator::from_str(s.eat_while(['=', '!', '~', '<', '>'])) else {
+        return false;
+    };
+    s.eat_while(|c: char| c.is_whitespace());
+    let version = s.eat_while(|c: char| !c.is_whitespace());
+    s.eat_while(|c: char| c.is_whitespace());
+    if !s.done() {
+        return false;
+    }
+    let Some(shape) = check_version_pattern(version) else {
+        return false;
+    };
+    // The same checks as in `VersionSpecifier::from_pattern`
+    let operator = if shape.wildcard {
+        let Some(operator) = operator.to_star() else {
+            return false;
+        };
+        operator
+    } else {
+        operator
+    };
+    if shape.local && !operator.allows_local() {
+        return false;
+    }
+    !operator.requires_two_release_segments() || shape.release_len >= 2
+}
+
+/// Whether the string is a valid list of version specifiers, like [`VersionSpecifiers::from_str`]
+/// succeeding, but without building the specifiers.
+///
+/// Checking valid specifiers doesn't allocate, see [`is_valid_specifier`].
+///
+/// ```rust
+/// use pep440_rs::is_valid_specifiers;
+///
+/// assert!(is_valid_specifiers(">=3.8, !=3.9.*"));
+/// assert!(is_valid_specifiers(""));
+/// assert!(!is_valid_specifiers(">=3.8,"));
+/// ```
+pub fn is_valid_specifiers(specifiers: &str) -> bool {
+    specifiers.trim().is_empty() || specifiers.split(',').all(is_valid_specifier)
+}
+
 /// Parse a list of specifiers such as `>= 1.0, != 1.3.*, < 2.0`.
+#[cfg_attr(
+    feature = "instrument",
+    tracing::instrument(level = "trace", skip_all, fields(len = spec.len(), clauses))
+)]
 pub(crate) fn parse_version_specifiers(
     spec: &str,
 ) -> Result<Vec<VersionSpecifier>, VersionSpecifiersParseError> {
     let mut version_ranges = Vec::new();
-    if spec.is_empty() {
+    // Like `packaging.specifiers.SpecifierSet("")`, an empty string matches all versions
+    if spec.trim().is_empty() {
         return Ok(version_ranges);
     }
     let mut start: usize = 0;
@@ -782,7 +1797,7 @@ pub(crate) fn parse_version_specifiers(
                 return Err(VersionSpecifiersParseError {
                     inner: Box::new(VersionSpecifiersParseErrorInner {
                         err,
-                        line: spec.to_string(),
+                        line: spec.into(),
                         start,
                         end: start + version_range_spec.len(),
                     }),
@@ -795,6 +1810,8 @@ pub(crate) fn parse_version_specifiers(
         start += version_range_spec.len();
         start += separator.len();
     }
+    #[cfg(feature = "instrument")]
+    tracing::Span::current().record("clauses", version_ranges.len());
     Ok(version_ranges)
 }
 
@@ -1,8 +1,8 @@
-use std::{cmp::Ordering, str::FromStr};
+use std::{cmp::Ordering, ops::RangeBounds, str::FromStr};
 
 use indoc::indoc;
 
-use crate::LocalSegment;
+use crate::{version, LocalSegment};
 
 use super::*;
 
@@ -276,6 +276,36 @@ fn test_arbitrary_equality() {
     assert!(!VersionSpecifier::from_str("=== 1.2a1")
         .unwrap()
         .contains(&Version::from_str("1.2a1+local").unwrap()));
+
+    // Like packaging, the operand is compared as written to the normalized candidate
+    let specifier = VersionSpecifier::from_str("===1.0.0-Post1").unwrap();
+    assert_eq!(specifier.to_string(), "===1.0.0-Post1");
+    assert_eq!(specifier.verbatim(), Some("1.0.0-Post1"));
+    assert!(!specifier.contains(&Version::from_str("1.0.0-post1").unwrap()));
+    let specifier = VersionSpecifier::from_str("===1.0.0.POST1").unwrap();
+    assert!(specifier.contains(&Version::from_str("1.0.0-post1").unwrap()));
+    assert!(!specifier.contains(&Version::from_str("1.0.post1").unwrap()));
+    // Without an operand as written, the normalized version is used
+    let specifier =
+        VersionSpecifier::from_version(Operator::ExactEqual, Version::from_str("1.0").unwrap())
+            .unwrap();
+    assert_eq!(specifier.verbatim(), None);
+    assert!(specifier.contains(&Version::from_str("1.0").unwrap()));
+
+    // With the candidate as written, both sides are compared verbatim
+    let specifier = VersionSpecifier::from_str("===1.0.0-Post1").unwrap();
+    let version = Version::from_str("1.0.0-post1").unwrap();
+    assert!(specifier.contains_verbatim(&version, "1.0.0-post1"));
+    assert!(specifier.contains_verbatim(&version, " 1.0.0-POST1 "));
+    assert!(!specifier.contains_verbatim(&version, "1.0.0.post1"));
+    let version = Version::from_str("1.0").unwrap();
+    assert!(!VersionSpecifier::from_str("===1.0.0")
+        .unwrap()
+        .contains_verbatim(&version, "1.0"));
+    // Other operators ignore the verbatim candidate
+    let specifiers = VersionSpecifiers::from_str(">=1.0, ===1.0.0").unwrap();
+    assert!(specifiers.contains_verbatim(&Version::from_str("1.0").unwrap(), "1.0.0"));
+    assert!(!specifiers.contains_verbatim(&Version::from_str("1.0").unwrap(), "1.0"));
 }
 
 #[test]
@@ -320,6 +350,7 @@ fn test_specifiers_true() {
         // Test the in-equality operation with a prefix
         ("2.0", "!=3.*"),
         ("2.1", "!=2.0.*"),
+        ("2", "!=2.1.*"),
         // Test the greater than equal operation
         ("2.0", ">=2"),
         ("2.0", ">=2.0"),
@@ -367,6 +398,7 @@ fn test_specifiers_true() {
         ("2!1.0", ">=2.0"),
         ("1.0", "<2!0.1"),
         ("2!1.0", ">2.0"),
+        ("1.0a1", "<1!1.0"),
         // Test some normalization rules
         ("2.0.5", ">2.0dev"),
     ];
@@ -394,6 +426,7 @@ fn test_specifier_false() {
         // Test the equality operation with a prefix
         ("2.0", "==3.*"),
         ("2.1", "==2.0.*"),
+        ("2", "==2.1.*"),
         // Test the in-equality operation
         ("2.0", "!=2"),
         ("2.0", "!=2.0"),
@@ -493,18 +526,22 @@ fn test_parse_version_specifiers() {
             VersionSpecifier {
                 operator: Operator::TildeEqual,
                 version: Version::new([0, 9]),
+                verbatim: None,
             },
             VersionSpecifier {
                 operator: Operator::GreaterThanEqual,
                 version: Version::new([1, 0]),
+                verbatim: None,
             },
             VersionSpecifier {
                 operator: Operator::NotEqualStar,
                 version: Version::new([1, 3, 4]),
+                verbatim: None,
             },
             VersionSpecifier {
                 operator: Operator::LessThan,
                 version: Version::new([2, 0]),
+                verbatim: None,
             }
         ]
     );
@@ -853,6 +890,11 @@ fn test_version_specifiers_str() {
 #[test]
 fn test_version_specifiers_empty() {
     assert_eq!(VersionSpecifiers::from_str("").unwrap().to_string(), "");
+    for input in ["", " ", "\t \n"] {
+        let specifiers = VersionSpecifiers::from_str(input).unwrap();
+        assert_eq!(specifiers, VersionSpecifiers::any());
+        assert!(specifiers.contains(&Version::from_str("1.0rc1").unwrap()));
+    }
 }
 
 /// All non-ASCII version specifiers are invalid, but the user can still
@@ -896,7 +938,7 @@ fn error_message_version_specifiers_parse_error() {
     };
     let inner = Box::new(VersionSpecifiersParseErrorInner {
         err,
-        line: specs.to_string(),
+        line: specs.into(),
         start: 8,
         end: 14,
     });
@@ -946,3 +988,435 @@ fn error_message_version_specifier_parse_error() {
         "The ~= operator requires at least two segments in the release version"
     );
 }
+
+#[test]
+fn explain() {
+    let cases = [
+        (">=1.16", "1.19", None),
+        (">=1.16", "1.15", Some(MismatchReason::BelowLowerBound)),
+        (">1.16", "1.16", Some(MismatchReason::BelowLowerBound)),
+        (
+            ">1.16",
+            "1.16.post1",
+            Some(MismatchReason::ExcludedPostRelease),
+        ),
+        ("<2.0", "2.0", Some(MismatchReason::AboveUpperBound)),
+        ("<=2.0", "2.0.post1", Some(MismatchReason::AboveUpperBound)),
+        ("<2.0", "2.0rc1", Some(MismatchReason::ExcludedPrerelease)),
+        ("~=1.4.2", "1.4.1", Some(MismatchReason::BelowLowerBound)),
+        ("~=1.4.2", "1.5", Some(MismatchReason::AboveUpperBound)),
+        ("!=1.19", "1.19+local", Some(MismatchReason::Excluded)),
+        ("!=1.*", "1.19", Some(MismatchReason::Excluded)),
+        ("==1.19", "1.20", Some(MismatchReason::NotEqual)),
+        ("==1.*", "2.0", Some(MismatchReason::NotEqual)),
+        ("===1.0", "1.0.0", Some(MismatchReason::NotEqual)),
+        (
+            "==1.0+cpu",
+            "1.0+cu118",
+            Some(MismatchReason::LocalMismatch),
+        ),
+        ("==1.0+cpu", "1.1+cpu", Some(MismatchReason::NotEqual)),
+    ];
+    for (specifier, version, reason) in cases {
+        let specifier = VersionSpecifier::from_str(specifier).unwrap();
+        let version = Version::from_str(version).unwrap();
+        assert_eq!(specifier.explain(&version), reason, "{specifier} {version}");
+    }
+
+    let specifiers = VersionSpecifiers::from_str(">=1.16, <2.0").unwrap();
+    let explanation = specifiers.explain(&Version::from_str("2.0").unwrap());
+    assert_eq!(explanation[0].1, None);
+    assert_eq!(explanation[1].1, Some(MismatchReason::AboveUpperBound));
+    assert_eq!(
+        MismatchReason::AboveUpperBound.to_string(),
+        "the version is above the upper bound"
+    );
+}
+
+#[test]
+fn bounding_range() {
+    let cases = [
+        ("==1.0", r#"["1.0", "1.0.post0.dev0")"#),
+        ("==1.0.dev1", r#"["1.0.dev1", "1.0.dev2")"#),
+        ("==1.0+local", r#"["1.0+local", "1.0+local"]"#),
+        // The bounds below all pre-releases, `1.2.min0` and `1.3.min0`, display as the release
+        ("==1.2.*", r#"["1.2", "1.3")"#),
+        ("~=1.2.3", r#"["1.2.3", "1.3")"#),
+        ("!=1.0", "(.., ..)"),
+        (">1.0", r#"("1.0", ..)"#),
+        ("<=1.0.post1", r#"(.., "1.0.post2.dev0")"#),
+    ];
+    for (specifier, expected) in cases {
+        let (lower, upper) = VersionSpecifier::from_str(specifier)
+            .unwrap()
+            .bounding_range();
+        let lower = match lower {
+            Bound::Included(version) => format!("[\"{version}\""),
+            Bound::Excluded(version) => format!("(\"{version}\""),
+            Bound::Unbounded => "(..".to_string(),
+        };
+        let upper = match upper {
+            Bound::Included(version) => format!("\"{version}\"]"),
+            Bound::Excluded(version) => format!("\"{version}\")"),
+            Bound::Unbounded => "..)".to_string(),
+        };
+        assert_eq!(format!("{lower}, {upper}"), expected, "{specifier}");
+    }
+}
+
+#[test]
+fn debug() {
+    let specifiers = VersionSpecifiers::from_str(">=1.0, ==1.2.*").unwrap();
+    assert_eq!(
+        format!("{specifiers:?}"),
+        r#"VersionSpecifiers(">=1.0, ==1.2.*")"#
+    );
+    assert_eq!(
+        format!("{:?}", specifiers[1]),
+        r#"VersionSpecifier("==1.2.*")"#
+    );
+    assert_eq!(
+        format!("{:?}", specifiers[1].version()),
+        r#"Version("1.2")"#
+    );
+    assert_eq!(
+        format!("{:#?}", specifiers[1]),
+        indoc! {r#"
+            VersionSpecifier {
+                operator: EqualStar,
+                version: Version {
+                    epoch: 0,
+                    release: [
+                        1,
+                        2,
+                    ],
+                    pre: None,
+                    post: None,
+                    dev: None,
+                    local: [],
+                    min: None,
+                    max: None,
+                },
+            }"#
+        }
+    );
+}
+
+#[test]
+fn display_padding() {
+    let specifiers = VersionSpecifiers::from_str(">=1.0, <2.0.1").unwrap();
+    assert_eq!(format!("[{specifiers:>14}]"), "[ >=1.0, <2.0.1]");
+    assert_eq!(format!("[{:<8}]", specifiers[0]), "[>=1.0   ]");
+    assert_eq!(format!("[{:^6}]", specifiers[0].operator()), "[  >=  ]");
+    assert_eq!(format!("[{:*>6}]", specifiers[0].version()), "[***1.0]");
+    assert_eq!(format!("[{:.3}]", specifiers[1].version()), "[2.0]");
+}
+
+#[test]
+fn error_message_long_line() {
+    let valid: Vec<String> = (0..20).map(|minor| format!("!=1.{minor}")).collect();
+    let specs = format!(
+        "{}, >=2.0, 3.0, <4.0, {}",
+        valid.join(", "),
+        valid.join(", ")
+    );
+    assert_eq!(
+        VersionSpecifiers::from_str(&specs).unwrap_err().to_string(),
+        indoc! {"
+            Failed to parse version: Unexpected end of version specifier, expected operator:
+            …=1.16, !=1.17, !=1.18, !=1.19, >=2.0, 3.0, <4.0, !=1.0, !=1.1, !=1.2, !=1.3, !…
+                                                  ^^^^
+        "}
+    );
+
+    // Near the end of the line, the context extends further to the left
+    let specs = format!("{}, 3.0", valid.join(", "));
+    assert_eq!(
+        VersionSpecifiers::from_str(&specs).unwrap_err().to_string(),
+        indoc! {"
+            Failed to parse version: Unexpected end of version specifier, expected operator:
+            …0, !=1.11, !=1.12, !=1.13, !=1.14, !=1.15, !=1.16, !=1.17, !=1.18, !=1.19, 3.0
+                                                                                       ^^^^
+        "}
+    );
+}
+
+#[test]
+fn error_message_alternate() {
+    let err = VersionSpecifiers::from_str(">=1.0, <2.0, 3.0").unwrap_err();
+    assert_eq!(
+        format!("{err:#}"),
+        "error at 12..16: Unexpected end of version specifier, expected operator"
+    );
+    // The default stays the multi-line rendering
+    assert_eq!(err.to_string().lines().count(), 3);
+
+    let err = VersionSpecifiers::from_str(">=1.0, ~=1").unwrap_err();
+    assert!(!format!("{err:#}").contains('\n'), "{err:#}");
+}
+
+#[test]
+fn error_kind() {
+    let kind = |s: &str| VersionSpecifier::from_str(s).unwrap_err().kind();
+    assert_eq!(kind("=>1.0"), SpecifierErrorKind::InvalidOperator);
+    assert_eq!(kind("1.0"), SpecifierErrorKind::MissingOperator);
+    assert_eq!(kind(">="), SpecifierErrorKind::MissingVersion);
+    assert_eq!(kind(">=1.0 foo"), SpecifierErrorKind::InvalidTrailing);
+    assert_eq!(kind(">=1.0+cpu"), SpecifierErrorKind::OperatorWithLocal);
+    assert_eq!(kind(">=1.0.*"), SpecifierErrorKind::OperatorWithStar);
+    assert_eq!(kind("~=1"), SpecifierErrorKind::CompatibleReleaseTooShort);
+    assert_eq!(
+        kind("==1.0+"),
+        SpecifierErrorKind::InvalidVersion(VersionErrorKind::InvalidLocal)
+    );
+
+    let err = VersionSpecifiers::from_str(">=1.0, <2.0, 3.0").unwrap_err();
+    assert_eq!(err.kind(), SpecifierErrorKind::MissingOperator);
+    assert_eq!(err.span(), 12..16);
+    assert_eq!(err.specifier_error().kind(), err.kind());
+}
+
+/// Matching and the range conversions don't panic for specifiers that bypassed the constructor
+/// checks or whose upper bound overflows.
+#[test]
+fn matching_is_total() {
+    let max = u64::MAX;
+    let specifiers = [
+        // Rejected by `from_version`, but constructible through rkyv
+        VersionSpecifier {
+            operator: Operator::TildeEqual,
+            version: Version::new([2]),
+            verbatim: None,
+        },
+        VersionSpecifier::from_str(&format!("~=1.{max}.0")).unwrap(),
+        VersionSpecifier::from_str(&format!("==1.{max}.*")).unwrap(),
+        VersionSpecifier::from_str(&format!("!=1.{max}.*")).unwrap(),
+        VersionSpecifier::from_str(&format!("==1.0.post{max}")).unwrap(),
+    ];
+    let candidates = ["1.0", "2.0", "3.0", &format!("1.{max}.5"), "1!2.0"]
+        .map(|version| Version::from_str(version).unwrap());
+    for specifier in specifiers {
+        for candidate in &candidates {
+            let contains = specifier.contains(candidate);
+            let _ = specifier.explain(candidate);
+            if contains {
+                assert!(
+                    specifier.bounding_range().contains(candidate),
+                    "{specifier}"
+                );
+            }
+        }
+        #[cfg(feature = "version-ranges")]
+        {
+            let _ = version_ranges::Ranges::from(specifier.clone());
+            let _ = crate::release_specifier_to_range(specifier.clone());
+        }
+    }
+    let compatible = VersionSpecifier {
+        operator: Operator::TildeEqual,
+        version: Version::new([2]),
+        verbatim: None,
+    };
+    assert!(compatible.contains(&Version::from_str("3.0").unwrap()));
+    assert!(!compatible.contains(&Version::from_str("1.0").unwrap()));
+    assert!(!compatible.contains(&Version::from_str("1!3.0").unwrap()));
+}
+
+#[test]
+fn from_str_with_warnings() {
+    let (specifier, warnings) = VersionSpecifier::from_str_with_warnings(">= 1.0").unwrap();
+    assert_eq!(specifier.to_string(), ">=1.0");
+    assert_eq!(warnings, []);
+
+    let (specifier, warnings) = VersionSpecifier::from_str_with_warnings("==1.01.*").unwrap();
+    assert_eq!(specifier.to_string(), "==1.1.*");
+    assert_eq!(
+        warnings,
+        [
+            ParseWarning::LeadingZeros {
+                number: "01".to_string()
+            },
+            ParseWarning::NonCanonical {
+                normalized: "1.1.*".to_string()
+            }
+        ]
+    );
+
+    let (_, warnings) = VersionSpecifier::from_str_with_warnings("===1.0").unwrap();
+    assert_eq!(warnings, [ParseWarning::ArbitraryEquality]);
+}
+
+#[test]
+fn specifiers_eq_ignores_order() {
+    let specifiers = |specifiers: &str| VersionSpecifiers::from_str(specifiers).unwrap();
+    assert_eq!(specifiers(">=1.0,<2.0"), specifiers("<2.0,>=1.0"));
+    assert_eq!(
+        specifiers("!=1.0, >=1.0, ==1.*"),
+        specifiers("==1.*, >=1.0, !=1.0")
+    );
+    assert_eq!(
+        specifiers(">=1.0, !=1.0").to_string(),
+        specifiers("!=1.0, >=1.0").to_string()
+    );
+    // Equal clauses with different spellings have the same order, too
+    assert_eq!(
+        specifiers(">=1.0, >=1.0.0").to_string(),
+        specifiers(">=1.0.0, >=1.0").to_string()
+    );
+    assert_eq!(
+        specifiers("!=10.*, !=10.0.*").to_string(),
+        specifiers("!=10.0.*, !=10.*").to_string()
+    );
+    let set: std::collections::HashSet<_> =
+        [specifiers(">=1.0,<2.0"), specifiers("<2.0, >=1.0")].into();
+    assert_eq!(set.len(), 1);
+    // Only the clauses are compared, not the matching versions
+    assert_ne!(specifiers("~=1.2"), specifiers(">=1.2, ==1.*"));
+}
+
+#[test]
+fn specifiers_mutation() {
+    let specifier = |specifier: &str| VersionSpecifier::from_str(specifier).unwrap();
+    let mut specifiers = VersionSpecifiers::from_str(">=1.0, <3").unwrap();
+    specifiers.push(specifier("!=2.0"));
+    specifiers.push(specifier("!=1.0"));
+    assert_eq!(specifiers.to_string(), "!=1.0, >=1.0, !=2.0, <3");
+    assert_eq!(
+        specifiers,
+        VersionSpecifiers::from_str(">=1.0, <3, !=2.0, !=1.0").unwrap()
+    );
+
+    assert_eq!(specifiers.remove(0), specifier("!=1.0"));
+    assert_eq!(specifiers.to_string(), ">=1.0, !=2.0, <3");
+
+    assert_eq!(
+        specifiers.replace_clause(0, specifier(">=2.5")),
+        specifier(">=1.0")
+    );
+    assert_eq!(specifiers.to_string(), "!=2.0, >=2.5, <3");
+
+    specifiers.retain(|specifier| *specifier.operator() != Operator::NotEqual);
+    assert_eq!(specifiers.to_string(), ">=2.5, <3");
+}
+
+#[test]
+fn specifiers_simplify() {
+    let cases = [
+        ("", ""),
+        (">=1.0, >=1.2, <=3, <=2.5", ">=1.2, <=2.5"),
+        (">=1.2, >=1.2.0, !=1.5, !=1.5", ">=1.2, !=1.5"),
+        // Only `>=` and `<=` are merged, `>` and `<` have exceptions for pre- and post-releases
+        (
+            ">1.0, >1.0.post1, <2, <2.0rc1",
+            ">1.0, >1.0.post1, <2.0rc1, <2",
+        ),
+        ("==1.0+local, ==1.0", "==1.0, ==1.0+local"),
+    ];
+    for (input, expected) in cases {
+        let mut specifiers = VersionSpecifiers::from_str(input).unwrap();
+        specifiers.simplify();
+        assert_eq!(specifiers.to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn specifiers_minimize() {
+    let available: Vec<Version> = ["1.0", "1.1", "1.2rc1", "1.2", "2.0", "2.1"]
+        .into_iter()
+        .map(|version| Version::from_str(version).unwrap())
+        .collect();
+    let cases = [
+        ("", ""),
+        (">=0.5, <3", ""),
+        (">=1.1, !=1.1.5, !=1.3, <2", ">=1.1, <2"),
+        ("~=1.1, !=1.2", "~=1.1, !=1.2"),
+        ("==1.2.*, !=1.2", "==1.2.*, !=1.2"),
+        // Nothing matches either way, and only the last clause is needed for that
+        (">=3, <=0.5", ">=3"),
+    ];
+    for (input, expected) in cases {
+        let specifiers = VersionSpecifiers::from_str(input).unwrap();
+        let minimized = specifiers.minimize(&available);
+        assert_eq!(minimized.to_string(), expected, "{input}");
+        for version in &available {
+            assert_eq!(
+                minimized.contains(version),
+                specifiers.contains(version),
+                "{input} {version}"
+            );
+        }
+    }
+}
+
+#[test]
+fn boundedness() {
+    let cases = [
+        ("", "unbounded"),
+        ("!=1.5, !=2.*", "unbounded"),
+        (">=1.0, ==1.2.3, <2", "pinned ==1.2.3"),
+        ("===1.0", "pinned ===1.0"),
+        ("==1.2.*", "bounded ==1.2.* ==1.2.*"),
+        ("~=1.4.2, !=1.4.5", "bounded ~=1.4.2 ~=1.4.2"),
+        (">=1.0, >1.2, <=3, <2.5", "bounded >1.2 <2.5"),
+        ("~=1.4, <1.6", "bounded ~=1.4 <1.6"),
+        (">1.0, >=0.5, !=1.5", "lower >1.0"),
+        ("<2, <=1.5", "upper <=1.5"),
+    ];
+    for (input, expected) in cases {
+        let specifiers = VersionSpecifiers::from_str(input).unwrap();
+        let actual = match specifiers.boundedness() {
+            Boundedness::Pinned(pinned) => format!("pinned {pinned}"),
+            Boundedness::Bounded { lower, upper } => format!("bounded {lower} {upper}"),
+            Boundedness::LowerOnly(lower) => format!("lower {lower}"),
+            Boundedness::UpperOnly(upper) => format!("upper {upper}"),
+            Boundedness::Unbounded => "unbounded".to_string(),
+        };
+        assert_eq!(actual, expected, "{input}");
+    }
+}
+
+#[cfg(feature = "version-ranges")]
+#[test]
+fn semantically_equals() {
+    let specifier = |s: &str| VersionSpecifier::from_str(s).unwrap();
+    assert!(specifier("==1.2.*").semantically_equals(&specifier("~=1.2.0.dev0")));
+    assert!(specifier(">=1.0").semantically_equals(&specifier(">= 1")));
+    assert!(!specifier("==1.*").semantically_equals(&specifier("~=1.0")));
+    assert!(!specifier("===1.0").semantically_equals(&specifier("==1.0")));
+    assert!(specifier("===1.0").semantically_equals(&specifier("=== 1.0")));
+
+    let specifiers = |s: &str| VersionSpecifiers::from_str(s).unwrap();
+    assert!(specifiers("~=1.4.5").semantically_equals(&specifiers(">=1.4.5, ==1.4.*")));
+    assert!(specifiers(">=1.0, >=0.5").semantically_equals(&specifiers(">=1")));
+    assert!(specifiers(">2, <1").semantically_equals(&specifiers("==3, ==4")));
+    assert!(!specifiers(">=1.0, <2").semantically_equals(&specifiers("~=1.0")));
+    assert!(!specifiers(">=1, ===1.0").semantically_equals(&specifiers("===1.0")));
+}
+
+#[test]
+fn new_unchecked() {
+    for specifier in ["==1.0+local", "~=1.4", "!=2.*", ">1.0.post1"] {
+        let specifier = VersionSpecifier::from_str(specifier).unwrap();
+        let unchecked =
+            VersionSpecifier::new_unchecked(*specifier.operator(), specifier.version().clone());
+        assert_eq!(unchecked, specifier);
+    }
+}
+
+#[test]
+fn from_version_star_release_only() {
+    let version = |version: &str| Version::from_str(version).unwrap();
+    assert!(VersionSpecifier::from_version(Operator::EqualStar, version("1!2.0")).is_ok());
+    for invalid in ["2.0rc1", "2.0.post1", "2.0.dev1"] {
+        for operator in [Operator::EqualStar, Operator::NotEqualStar] {
+            let err = VersionSpecifier::from_version(operator, version(invalid)).unwrap_err();
+            assert_eq!(err.kind(), SpecifierErrorKind::StarNotReleaseOnly);
+        }
+    }
+    let err = VersionSpecifier::from_version(Operator::EqualStar, version("2.0rc1")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Operator == requires a version with only an epoch and a release, but `2.0rc1` has other \
+         components"
+    );
+}
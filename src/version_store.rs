@@ -0,0 +1,172 @@
+//! Intern versions into a store and refer to them by small copyable IDs.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::Version;
+
+/// A handle for a version in a [`VersionStore`].
+///
+/// IDs are 4 bytes and `Copy`, and two IDs from the same store are equal if and only if their
+/// versions are equal. IDs don't implement `Ord`, since the order in which versions were
+/// interned is unrelated to the version order; use [`VersionStore::cmp`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VersionId(u32);
+
+impl VersionId {
+    /// The position of the version in the store, from `0` to `len() - 1` in the order the
+    /// versions were interned.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// An arena of distinct versions, for holding a large number of candidate versions in a
+/// resolver.
+///
+/// Each distinct version is stored once, equal versions such as `1.0` and `1.0.0` get the same
+/// [`VersionId`] and the first spelling is kept. Next to each version, the store keeps its
+/// [`Version::to_bytes`] encoding in a single buffer, so comparing and sorting IDs compares
+/// bytes instead of walking the version components.
+///
+/// Using an ID with a store other than the one that created it returns unrelated versions or
+/// panics.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use pep440_rs::{Version, VersionStore};
+///
+/// let mut store = VersionStore::new();
+/// let mut ids: Vec<_> = ["2.0", "1.0rc1", "1.0", "1.0.0"]
+///     .into_iter()
+///     .map(|version| store.intern(Version::from_str(version).unwrap()))
+///     .collect();
+/// assert_eq!(ids[2], ids[3]);
+/// assert_eq!(store.len(), 3);
+///
+/// store.sort(&mut ids);
+/// let sorted: Vec<String> = ids.iter().map(|id| store[*id].to_string()).collect();
+/// assert_eq!(sorted, ["1.0rc1", "1.0", "1.0", "2.0"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VersionStore {
+    /// The versions by ID.
+    versions: Vec<Version>,
+    /// The IDs by version.
+    ids: HashMap<Version, VersionId>,
+    /// The concatenated order-preserving encodings of the versions.
+    keys: Vec<u8>,
+    /// The end of each version's encoding in `keys`, by ID.
+    key_ends: Vec<usize>,
+}
+
+impl VersionStore {
+    /// An empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty store with space for `capacity` versions.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            versions: Vec::with_capacity(capacity),
+            ids: HashMap::with_capacity(capacity),
+            keys: Vec::new(),
+            key_ends: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// The ID of the version, adding the version to the store if there's no equal version yet.
+    ///
+    /// # Panics
+    ///
+    /// If the store already contains `u32::MAX + 1` versions.
+    pub fn intern(&mut self, version: Version) -> VersionId {
+        if let Some(id) = self.ids.get(&version) {
+            return *id;
+        }
+        let id = VersionId(u32::try_from(self.versions.len()).expect("Too many versions"));
+        self.keys.extend_from_slice(&version.to_bytes());
+        self.key_ends.push(self.keys.len());
+        self.ids.insert(version.clone(), id);
+        self.versions.push(version);
+        id
+    }
+
+    /// The ID of an equal version, if it was interned.
+    pub fn id(&self, version: &Version) -> Option<VersionId> {
+        self.ids.get(version).copied()
+    }
+
+    /// The version for the ID.
+    pub fn get(&self, id: VersionId) -> Option<&Version> {
+        self.versions.get(id.index())
+    }
+
+    /// Compare the versions of two IDs, the same as comparing the versions themselves.
+    pub fn cmp(&self, a: VersionId, b: VersionId) -> Ordering {
+        if a == b {
+            return Ordering::Equal;
+        }
+        self.key(a).cmp(self.key(b))
+    }
+
+    /// Sort IDs by their versions.
+    pub fn sort(&self, ids: &mut [VersionId]) {
+        ids.sort_unstable_by(|a, b| self.cmp(*a, *b));
+    }
+
+    /// The number of distinct versions.
+    pub fn len(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Returns `true` if no version was interned.
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// All IDs with their versions, in the order they were interned.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (VersionId, &Version)> {
+        self.versions
+            .iter()
+            .enumerate()
+            .map(|(idx, version)| (VersionId(idx as u32), version))
+    }
+
+    /// The encoding of the version with the ID.
+    fn key(&self, id: VersionId) -> &[u8] {
+        let start = match id.index() {
+            0 => 0,
+            idx => self.key_ends[idx - 1],
+        };
+        &self.keys[start..self.key_ends[id.index()]]
+    }
+}
+
+impl std::ops::Index<VersionId> for VersionStore {
+    type Output = Version;
+
+    fn index(&self, id: VersionId) -> &Version {
+        &self.versions[id.index()]
+    }
+}
+
+impl Extend<Version> for VersionStore {
+    fn extend<I: IntoIterator<Item = Version>>(&mut self, iter: I) {
+        for version in iter {
+            self.intern(version);
+        }
+    }
+}
+
+impl FromIterator<Version> for VersionStore {
+    fn from_iter<I: IntoIterator<Item = Version>>(iter: I) -> Self {
+        let mut store = Self::new();
+        store.extend(iter);
+        store
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use super::*;
+
+fn version(version: &str) -> Version {
+    Version::from_str(version).unwrap()
+}
+
+#[test]
+fn intern() {
+    let mut store = VersionStore::new();
+    let a = store.intern(version("1.0"));
+    let b = store.intern(version("2.0"));
+    assert_eq!(store.intern(version("1.0.0")), a);
+    assert_ne!(a, b);
+    assert_eq!(store.len(), 2);
+    // The first spelling is kept
+    assert_eq!(store[a].to_string(), "1.0");
+    assert_eq!(store.get(b), Some(&version("2.0")));
+    assert_eq!(store.id(&version("1")), Some(a));
+    assert_eq!(store.id(&version("3.0")), None);
+    assert_eq!(
+        store.iter().map(|(id, _)| id.index()).collect::<Vec<_>>(),
+        [0, 1]
+    );
+}
+
+#[test]
+fn cmp_matches_version_order() {
+    let versions = [
+        "1.0.post1",
+        "1!0.1",
+        "1.0+local",
+        "1.0",
+        "1.0a1",
+        "1.0.dev0",
+        "0.9",
+        "1.0a1.post2.dev3",
+        "1.0+local.2",
+    ];
+    let store: VersionStore = versions.iter().map(|v| version(v)).collect();
+    let ids: Vec<VersionId> = store.iter().map(|(id, _)| id).collect();
+    for a in &ids {
+        for b in &ids {
+            assert_eq!(store.cmp(*a, *b), store[*a].cmp(&store[*b]), "{a:?} {b:?}");
+        }
+    }
+
+    let mut sorted = ids.clone();
+    store.sort(&mut sorted);
+    let mut expected: Vec<Version> = versions.iter().map(|v| version(v)).collect();
+    expected.sort();
+    assert_eq!(
+        sorted.iter().map(|id| &store[*id]).collect::<Vec<_>>(),
+        expected.iter().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,88 @@
+//! JavaScript bindings through [wasm-bindgen](https://rustwasm.github.io/docs/wasm-bindgen/).
+//!
+//! Build with `wasm-pack build --features wasm`, which also generates the TypeScript definitions.
+//!
+//! ```js
+//! import { parseVersion, compareVersions, specifierContains, VersionSpecifiers } from "pep440_rs";
+//!
+//! parseVersion("1.0-Alpha1"); // "1.0a1"
+//! compareVersions("1.0rc1", "1.0"); // -1
+//! specifierContains(">=1.16, <2.0", "1.19"); // true
+//! new VersionSpecifiers(">=1.16, <2.0").contains("1.19"); // true
+//! ```
+//!
+//! Invalid versions and specifiers throw an `Error` with the parser's message.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use wasm_bindgen::prelude::*;
+
+use crate::Version;
+
+/// Parse a version and return its normalized form, e.g. `1.0-Alpha1` becomes `1.0a1`.
+#[wasm_bindgen(js_name = parseVersion)]
+pub fn parse_version(version: &str) -> Result<String, JsError> {
+    Ok(parse(version)?.to_string())
+}
+
+/// Compare two versions, returning -1, 0 or 1 like a JavaScript sort comparator.
+#[wasm_bindgen(js_name = compareVersions)]
+pub fn compare_versions(a: &str, b: &str) -> Result<i32, JsError> {
+    Ok(match parse(a)?.cmp(&parse(b)?) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    })
+}
+
+/// Whether the version matches all specifiers, e.g. `>=1.16, <2.0` contains `1.19`.
+#[wasm_bindgen(js_name = specifierContains)]
+pub fn specifier_contains(specifiers: &str, version: &str) -> Result<bool, JsError> {
+    let specifiers = crate::VersionSpecifiers::from_str(specifiers)?;
+    Ok(specifiers.contains(&parse(version)?))
+}
+
+/// A parsed list of version specifiers such as `>=1.16, <2.0`.
+#[wasm_bindgen]
+pub struct VersionSpecifiers(crate::VersionSpecifiers);
+
+#[wasm_bindgen]
+impl VersionSpecifiers {
+    /// Parse a list of version specifiers such as `>=1.16, <2.0`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(specifiers: &str) -> Result<VersionSpecifiers, JsError> {
+        Ok(Self(crate::VersionSpecifiers::from_str(specifiers)?))
+    }
+
+    /// Whether the version matches all specifiers.
+    pub fn contains(&self, version: &str) -> Result<bool, JsError> {
+        Ok(self.0.contains(&parse(version)?))
+    }
+
+    /// Return the versions that match all specifiers, skipping invalid versions.
+    pub fn filter(&self, versions: Vec<String>) -> Vec<String> {
+        versions
+            .into_iter()
+            .filter(|version| {
+                Version::from_str(version).is_ok_and(|version| self.0.contains(&version))
+            })
+            .collect()
+    }
+
+    /// The number of specifiers.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The normalized specifiers, e.g. `>=1.16, <2.0`.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+fn parse(version: &str) -> Result<Version, JsError> {
+    Ok(Version::from_str(version)?)
+}
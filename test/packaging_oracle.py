@@ -0,0 +1,61 @@
+"""
+Reference implementation for the differential tests against pypa/packaging.
+
+Reads `{"versions": [...], "specifiers": [...]}` as JSON from stdin and writes the results
+of parsing, normalization, ordering and matching with `packaging` as JSON to stdout. See
+`src/tests/packaging.rs`.
+"""
+
+import json
+import sys
+
+try:
+    from packaging.specifiers import InvalidSpecifier, Specifier
+    from packaging.version import InvalidVersion, Version
+except ImportError:
+    # pip vendors a copy of packaging
+    from pip._vendor.packaging.specifiers import InvalidSpecifier, Specifier
+    from pip._vendor.packaging.version import InvalidVersion, Version
+
+
+def parse_version(version):
+    try:
+        return Version(version)
+    except InvalidVersion:
+        return None
+
+
+def parse_specifiers(specifiers):
+    try:
+        return [Specifier(specifier.strip()) for specifier in specifiers.split(",")]
+    except InvalidSpecifier:
+        return None
+
+
+def main():
+    request = json.load(sys.stdin)
+    versions = [parse_version(version) for version in request["versions"]]
+    specifiers = [parse_specifiers(specifiers) for specifiers in request["specifiers"]]
+
+    valid = [idx for idx, version in enumerate(versions) if version is not None]
+    response = {
+        "versions": [str(version) if version else None for version in versions],
+        "order": sorted(valid, key=lambda idx: versions[idx]),
+        "specifiers": [specifier is not None for specifier in specifiers],
+        # Rust doesn't implement the pre-release exclusion, which needs to know about other
+        # available versions, so we always allow pre-releases.
+        "contains": [
+            [
+                all(part.contains(versions[idx], prereleases=True) for part in parts)
+                for idx in valid
+            ]
+            if parts is not None
+            else None
+            for parts in specifiers
+        ],
+    }
+    json.dump(response, sys.stdout)
+
+
+if __name__ == "__main__":
+    main()